@@ -0,0 +1,511 @@
+use crate::state::fresh_state;
+use crate::{AutomateTrait, AutomatonError, BTSet, State, Symbol, Transition, DFA, FSM, NDFA};
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Write;
+use std::fs;
+
+/// Le nom donné au symbole d'identifiant 0 (ε) dans les tables de symboles générées
+const EPSILON_NAME: &str = "<eps>";
+
+fn malformed(line: usize, message: &str) -> AutomatonError {
+    AutomatonError::MalformedAtt { message: format!("ligne {}: {}", line, message) }
+}
+
+// un symbole écrit dans un texte AT&T ou une table ne doit pas contenir de blanc, ni prendre le nom réservé à ε
+fn check_symbol(symbol: &Symbol) -> Result<(), AutomatonError> {
+    let value: &str = symbol.get_value();
+    if value.is_empty() || value.chars().any(char::is_whitespace) {
+        return Err(AutomatonError::Unsupported { message: format!("le symbole \"{}\" est vide ou contient un blanc, il ne peut pas être écrit au format AT&T", value) });
+    }
+    if value == EPSILON_NAME {
+        return Err(AutomatonError::Unsupported { message: format!("le nom \"{}\" est réservé à ε", EPSILON_NAME) });
+    }
+    Ok(())
+}
+
+fn read_file(path: &str) -> Result<String, AutomatonError> {
+    fs::read_to_string(path).map_err(|error| AutomatonError::Io {
+        path: path.to_string(),
+        message: error.to_string(),
+    })
+}
+
+fn write_file(path: &str, content: &str) -> Result<(), AutomatonError> {
+    fs::write(path, content).map_err(|error| AutomatonError::Io {
+        path: path.to_string(),
+        message: error.to_string(),
+    })
+}
+
+/// Une table de symboles OpenFst (fichier `.syms`), associant à chaque nom de symbole un identifiant entier
+///
+/// L'identifiant 0 est réservé à ε.
+///
+/// # Examples
+///
+/// ```
+/// use automaters::*;
+/// fn main() {
+///     let table : SymbolTable = SymbolTable::from_syms("<eps>\t0\na\t1\nb\t2\n").unwrap();
+///     assert_eq!(table.get_id("b"), Some(2));
+///     assert_eq!(table.get_name(1), Some("a"));
+///     assert_eq!(table.to_syms(), "<eps>\t0\na\t1\nb\t2\n");
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct SymbolTable {
+    ids: HashMap<String, usize>,
+    names: BTreeMap<usize, String>,
+}
+
+impl SymbolTable {
+    /// Créer une table vide
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Créer la table d'un alphabet: `<eps>` reçoit l'identifiant 0, puis les symboles sont numérotés à partir de 1 dans leur ordre
+    ///
+    /// Un symbole vide, contenant un blanc ou nommé `<eps>` ne peut pas figurer dans une table, et produit une erreur
+    /// `AutomatonError::Unsupported`.
+    pub fn from_alphabet(alphabet: &BTSet<Symbol>) -> Result<Self, AutomatonError> {
+        let mut table: SymbolTable = SymbolTable::new();
+        table.insert(EPSILON_NAME, 0);
+        for (i, symbol) in alphabet.get().iter().enumerate() {
+            check_symbol(symbol)?;
+            table.insert(symbol.get_value(), i + 1);
+        }
+        Ok(table)
+    }
+
+    /// Ajoute un symbole, retourne `false` si le nom ou l'identifiant est déjà utilisé, ou si le nom est vide ou contient un blanc
+    pub fn insert(&mut self, name: &str, id: usize) -> bool {
+        if name.is_empty() || name.chars().any(char::is_whitespace) || self.ids.contains_key(name) || self.names.contains_key(&id) {
+            return false;
+        }
+        self.ids.insert(name.to_string(), id);
+        self.names.insert(id, name.to_string());
+        true
+    }
+
+    /// Retourne l'identifiant d'un nom de symbole
+    pub fn get_id(&self, name: &str) -> Option<usize> {
+        self.ids.get(name).copied()
+    }
+
+    /// Retourne le nom associé à un identifiant
+    pub fn get_name(&self, id: usize) -> Option<&str> {
+        self.names.get(&id).map(String::as_str)
+    }
+
+    /// Retourne le nombre de symboles de la table
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    /// Indique si la table est vide
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    /// Lit une table au format `.syms`: une ligne `nom identifiant` par symbole
+    pub fn from_syms(content: &str) -> Result<Self, AutomatonError> {
+        let mut table: SymbolTable = SymbolTable::new();
+        for (i, line) in content.lines().enumerate() {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            match fields[..] {
+                [] => {}
+                [name, id] => {
+                    let id: usize = id.parse().map_err(|_| malformed(i + 1, &format!("identifiant \"{}\" invalide", id)))?;
+                    if !table.insert(name, id) {
+                        return Err(malformed(i + 1, &format!("le symbole \"{}\" ou l'identifiant {} est déjà défini", name, id)));
+                    }
+                }
+                _ => return Err(malformed(i + 1, "une ligne de la table doit contenir un nom et un identifiant")),
+            }
+        }
+        Ok(table)
+    }
+
+    /// Lit une table depuis un fichier `.syms`
+    pub fn from_syms_file(path: &str) -> Result<Self, AutomatonError> {
+        SymbolTable::from_syms(&read_file(path)?)
+    }
+
+    /// Ecrit la table au format `.syms`, par identifiant croissant
+    pub fn to_syms(&self) -> String {
+        let mut syms: String = String::new();
+        for (id, name) in &self.names {
+            writeln!(syms, "{}\t{}", name, id).unwrap();
+        }
+        syms
+    }
+
+    /// Ecrit la table dans un fichier `.syms`
+    pub fn to_syms_file(&self, path: &str) -> Result<(), AutomatonError> {
+        write_file(path, &self.to_syms())
+    }
+}
+
+/// La forme des transitions d'un texte AT&T, comme l'option `--acceptor` de `fstcompile`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum AttMode {
+    /// Les transitions sont `source destination etiquette [poids]`
+    #[default]
+    Acceptor,
+    /// Les transitions sont `source destination etiquette_entree etiquette_sortie [poids]`, les deux étiquettes devant être égales
+    Transducer,
+}
+
+/// L'import et l'export d'automates au format texte AT&T d'OpenFst
+///
+/// Chaque ligne est soit une transition, dont la forme dépend de `AttMode`, soit un état final `etat [poids]`.
+/// L'état initial est la source de la première ligne. Les états sont nommés par leur identifiant dans le texte, et
+/// les poids sont ignorés, sauf le poids `Infinity` (le zéro du semi-anneau tropical) qui retire la transition ou
+/// rend l'état non final. Une étiquette de sortie différente de l'étiquette d'entrée et les transitions ε
+/// (identifiant 0) sont refusées par une erreur `AutomatonError::Unsupported`.
+pub trait AutomateAtt: Sized {
+    /// Créer un automate depuis un texte AT&T
+    ///
+    /// Sans table, les étiquettes sont des identifiants numériques dont la valeur devient celle du symbole, et `0` est ε.
+    /// Avec une table, chaque étiquette est un nom de symbole de la table (comme pour `fstcompile --isymbols`), ou à
+    /// défaut un identifiant de la table; le symbole d'identifiant 0 est ε.
+    fn from_att(content: &str, symbols: Option<&SymbolTable>, mode: AttMode) -> Result<Self, AutomatonError>;
+
+    /// Créer un automate depuis un fichier AT&T
+    fn from_att_file(path: &str, symbols: Option<&SymbolTable>, mode: AttMode) -> Result<Self, AutomatonError> {
+        Self::from_att(&read_file(path)?, symbols, mode)
+    }
+
+    /// Convertit l'automate au format AT&T d'un accepteur (`AttMode::Acceptor`), les étiquettes étant les noms des symboles
+    ///
+    /// L'état initial reçoit l'identifiant 0, puis les autres états sont numérotés dans l'ordre de leurs noms. La table
+    /// `SymbolTable::from_alphabet(automate.get_alphabet())` permet de compiler le texte avec `fstcompile --acceptor --isymbols`.
+    /// Un symbole vide, contenant un blanc ou nommé `<eps>` rendrait le texte illisible, et produit une erreur
+    /// `AutomatonError::Unsupported`.
+    fn to_att(&self) -> Result<String, AutomatonError>;
+
+    /// Ecrit l'automate dans un fichier AT&T
+    fn to_att_file(&self, path: &str) -> Result<(), AutomatonError> {
+        write_file(path, &self.to_att()?)
+    }
+}
+
+// indique si un poids facultatif est le zéro du semi-anneau tropical, `Infinity`
+fn is_zero(line: usize, weight: Option<&&str>) -> Result<bool, AutomatonError> {
+    match weight {
+        None => Ok(false),
+        Some(weight) if weight.eq_ignore_ascii_case("infinity") || weight.eq_ignore_ascii_case("inf") => Ok(true),
+        Some(weight) => match weight.parse::<f64>() {
+            Ok(_) => Ok(false),
+            Err(_) => Err(malformed(line, &format!("le poids \"{}\" n'est pas un nombre", weight))),
+        },
+    }
+}
+
+// le contenu d'un texte AT&T
+struct Att {
+    start: Option<State>,
+    states: BTSet<State>,
+    ends: BTSet<State>,
+    alphabet: BTSet<Symbol>,
+    delta: HashMap<Transition<State>, BTSet<State>>,
+}
+
+impl Att {
+    fn read(content: &str, symbols: Option<&SymbolTable>, mode: AttMode) -> Result<Att, AutomatonError> {
+        let mut att: Att = Att { start: None, states: BTSet::new(), ends: BTSet::new(), alphabet: BTSet::new(), delta: HashMap::new() };
+        // retourne le symbole d'une etiquette, None pour ε
+        let label = |line: usize, label: &str| -> Result<Option<Symbol>, AutomatonError> {
+            let name: &str = match symbols {
+                None => {
+                    label.parse::<usize>().map_err(|_| malformed(line, &format!("l'étiquette \"{}\" n'est pas un identifiant numérique", label)))?;
+                    label
+                }
+                Some(table) => match (table.get_id(label), label.parse::<usize>().ok().and_then(|id| table.get_name(id))) {
+                    (Some(_), _) => label,
+                    (None, Some(name)) => name,
+                    (None, None) => return Err(malformed(line, &format!("l'étiquette \"{}\" n'est pas dans la table de symboles", label))),
+                },
+            };
+            let is_epsilon: bool = match symbols {
+                None => name == "0",
+                Some(table) => table.get_id(name) == Some(0),
+            };
+            Ok(if is_epsilon { None } else { Some(Symbol::from_str(name)) })
+        };
+        for (i, line) in content.lines().enumerate() {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            if fields.is_empty() {
+                continue;
+            }
+            let source: State = State::from_str(fields[0]);
+            if att.start.is_none() {
+                att.start = Some(source.clone());
+            }
+            att.states.insert(source.clone());
+            if fields.len() <= 2 {
+                // un etat final, avec un poids facultatif
+                if !is_zero(i + 1, fields.get(1))? {
+                    att.ends.insert(source);
+                }
+                continue;
+            }
+            let (input, output, weight): (&str, Option<&str>, Option<&&str>) = match (mode, &fields[..]) {
+                (AttMode::Acceptor, [_, _, input]) => (input, None, None),
+                (AttMode::Acceptor, [_, _, input, weight]) => (input, None, Some(weight)),
+                (AttMode::Transducer, [_, _, input, output]) => (input, Some(output), None),
+                (AttMode::Transducer, [_, _, input, output, weight]) => (input, Some(output), Some(weight)),
+                (AttMode::Acceptor, _) => return Err(malformed(i + 1, "une transition d'accepteur a trois ou quatre champs")),
+                (AttMode::Transducer, _) => return Err(malformed(i + 1, "une transition de transducteur a quatre ou cinq champs")),
+            };
+            let target: State = State::from_str(fields[1]);
+            att.states.insert(target.clone());
+            let symbol: Option<Symbol> = label(i + 1, input)?;
+            if let Some(output) = output {
+                if label(i + 1, output)? != symbol {
+                    return Err(AutomatonError::Unsupported { message: format!("ligne {}: les transducteurs ne sont pas supportés, l'étiquette de sortie doit être celle d'entrée", i + 1) });
+                }
+            }
+            let symbol: Symbol = symbol.ok_or(AutomatonError::Unsupported { message: format!("ligne {}: les transitions ε ne sont pas supportées", i + 1) })?;
+            att.alphabet.insert(symbol.clone());
+            // une transition de poids nul ne peut pas etre empruntée
+            if is_zero(i + 1, weight)? {
+                continue;
+            }
+            att.delta.entry(Transition::new(symbol, source)).or_default().insert(target);
+        }
+        Ok(att)
+    }
+}
+
+impl AutomateAtt for DFA {
+    /// ```
+    /// use automaters::*;
+    /// fn main() {
+    ///     let dfa : DFA = DFA::from_json_file("src/automates/DFA2.json");
+    ///     let symbols : SymbolTable = SymbolTable::from_alphabet(dfa.get_alphabet()).unwrap();
+    ///     let dfa2 : DFA = DFA::from_att(&dfa.to_att().unwrap(), Some(&symbols), AttMode::Acceptor).unwrap();
+    ///     assert!(equivalent(&dfa, &dfa2).is_ok());
+    ///     // sans table, les étiquettes sont des identifiants numériques
+    ///     let dfa3 : DFA = DFA::from_att("0 1 3\n1 1 4 0.5\n1\n", None, AttMode::Acceptor).unwrap();
+    ///     assert!(dfa3.accept("3") && !dfa3.accept("4"));
+    ///     // un transducteur dont les étiquettes de sortie sont celles d'entrée
+    ///     let dfa4 : DFA = DFA::from_att("0 1 3 3\n1\n", None, AttMode::Transducer).unwrap();
+    ///     assert!(dfa4.accept("3"));
+    /// }
+    /// ```
+    fn from_att(content: &str, symbols: Option<&SymbolTable>, mode: AttMode) -> Result<Self, AutomatonError> {
+        let att: Att = Att::read(content, symbols, mode)?;
+        let start: State = att.start.ok_or(AutomatonError::MalformedAtt { message: "le texte ne contient aucun état".to_string() })?;
+        let mut delta: HashMap<Transition<State>, State> = HashMap::new();
+        for (transition, images) in att.delta {
+            if images.len() > 1 {
+                return Err(AutomatonError::Unsupported {
+                    message: format!("l'état \"{}\" a plusieurs transitions par \"{}\", il doit être chargé en NDFA", transition.get_content().get_name(), transition.get_symbol().get_value()),
+                });
+            }
+            let image: State = images.get().iter().next().unwrap().clone();
+            delta.insert(transition, image);
+        }
+        Ok(DFA::new(start, delta, FSM::new(att.states, att.alphabet, att.ends)))
+    }
+
+    fn to_att(&self) -> Result<String, AutomatonError> {
+        let edges: Vec<(State, Symbol, State)> = self
+            .get_delta()
+            .iter()
+            .map(|(transition, image)| (transition.get_content().clone(), transition.get_symbol().clone(), image.clone()))
+            .collect();
+        write(self.get_start(), self.get_states(), self.get_ends(), edges)
+    }
+}
+
+impl AutomateAtt for NDFA {
+    /// Le format AT&T n'a qu'un état initial: s'il y en a plusieurs, un nouvel état initial `init` est ajouté, avec
+    /// les transitions de chacun d'eux, et il est final si l'un d'eux l'est.
+    ///
+    /// ```
+    /// use automaters::*;
+    /// fn main() {
+    ///     // NFA3 a trois états initiaux
+    ///     let nfa : NDFA = NDFA::from_json_file("src/automates/NFA3.json");
+    ///     let att : String = nfa.to_att().unwrap();
+    ///     let nfa2 : NDFA = NDFA::from_att(&att, Some(&SymbolTable::from_alphabet(nfa.get_alphabet()).unwrap()), AttMode::Acceptor).unwrap();
+    ///     assert_eq!(nfa2.get_starts().len(), 1);
+    ///     assert!(equivalent(&nfa, &nfa2).is_ok());
+    /// }
+    /// ```
+    fn from_att(content: &str, symbols: Option<&SymbolTable>, mode: AttMode) -> Result<Self, AutomatonError> {
+        let att: Att = Att::read(content, symbols, mode)?;
+        let start: State = att.start.ok_or(AutomatonError::MalformedAtt { message: "le texte ne contient aucun état".to_string() })?;
+        Ok(NDFA::new(BTSet::new().from_vect(vec![start]), att.delta, FSM::new(att.states, att.alphabet, att.ends)))
+    }
+
+    fn to_att(&self) -> Result<String, AutomatonError> {
+        let mut edges: Vec<(State, Symbol, State)> = Vec::new();
+        for (transition, images) in self.get_delta() {
+            for image in images.get() {
+                edges.push((transition.get_content().clone(), transition.get_symbol().clone(), image.clone()));
+            }
+        }
+        let starts: Vec<&State> = self.get_starts().get().iter().collect();
+        if let [start] = starts[..] {
+            return write(start, self.get_states(), self.get_ends(), edges);
+        }
+        // un nouvel etat initial reprend les transitions de chaque etat initial
+        let mut all: BTSet<State> = self.get_states().clone();
+        all.insert_all(self.get_starts().clone());
+        // les extrémités des transitions sont écrites meme si elles ne sont pas declarées dans les etats
+        for (from, _, to) in &edges {
+            all.insert(from.clone());
+            all.insert(to.clone());
+        }
+        let start: State = fresh_state(&all, "init");
+        let copied: Vec<(State, Symbol, State)> = edges
+            .iter()
+            .filter(|(from, _, _)| self.get_starts().contains(from))
+            .map(|(_, symbol, to)| (start.clone(), symbol.clone(), to.clone()))
+            .collect();
+        edges.extend(copied);
+        let mut ends: BTSet<State> = self.get_ends().clone();
+        if starts.iter().any(|state| self.get_ends().contains(state)) {
+            ends.insert(start.clone());
+        }
+        write(&start, &all, &ends, edges)
+    }
+}
+
+// écrit un accepteur, l'etat initial ayant l'identifiant 0 et les autres etant numérotés dans l'ordre des noms
+fn write(start: &State, states: &BTSet<State>, ends: &BTSet<State>, mut edges: Vec<(State, Symbol, State)>) -> Result<String, AutomatonError> {
+    let mut order: Vec<State> = vec![start.clone()];
+    let mut all: BTSet<State> = states.clone();
+    for (from, symbol, to) in &edges {
+        check_symbol(symbol)?;
+        all.insert(from.clone());
+        all.insert(to.clone());
+    }
+    order.extend(all.get().iter().filter(|state| *state != start).cloned());
+    let ids: HashMap<&State, usize> = order.iter().enumerate().map(|(i, state)| (state, i)).collect();
+    edges.sort_by_key(|(from, symbol, to)| (ids[from], symbol.clone(), ids[to]));
+    let mut att: String = String::new();
+    // l'etat initial est la source de la premiere ligne: sans transition ni poids final, il est écrit non final
+    if !ends.contains(start) && !edges.iter().any(|(from, _, _)| from == start) {
+        att.push_str("0\tInfinity\n");
+    }
+    for (i, state) in order.iter().enumerate() {
+        for (_, symbol, to) in edges.iter().filter(|(from, _, _)| ids[from] == i) {
+            writeln!(att, "{}\t{}\t{}", i, ids[to], symbol.get_value()).unwrap();
+        }
+        if ends.contains(state) {
+            writeln!(att, "{}", i).unwrap();
+        }
+    }
+    Ok(att)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{equivalent, AutomateJsonIO, Regex};
+
+    #[test]
+    fn att_and_symbol_tables() {
+        // aller retour avec une table de symboles
+        for name in ["DFA1", "DFA2", "DFA3"] {
+            let dfa: DFA = DFA::from_json_file(&format!("src/automates/{}.json", name));
+            let symbols: SymbolTable = SymbolTable::from_alphabet(dfa.get_alphabet()).unwrap();
+            assert_eq!(SymbolTable::from_syms(&symbols.to_syms()).unwrap(), symbols);
+            let dfa2: DFA = DFA::from_att(&dfa.to_att().unwrap(), Some(&symbols), AttMode::Acceptor).unwrap();
+            assert!(equivalent(&dfa, &dfa2).is_ok(), "{}", name);
+            assert_eq!(dfa2.get_start(), &State::from_str("0"));
+        }
+        for name in ["NFA1", "NFA2", "NFA3", "NFATODFA"] {
+            let nfa: NDFA = NDFA::from_json_file(&format!("src/automates/{}.json", name));
+            let nfa2: NDFA = NDFA::from_att(&nfa.to_att().unwrap(), Some(&SymbolTable::from_alphabet(nfa.get_alphabet()).unwrap()), AttMode::Acceptor).unwrap();
+            assert!(equivalent(&nfa, &nfa2).is_ok(), "{}", name);
+        }
+        // les etiquettes sont les noms des symboles, l'etat initial est numéroté 0
+        let dfa: DFA = Regex::parse("ab*").unwrap().to_brzozowski();
+        assert_eq!(dfa.to_att().unwrap(), "0\t1\ta\n1\t1\tb\n1\n");
+        let symbols: SymbolTable = SymbolTable::from_alphabet(dfa.get_alphabet()).unwrap();
+        assert_eq!(symbols.to_syms(), "<eps>\t0\na\t1\nb\t2\n");
+        // un texte numérique lu avec une table, avec des poids
+        let nfa: NDFA = NDFA::from_att("0 1 1 0.5\n0 2 2\n1 1 2\n0 3 2 Infinity\n1 0.25\n2 Infinity\n", Some(&symbols), AttMode::Acceptor).unwrap();
+        assert!(equivalent(&nfa, &Regex::parse("ab*").unwrap().to_thompson()).is_ok());
+        assert!(nfa.get_states().contains(&State::from_str("2")));
+        assert!(!nfa.get_ends().contains(&State::from_str("2")));
+        // le meme texte écrit comme un transducteur
+        let nfa: NDFA = NDFA::from_att("0 1 1 1 0.5\n0 2 2 2\n1 1 2 2\n1 0.25\n", Some(&symbols), AttMode::Transducer).unwrap();
+        assert!(equivalent(&nfa, &Regex::parse("ab*").unwrap().to_thompson()).is_ok());
+        // un nom de symbole est prioritaire sur un identifiant
+        let digits: SymbolTable = SymbolTable::from_syms("<eps> 0\n2 1\n1 2\n").unwrap();
+        let nfa: NDFA = NDFA::from_att("0 1 1\n1\n", Some(&digits), AttMode::Acceptor).unwrap();
+        assert!(nfa.accept("1"));
+        // les fichiers
+        let path: String = std::env::temp_dir().join("automaters_att_test.syms").to_string_lossy().to_string();
+        symbols.to_syms_file(&path).unwrap();
+        assert_eq!(SymbolTable::from_syms_file(&path).unwrap(), symbols);
+        let path: String = std::env::temp_dir().join("automaters_att_test.fst.txt").to_string_lossy().to_string();
+        dfa.to_att_file(&path).unwrap();
+        assert!(equivalent(&DFA::from_att_file(&path, Some(&symbols), AttMode::Acceptor).unwrap(), &dfa).is_ok());
+        // un automate sans transition ni etat final
+        let empty: NDFA = NDFA::from_json_file("src/automates/NFA2.json");
+        let empty: NDFA = NDFA::new(empty.get_starts().clone(), HashMap::new(), FSM::new(empty.get_starts().clone(), BTSet::new(), BTSet::new()));
+        assert!(NDFA::from_att(&empty.to_att().unwrap(), None, AttMode::Acceptor).unwrap().get_ends().is_empty());
+        // un etat initial isolé et non final reste l'état initial
+        let s: State = State::from_str("s");
+        let q: State = State::from_str("q");
+        let mut delta: HashMap<Transition<State>, State> = HashMap::new();
+        delta.insert(Transition::new(Symbol::from_str("a"), q.clone()), q.clone());
+        let isolated: DFA = DFA::new(s.clone(), delta, FSM::new(BTSet::new().from_vect(vec![s, q.clone()]), BTSet::new().from_vect(vec![Symbol::from_str("a")]), BTSet::new().from_vect(vec![q])));
+        assert_eq!(isolated.to_att().unwrap(), "0\tInfinity\n1\t1\ta\n1\n");
+        let reloaded: DFA = DFA::from_att(&isolated.to_att().unwrap(), Some(&SymbolTable::from_alphabet(isolated.get_alphabet()).unwrap()), AttMode::Acceptor).unwrap();
+        assert!(!reloaded.accept("") && !reloaded.accept("a"));
+        assert!(equivalent(&isolated, &reloaded).is_ok());
+        // le nouvel etat initial ne se confond pas avec une image non declarée nommée init
+        let state = |name: &str| -> State { State::from_str(name) };
+        let delta: HashMap<Transition<State>, BTSet<State>> = HashMap::from([
+            (Transition::new(Symbol::from_str("a"), state("p")), BTSet::new().from_vect(vec![state("init")])),
+            (Transition::new(Symbol::from_str("b"), state("init")), BTSet::new().from_vect(vec![state("q")])),
+        ]);
+        let starts: BTSet<State> = BTSet::new().from_vect(vec![state("p"), state("q")]);
+        let alphabet: BTSet<Symbol> = BTSet::new().from_vect(vec![Symbol::from_str("a"), Symbol::from_str("b")]);
+        let colliding: NDFA = NDFA::new(starts.clone(), delta, FSM::new(starts, alphabet, BTSet::new().from_vect(vec![state("q")])));
+        let reloaded: NDFA = NDFA::from_att(&colliding.to_att().unwrap(), Some(&SymbolTable::from_alphabet(colliding.get_alphabet()).unwrap()), AttMode::Acceptor).unwrap();
+        assert!(!colliding.accept("b") && !reloaded.accept("b"));
+        assert!(equivalent(&colliding, &reloaded).is_ok());
+        // les erreurs
+        let unsupported = |att: &str, symbols: Option<&SymbolTable>, mode: AttMode| match NDFA::from_att(att, symbols, mode) {
+            Err(AutomatonError::Unsupported { message }) => message,
+            other => panic!("{:?}", other.map(|nfa| nfa.get_states().len())),
+        };
+        assert_eq!(unsupported("0 1 0\n", None, AttMode::Acceptor), "ligne 1: les transitions ε ne sont pas supportées");
+        assert_eq!(unsupported("0 1 a\n1 2 <eps>\n", Some(&symbols), AttMode::Acceptor), "ligne 2: les transitions ε ne sont pas supportées");
+        assert!(unsupported("0 1 a b\n", Some(&symbols), AttMode::Transducer).contains("transducteurs"));
+        // une étiquette de sortie numérique n'est pas prise pour un poids
+        assert!(unsupported("0 1 3 4\n1\n", None, AttMode::Transducer).contains("transducteurs"));
+        assert!(matches!(DFA::from_att("0 1 1\n0 2 1\n", None, AttMode::Acceptor), Err(AutomatonError::Unsupported { .. })));
+        let malformed = |att: &str, symbols: Option<&SymbolTable>| match NDFA::from_att(att, symbols, AttMode::Acceptor) {
+            Err(AutomatonError::MalformedAtt { message }) => message,
+            other => panic!("{:?}", other.map(|nfa| nfa.get_states().len())),
+        };
+        assert_eq!(malformed("0 1 a\n", None), "ligne 1: l'étiquette \"a\" n'est pas un identifiant numérique");
+        assert_eq!(malformed("\n0 1 c\n", Some(&symbols)), "ligne 2: l'étiquette \"c\" n'est pas dans la table de symboles");
+        assert_eq!(malformed("0 1 1 1 0\n", None), "ligne 1: une transition d'accepteur a trois ou quatre champs");
+        assert_eq!(malformed("0 1 a b\n", Some(&symbols)), "ligne 1: le poids \"b\" n'est pas un nombre");
+        assert!(matches!(NDFA::from_att("0 1 1\n", None, AttMode::Transducer), Err(AutomatonError::MalformedAtt { .. })));
+        assert_eq!(malformed("", None), "le texte ne contient aucun état");
+        assert!(matches!(SymbolTable::from_syms("a 1\nb 1\n"), Err(AutomatonError::MalformedAtt { .. })));
+        assert!(matches!(SymbolTable::from_syms("a\n"), Err(AutomatonError::MalformedAtt { .. })));
+        assert!(matches!(SymbolTable::from_syms("a x\n"), Err(AutomatonError::MalformedAtt { .. })));
+        // les symboles qui ne peuvent pas etre écrits
+        for value in ["a b", "<eps>", ""] {
+            let symbol: Symbol = Symbol::from_str(value);
+            let dfa: DFA = DFA::new(State::from_str("q"), HashMap::from([(Transition::new(symbol.clone(), State::from_str("q")), State::from_str("q"))]), FSM::new(BTSet::new().from_vect(vec![State::from_str("q")]), BTSet::new().from_vect(vec![symbol]), BTSet::new()));
+            assert!(matches!(dfa.to_att(), Err(AutomatonError::Unsupported { .. })), "{}", value);
+            assert!(matches!(SymbolTable::from_alphabet(dfa.get_alphabet()), Err(AutomatonError::Unsupported { .. })), "{}", value);
+        }
+        assert!(!SymbolTable::new().insert("a b", 1));
+    }
+}
@@ -6,6 +6,12 @@ pub struct BTSet<T : Clone+ Eq +PartialEq+ Ord+ PartialOrd> {
     set: BTreeSet<T>,
 }
 
+impl<T : Clone+ Eq +PartialEq+ Ord+ PartialOrd + Hash> Default for BTSet<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<T : Clone+ Eq +PartialEq+ Ord+ PartialOrd + Hash> BTSet<T> {  
     pub fn new() -> Self{
         let mut _set : BTreeSet<T> = BTreeSet::new();
@@ -14,6 +20,7 @@ impl<T : Clone+ Eq +PartialEq+ Ord+ PartialOrd + Hash> BTSet<T> {
         }
     }
     
+    #[allow(clippy::needless_return)]
     pub fn from_vect(&self, v: Vec<T>) -> BTSet<T> {
         let mut btset :BTSet<T>= BTSet::new();
         // recopie de l'element
@@ -41,6 +48,7 @@ impl<T : Clone+ Eq +PartialEq+ Ord+ PartialOrd + Hash> BTSet<T> {
     }
 
     // renvoie la difference entre self et other
+    #[allow(clippy::extra_unused_lifetimes, clippy::needless_borrow)]
     pub fn difference<'a>(&self, other :BTSet<T>) -> BTSet<T>{
         let _other:&BTreeSet<T> = &other.get(); // &other.get().clone();
         let _difference:Difference<T> = self.set.difference(_other); 
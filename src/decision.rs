@@ -0,0 +1,395 @@
+use crate::equivalence::{rebuild_word, IndexedNDFA};
+use crate::{AutomateNdfa, AutomateTrait, Symbol, NDFA};
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+use std::fmt::Debug;
+use std::hash::Hash;
+
+/// Un cycle que l'on peut répéter autant de fois que voulu en restant dans le langage
+///
+/// Pour tout n, le mot `prefix · cycle^n · suffix` est accepté par l'automate.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PumpingWitness {
+    prefix: Vec<Symbol>,
+    cycle: Vec<Symbol>,
+    suffix: Vec<Symbol>,
+}
+
+impl PumpingWitness {
+    /// Retourne le mot menant au début du cycle
+    pub fn get_prefix(&self) -> &Vec<Symbol> {
+        &self.prefix
+    }
+
+    /// Retourne le mot lu le long du cycle, jamais vide
+    pub fn get_cycle(&self) -> &Vec<Symbol> {
+        &self.cycle
+    }
+
+    /// Retourne le mot menant de la fin du cycle à un état final
+    pub fn get_suffix(&self) -> &Vec<Symbol> {
+        &self.suffix
+    }
+
+    /// Construit le mot accepté obtenu en répétant `n` fois le cycle
+    pub fn pump(&self, n: usize) -> Vec<Symbol> {
+        let mut word: Vec<Symbol> = self.prefix.clone();
+        for _ in 0..n {
+            word.extend(self.cycle.iter().cloned());
+        }
+        word.extend(self.suffix.iter().cloned());
+        word
+    }
+}
+
+impl fmt::Display for PumpingWitness {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let concat = |word: &Vec<Symbol>| -> String { word.iter().map(|symbol| symbol.get_value().as_str()).collect() };
+        write!(f, "{}({})*{}", concat(&self.prefix), concat(&self.cycle), concat(&self.suffix))
+    }
+}
+
+/// Le résultat du test de finitude d'un langage
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Finiteness {
+    /// Le langage est fini, `longest` est l'un de ses plus longs mots (`None` si le langage est vide)
+    Finite { longest: Option<Vec<Symbol>> },
+    /// Le langage est infini, le témoin donne un cycle que l'on peut répéter
+    Infinite(PumpingWitness),
+}
+
+/// Les procédures de décision sur le langage reconnu par un automate
+///
+/// Les mots sont donnés sous forme de suites de symboles, et l'univers des mots est celui construit sur
+/// `get_alphabet()` (sans `ε`). Les témoins retournés sont des plus courts mots lorsque cela a un sens.
+pub trait AutomateDecision<T: Clone + Hash + Debug>: AutomateTrait<T> + AutomateNdfa {
+    /// Retourne un plus court mot accepté par l'automate, ou `None` si le langage est vide
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use automaters::*;
+    /// fn main() {
+    ///     // DFA2 accepte les mots contenant 01 ou 10
+    ///     let dfa : DFA = DFA::from_json_file("src/automates/DFA2.json");
+    ///     assert_eq!(dfa.find_accepted(), Some(vec![Symbol::from_str("0"), Symbol::from_str("1")]));
+    /// }
+    /// ```
+    fn find_accepted(&self) -> Option<Vec<Symbol>> {
+        let (alphabet, nfa): (Vec<Symbol>, IndexedNDFA) = index(self);
+        let allowed: Vec<bool> = vec![true; nfa.finals.len()];
+        shortest_word(&nfa, &alphabet, &nfa.starts, &allowed, |q| nfa.finals[q], false)
+    }
+
+    /// Indique si l'automate n'accepte aucun mot
+    fn is_empty(&self) -> bool {
+        self.find_accepted().is_none()
+    }
+
+    /// Retourne un plus court mot sur l'alphabet refusé par l'automate, ou `None` s'il accepte tout les mots
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use automaters::*;
+    /// fn main() {
+    ///     let nfa : NDFA = Regex::parse("(a|b)*").unwrap().to_antimirov();
+    ///     assert!(nfa.is_universal());
+    ///     let nfa : NDFA = Regex::parse("(a|b)*a|ε").unwrap().to_antimirov();
+    ///     assert_eq!(nfa.find_rejected(), Some(vec![Symbol::from_str("b")]));
+    /// }
+    /// ```
+    fn find_rejected(&self) -> Option<Vec<Symbol>> {
+        let (alphabet, nfa): (Vec<Symbol>, IndexedNDFA) = index(self);
+        // parcours en largeur des sets d'etats atteints, le set vide etant un set comme un autre
+        let mut start: Vec<usize> = nfa.starts.clone();
+        start.sort_unstable();
+        start.dedup();
+        let mut seen: HashMap<Vec<usize>, usize> = HashMap::new();
+        let mut nodes: Vec<Vec<usize>> = Vec::new();
+        let mut parents: Vec<Option<(usize, usize)>> = Vec::new();
+        let mut queue: VecDeque<usize> = VecDeque::new();
+        seen.insert(start.clone(), 0);
+        nodes.push(start);
+        parents.push(None);
+        queue.push_back(0);
+        while let Some(node) = queue.pop_front() {
+            if !nfa.any_final(&nodes[node]) {
+                return Some(rebuild_word(&parents, &alphabet, node));
+            }
+            for a in 0..alphabet.len() {
+                let next: Vec<usize> = nfa.step(&nodes[node], a);
+                if !seen.contains_key(&next) {
+                    seen.insert(next.clone(), nodes.len());
+                    queue.push_back(nodes.len());
+                    nodes.push(next);
+                    parents.push(Some((node, a)));
+                }
+            }
+        }
+        None
+    }
+
+    /// Indique si l'automate accepte tout les mots sur son alphabet
+    fn is_universal(&self) -> bool {
+        self.find_rejected().is_none()
+    }
+
+    /// Décide si le langage est fini
+    ///
+    /// Le langage est infini si et seulement si un cycle passe par un état à la fois accessible et co-accessible.
+    /// Sinon, les états utiles forment un graphe sans cycle dont le plus long chemin donne le plus long mot.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use automaters::*;
+    /// fn main() {
+    ///     let nfa : NDFA = Regex::parse("ab|a|abc").unwrap().to_antimirov();
+    ///     let abc : Vec<Symbol> = ["a", "b", "c"].iter().map(|value| Symbol::from_str(value)).collect();
+    ///     assert_eq!(nfa.finiteness(), Finiteness::Finite { longest: Some(abc) });
+    ///     let dfa : DFA = Regex::parse("ab*c").unwrap().to_brzozowski();
+    ///     match dfa.finiteness() {
+    ///         Finiteness::Infinite(witness) => assert_eq!(witness.to_string(), "a(b)*c"),
+    ///         Finiteness::Finite { .. } => panic!("le langage est infini"),
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Return
+    ///
+    /// * `Finiteness` - Le plus long mot si le langage est fini, un cycle que l'on peut répéter sinon
+    ///
+    fn finiteness(&self) -> Finiteness {
+        let (alphabet, nfa): (Vec<Symbol>, IndexedNDFA) = index(self);
+        let useful: Vec<bool> = useful_states(&nfa);
+        let everywhere: Vec<bool> = vec![true; useful.len()];
+        // un cycle passant par un etat utile q, le plus court parmi ceux passant par q
+        for q in (0..useful.len()).filter(|&q| useful[q]) {
+            if let Some(cycle) = shortest_word(&nfa, &alphabet, &[q], &useful, |p| p == q, true) {
+                return Finiteness::Infinite(PumpingWitness {
+                    prefix: shortest_word(&nfa, &alphabet, &nfa.starts, &everywhere, |p| p == q, false).unwrap(),
+                    cycle,
+                    suffix: shortest_word(&nfa, &alphabet, &[q], &everywhere, |p| nfa.finals[p], false).unwrap(),
+                });
+            }
+        }
+        Finiteness::Finite { longest: longest_word(&nfa, &alphabet, &useful) }
+    }
+
+    /// Indique si l'automate accepte un nombre fini de mots
+    fn is_finite(&self) -> bool {
+        matches!(self.finiteness(), Finiteness::Finite { .. })
+    }
+}
+
+impl<T: Clone + Hash + Debug, A: AutomateTrait<T> + AutomateNdfa> AutomateDecision<T> for A {}
+
+// convertit l'automate en NDFA indexé sur son alphabet, sans ε
+fn index<T, A>(automate: &A) -> (Vec<Symbol>, IndexedNDFA)
+where
+    T: Clone + Hash + Debug,
+    A: AutomateTrait<T> + AutomateNdfa + ?Sized,
+{
+    let nfa: NDFA = automate.to_ndfa();
+    let alphabet: Vec<Symbol> = nfa.get_alphabet().get().iter().cloned().collect();
+    let indexed: IndexedNDFA = IndexedNDFA::new(&nfa, &alphabet);
+    (alphabet, indexed)
+}
+
+// plus court mot menant d'un etat de `from` à un etat vérifiant `target`, en ne passant que par les etats autorisés
+// si `nonempty` est vrai, le mot vide n'est pas retenu, ce qui permet de chercher un cycle
+fn shortest_word(nfa: &IndexedNDFA, alphabet: &[Symbol], from: &[usize], allowed: &[bool], target: impl Fn(usize) -> bool, nonempty: bool) -> Option<Vec<Symbol>> {
+    let mut parents: Vec<Option<(usize, usize)>> = vec![None; allowed.len()];
+    let mut visited: Vec<bool> = vec![false; allowed.len()];
+    let mut queue: VecDeque<usize> = VecDeque::new();
+    for &q in from {
+        if allowed[q] && !visited[q] {
+            if !nonempty && target(q) {
+                return Some(Vec::new());
+            }
+            visited[q] = true;
+            queue.push_back(q);
+        }
+    }
+    while let Some(q) = queue.pop_front() {
+        for a in 0..alphabet.len() {
+            for &next in &nfa.transitions[q][a] {
+                if !allowed[next] {
+                    continue;
+                }
+                if target(next) {
+                    let mut word: Vec<Symbol> = rebuild_word(&parents, alphabet, q);
+                    word.push(alphabet[a].clone());
+                    return Some(word);
+                }
+                if !visited[next] {
+                    visited[next] = true;
+                    parents[next] = Some((q, a));
+                    queue.push_back(next);
+                }
+            }
+        }
+    }
+    None
+}
+
+// les etats accessibles depuis un etat initial et co-accessibles vers un etat final
+pub(crate) fn useful_states(nfa: &IndexedNDFA) -> Vec<bool> {
+    let count: usize = nfa.finals.len();
+    let mut predecessors: Vec<Vec<usize>> = vec![Vec::new(); count];
+    for (q, images) in nfa.transitions.iter().enumerate() {
+        for &next in images.iter().flatten() {
+            predecessors[next].push(q);
+        }
+    }
+    let mut accessible: Vec<bool> = vec![false; count];
+    let mut stack: Vec<usize> = nfa.starts.clone();
+    while let Some(q) = stack.pop() {
+        if !accessible[q] {
+            accessible[q] = true;
+            stack.extend(nfa.transitions[q].iter().flatten().copied());
+        }
+    }
+    let mut coaccessible: Vec<bool> = vec![false; count];
+    let mut stack: Vec<usize> = (0..count).filter(|&q| nfa.finals[q]).collect();
+    while let Some(q) = stack.pop() {
+        if !coaccessible[q] {
+            coaccessible[q] = true;
+            stack.extend(predecessors[q].iter().copied());
+        }
+    }
+    (0..count).map(|q| accessible[q] && coaccessible[q]).collect()
+}
+
+// plus long mot accepté lorsque les etats utiles ne forment aucun cycle, ou None si aucun etat n'est utile
+fn longest_word(nfa: &IndexedNDFA, alphabet: &[Symbol], useful: &[bool]) -> Option<Vec<Symbol>> {
+    let count: usize = useful.len();
+    // ordre topologique des etats utiles (algorithme de Kahn)
+    let mut incoming: Vec<usize> = vec![0; count];
+    for q in (0..count).filter(|&q| useful[q]) {
+        for &next in nfa.transitions[q].iter().flatten() {
+            if useful[next] {
+                incoming[next] += 1;
+            }
+        }
+    }
+    let mut order: Vec<usize> = Vec::new();
+    let mut ready: Vec<usize> = (0..count).filter(|&q| useful[q] && incoming[q] == 0).collect();
+    while let Some(q) = ready.pop() {
+        order.push(q);
+        for &next in nfa.transitions[q].iter().flatten() {
+            if useful[next] {
+                incoming[next] -= 1;
+                if incoming[next] == 0 {
+                    ready.push(next);
+                }
+            }
+        }
+    }
+    // longest[q] est la longueur du plus long mot menant de q à un etat final, et choice[q] la transition choisie
+    let mut longest: Vec<Option<usize>> = vec![None; count];
+    let mut choice: Vec<Option<(usize, usize)>> = vec![None; count];
+    for &q in order.iter().rev() {
+        if nfa.finals[q] {
+            longest[q] = Some(0);
+        }
+        for (a, images) in nfa.transitions[q].iter().enumerate() {
+            for &next in images {
+                if let Some(length) = longest[next].filter(|_| useful[next]) {
+                    if longest[q].iter().all(|&current| length + 1 > current) {
+                        longest[q] = Some(length + 1);
+                        choice[q] = Some((a, next));
+                    }
+                }
+            }
+        }
+    }
+    let mut state: usize = nfa.starts.iter().copied().filter(|&q| useful[q]).max_by_key(|&q| longest[q])?;
+    let mut word: Vec<Symbol> = Vec::new();
+    while let Some((a, next)) = choice[state] {
+        word.push(alphabet[a].clone());
+        state = next;
+    }
+    Some(word)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{AutomateJsonIO, NDFAEpsilon, Regex, DFA};
+    use crate::test_utils::{concat, undeclared_intermediate, undeclared_intermediate_epsilon, words};
+
+    #[test]
+    fn decision_procedures() {
+        // les témoins correspondent à une recherche exhaustive des mots les plus courts
+        for pattern in ["∅", "ε", "(a|b)*", "(a|b)*a|ε", "ab|a|abc", "a(b|ε)c", "ab*c", "(ab)*a?|b(a|b)*", "a∅|b"] {
+            let regex: Regex = Regex::parse(pattern).unwrap();
+            let nfae: NDFAEpsilon = regex.to_thompson();
+            let alphabet: Vec<String> = regex.symbols().get().iter().map(|symbol| symbol.get_value().clone()).collect();
+            let alphabet: Vec<&str> = alphabet.iter().map(String::as_str).collect();
+            let candidates: Vec<String> = words(&alphabet, 6);
+            let accepted: Option<&String> = candidates.iter().find(|word| nfae.accept(word));
+            let rejected: Option<&String> = candidates.iter().find(|word| !nfae.accept(word));
+            for (found, expected) in [(nfae.find_accepted(), accepted), (regex.to_glushkov().0.find_accepted(), accepted), (regex.to_brzozowski().find_accepted(), accepted)] {
+                assert_eq!(found.map(|word| concat(&word).len()), expected.map(String::len), "{}", pattern);
+            }
+            for (found, expected) in [(nfae.find_rejected(), rejected), (regex.to_antimirov().find_rejected(), rejected), (regex.to_brzozowski().find_rejected(), rejected)] {
+                let found: Option<String> = found.map(|word| concat(&word));
+                assert_eq!(found.as_ref().map(String::len), expected.map(String::len), "{}", pattern);
+                assert!(found.iter().all(|word| !nfae.accept(word)), "{}", pattern);
+            }
+            // un langage fini n'a aucun mot plus long que son plus long mot
+            match nfae.finiteness() {
+                Finiteness::Finite { longest } => {
+                    let length: Option<usize> = candidates.iter().filter(|word| nfae.accept(word)).map(String::len).max();
+                    assert_eq!(longest.as_ref().map(Vec::len), length, "{}", pattern);
+                    assert!(longest.iter().all(|word| nfae.accept(&concat(word))), "{}", pattern);
+                }
+                Finiteness::Infinite(witness) => {
+                    assert!(!witness.get_cycle().is_empty());
+                    for n in 0..4 {
+                        assert!(nfae.accept(&concat(&witness.pump(n))), "{} {}", pattern, witness);
+                    }
+                }
+            }
+        }
+        assert!(Regex::parse("∅").unwrap().to_thompson().is_empty());
+        assert!(Regex::parse("ε").unwrap().to_thompson().is_universal());
+        assert!(Regex::parse("ab|a|abc").unwrap().to_brzozowski().is_finite());
+        assert!(!Regex::parse("(ab)*a?|b").unwrap().to_glushkov().0.is_finite());
+        // NFA2e n'a aucun état final, DFA2 accepte les mots contenant 01 ou 10
+        assert!(NDFAEpsilon::from_json_file("src/automates/NFA2e.json").is_empty());
+        assert!(NDFAEpsilon::from_json_file("src/automates/NFA2e.json").is_finite());
+        let dfa: DFA = DFA::from_json_file("src/automates/DFA2.json");
+        assert_eq!(dfa.find_rejected(), Some(Vec::new()));
+        assert!(!dfa.is_finite());
+        // un cycle inutile ne rend pas le langage infini
+        let dfa: DFA = DFA::from_json(&serde_json::json!({
+            "states" : ["A", "B", "C"],
+            "alphabet" : ["a"],
+            "ends" : ["B"],
+            "start" : "A",
+            "delta" : [
+                {"state" : "A", "symbol" : "a", "image" : "B"},
+                {"state" : "B", "symbol" : "a", "image" : "C"},
+                {"state" : "C", "symbol" : "a", "image" : "C"}
+            ]
+        }));
+        assert_eq!(dfa.finiteness(), Finiteness::Finite { longest: Some(vec![Symbol::from_str("a")]) });
+    }
+
+    #[test]
+    fn undeclared_intermediate_state() {
+        // l'etat t n'est pas declaré mais le mot "ab" passe par lui
+        let dfa: DFA = undeclared_intermediate();
+        let ab: Vec<Symbol> = vec![Symbol::from_str("a"), Symbol::from_str("b")];
+        assert!(dfa.accept("ab"));
+        assert!(!dfa.is_empty());
+        assert_eq!(dfa.find_accepted(), Some(ab.clone()));
+        assert_eq!(dfa.finiteness(), Finiteness::Finite { longest: Some(ab.clone()) });
+        let nfae: NDFAEpsilon = undeclared_intermediate_epsilon();
+        assert!(!nfae.is_empty());
+        assert_eq!(nfae.find_accepted(), Some(ab));
+    }
+}
@@ -1,9 +1,11 @@
-use crate::{NDFA, AutomateTrait, AutomateJsonIO};
+use crate::{NDFA, AutomateTrait, AutomateJsonIO, AutomatonError};
+use crate::json;
+use crate::interfaces::ToNdfa;
 
 use super::{Transition, State,Symbol, FSM, BTSet};
-use std::collections::{HashMap};
-use std::fs;
-use serde_json::{Value, from_str};
+use crate::state::fresh_state;
+use std::collections::{HashMap, hash_map::Entry};
+use serde_json::{json, Value};
 
 /// Automate a état fini déterministe
 #[derive(Debug, Clone)]
@@ -78,6 +80,7 @@ impl DFA {
     ///
     /// * `DFA` - L'automate déterministe à état fini correspondante
     /// 
+    #[allow(clippy::tabs_in_doc_comments)]
     pub fn new(_start : State, _delta : HashMap<Transition<State>, State>, _fsm : FSM) -> Self {
         DFA{
             start : _start,
@@ -90,6 +93,131 @@ impl DFA {
         self.get_delta().get(&transition)
     }
 
+    /// Retourne les états déclarés, l'état initial et les extrémités des transitions, meme s'ils ne sont pas déclarés
+    pub(crate) fn used_states(&self) -> BTSet<State> {
+        let mut states : BTSet<State> = self.get_states().clone();
+        states.insert(self.get_start().clone());
+        for (transition, image) in self.get_delta() {
+            states.insert(transition.get_content().clone());
+            states.insert(image.clone());
+        }
+        states
+    }
+
+    /// Retourne les états accessibles depuis l'état initial
+    pub(crate) fn reachable_states(&self) -> BTSet<State> {
+        let mut reachable : BTSet<State> = BTSet::new();
+        let mut stack : Vec<State> = vec![self.get_start().clone()];
+        reachable.insert(self.get_start().clone());
+        while let Some(state) = stack.pop() {
+            for symbol in self.get_alphabet().get() {
+                if let Some(image) = self.apply_delta(Transition::new(symbol.clone(), state.clone())) {
+                    if reachable.insert(image.clone()) {
+                        stack.push(image.clone());
+                    }
+                }
+            }
+        }
+        reachable
+    }
+
+    /// Indique si l'automate est complet, c'est a dire si chaque état a une transition pour chaque symbole de l'alphabet
+    ///
+    /// L'état initial et les images des transitions sont des états, meme s'ils ne sont pas déclarés.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use automaters::*;
+    /// fn main() {
+    ///     let dfa : DFA = DFA::from_json_file("src/automates/DFA2.json");
+    ///     assert!(dfa.is_complete());
+    ///     let dfa : DFA = DFA::from_json_file("src/automates/DFA3.json");
+    ///     assert!(!dfa.is_complete());
+    /// }
+    /// ```
+    ///
+    /// # Return
+    ///
+    /// * `bool` - true si aucune transition ne manque
+    ///
+    pub fn is_complete(&self) -> bool {
+        self.used_states().get().iter().all(|state| {
+            self.get_alphabet().get().iter().all(|symbol| self.apply_delta(Transition::new(symbol.clone(), state.clone())).is_some())
+        })
+    }
+
+    /// Complete l'automate avec un état puits
+    ///
+    /// Chaque transition manquante mene au puits, qui boucle sur lui meme pour chaque symbole de l'alphabet.
+    /// Le puits n'est pas ajouté si l'automate est déjà complet.
+    ///
+    /// # Arguments
+    ///
+    /// * `_sink_name` - Le nom de l'état puits, qui ne doit pas être le nom d'un état existant
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use automaters::*;
+    /// fn main() {
+    ///     let dfa : DFA = DFA::from_json_file("src/automates/DFA3.json");
+    ///     let complete : DFA = dfa.complete("puits").unwrap();
+    ///     assert!(complete.is_complete());
+    ///     assert!(complete.get_states().contains(&State::from_str("puits")));
+    ///     assert!(dfa.complete("q_0").is_err());
+    /// }
+    /// ```
+    ///
+    /// # Return
+    ///
+    /// * `Result<DFA, AutomatonError>` - Le DFA complet, ou une erreur si le nom du puits est déjà utilisé
+    ///
+    pub fn complete(&self, _sink_name: &str) -> Result<DFA, AutomatonError> {
+        let sink : State = State::from_str(_sink_name);
+        if self.used_states().contains(&sink) {
+            return Err(AutomatonError::StateCollision { state: _sink_name.to_string() });
+        }
+        Ok(self.completed_with_sink(&sink))
+    }
+
+    /// Complete l'automate avec un état puits dont le nom est choisi pour ne pas entrer en collision
+    ///
+    /// # Return
+    ///
+    /// * `(DFA, State)` - Le DFA complet et le nom de l'état puits, qui n'est pas ajouté si l'automate était déjà complet
+    ///
+    pub(crate) fn completed_with_fresh_sink(&self) -> (DFA, State) {
+        let sink : State = fresh_state(&self.used_states(), "puits");
+        (self.completed_with_sink(&sink), sink)
+    }
+
+    // complete l'automate avec le puits donné, qui ne doit pas être un état existant
+    fn completed_with_sink(&self, sink : &State) -> DFA {
+        let mut _delta : HashMap<Transition<State>, State> = self.get_delta().clone();
+        // les images non declarées deviennent des états, complétés comme les autres
+        let mut _states : BTSet<State> = self.used_states();
+        let mut transition : Transition<State>;
+        for state in self.used_states().get() {
+            for symbol in self.get_alphabet().get() {
+                transition = Transition::new(symbol.clone(), state.clone());
+                if let Entry::Vacant(entry) = _delta.entry(transition) {
+                    // la transition manquante mene au puits
+                    entry.insert(sink.clone());
+                    _states.insert(sink.clone());
+                }
+            }
+        }
+        if _states.contains(sink) {
+            // le puits boucle sur lui meme
+            for symbol in self.get_alphabet().get() {
+                _delta.insert(Transition::new(symbol.clone(), sink.clone()), sink.clone());
+            }
+        }
+        let _fsm : FSM = FSM::new(_states, self.get_alphabet().clone(), self.get_ends().clone());
+        DFA::new(self.get_start().clone(), _delta, _fsm)
+    }
+
     /// Réalise la transposition de l'automate
     /// 
     /// ```
@@ -242,10 +370,117 @@ impl AutomateJsonIO for DFA{
     ///
     /// * `DFA` - L'automate déterministe à état fini correspondante
     /// 
+    #[allow(clippy::tabs_in_doc_comments)]
     fn from_json(content_json: &Value) -> Self {
+        match DFA::try_from_json(content_json) {
+            Ok(dfa) => dfa,
+            Err(error) => panic!("{}", error),
+        }
+    }
+
+    /// Créer un automate à état fini détérministe depuis un chemin vers un fichier json
+    /// 
+    /// # Arguments
+    ///
+    /// * `_start` - L'état initial de l'automate
+    /// * `_delta` - Une HashMap decrivant les differentes transition de l'automate
+    /// * `_fsm` - Une machine à état fini décrivant l'automate
+    ///
+    /// # Examples
+    /// 
+    /// Le contenu du json
+    /// 
+    /// ```json
+    /// {
+    ///     "states" : ["q_0","q_1"],
+    ///     "alphabet" : ["b","a"],
+    ///     "ends" : ["q_0"],
+    ///     "start" : "q_0", 
+    ///     "delta" : [
+    ///         {
+    ///             "state" : "q_0",         
+    ///             "symbol" : "a",         
+    ///             "image" : "q_1"         
+    ///         },         
+    ///         {         
+    ///             "state" : "q_1",         
+    ///             "symbol" : "b",         
+    ///             "image" : "q_0"        
+    ///         }
+    ///     ] 
+    /// }
+    /// 
+    /// ```
+    /// 
+    /// Le chargement dans le code
+    /// 
+    /// ```
+    /// use automaters::*;
+    /// use std::fs;
+    /// use serde_json::{Value, from_str};
+    /// fn main() {
+    /// 
+    ///     let link_file: &str = "src/automates/DFA1.json";
+    ///     let content_json: Value = {
+    ///         // Charge le contenu du fichier en tant que String
+    ///         let content : String = fs::read_to_string(link_file).unwrap();
+    ///         // Parse le texte en structure Json
+    ///         from_str::<Value>(&content).unwrap()
+    ///     };
+    ///     //creation depuis un lien
+    ///     let dfa : DFA = DFA::from_json_file(link_file);  
+    /// 
+    /// }
+    /// ```
+    /// 
+    /// # Return
+    ///
+    /// * `DFA` - L'automate déterministe à état fini correspondante
+    /// 
+    fn from_json_file(path: &str) -> Self {
+        match DFA::try_from_json_file(path) {
+            Ok(dfa) => dfa,
+            Err(error) => panic!("{}", error),
+        }
+    }
+
+    /// Créer un automate à état fini détérministe depuis du json sans paniquer
+    /// 
+    /// Les clés `states` et `alphabet` sont facultatives, mais lorsque `states` est présente, 
+    /// tout les états utilisés par `start`, `ends` et `delta` doivent y être déclarés.
+    /// 
+    /// # Arguments
+    ///
+    /// * `content_json` - Le contenu json
+    ///
+    /// # Examples
+    /// 
+    /// ```
+    /// use automaters::*;
+    /// use serde_json::json;
+    /// fn main() {
+    ///     let content_json = json!({
+    ///         "ends" : ["q_0"],
+    ///         "start" : "q_0",
+    ///         "delta" : [{"state" : "q_0", "symbol" : "a"}]
+    ///     });
+    ///     let error : AutomatonError = DFA::try_from_json(&content_json).unwrap_err();
+    ///     assert_eq!(error, AutomatonError::MissingKey { key: "image".to_string(), index: Some(0) });
+    /// }
+    /// ```
+    /// 
+    /// # Return
+    ///
+    /// * `Result<DFA, AutomatonError>` - L'automate correspondant, ou l'erreur rencontrée
+    /// 
+    fn try_from_json(content_json: &Value) -> Result<Self, AutomatonError> {
         //creation du DFA à l'aide du content_json
+        json::expect_root(content_json)?;
+        // les etats declarés, s'ils sont precisés
+        let declared: Option<BTSet<State>> = json::get_declared_states(content_json)?;
         // reccupere le state de depart
-        let state_init :State = State::new(content_json["start"].as_str().unwrap().to_string());
+        let state_init :State = State::from_str(json::get_str(content_json, "start", None)?);
+        json::check_declared(&declared, &state_init, "start", None)?;
         // buffers
         let mut symbol: Symbol;
         let mut state: State;
@@ -253,15 +488,22 @@ impl AutomateJsonIO for DFA{
         let mut transition: Transition<State>;
         
         let mut alphabet: BTSet<Symbol> = BTSet::new();
-        let mut states: BTSet<State> = BTSet::new();
+        if let Some(symbols) = json::get_optional_strings(content_json, "alphabet")? {
+            for elem in symbols {
+                alphabet.insert(Symbol::from_str(elem));
+            }
+        }
+        let mut states: BTSet<State> = declared.clone().unwrap_or_default();
         // reccuperation de delta
         let mut delta: HashMap<Transition<State>, State> = HashMap::new();
         let mut transition_json: &Value;
-        for element_delta in content_json["delta"].as_array().unwrap(){
-            transition_json = element_delta;
-            symbol = Symbol::new(transition_json["symbol"].as_str().unwrap().to_string());
-            state = State::new(transition_json["state"].as_str().unwrap().to_string());
-            image = State::new(transition_json["image"].as_str().unwrap().to_string());
+        for (index, element_delta) in json::get_array(content_json, "delta", None)?.iter().enumerate(){
+            transition_json = json::expect_entry(element_delta, index)?;
+            symbol = Symbol::from_str(json::get_str(transition_json, "symbol", Some(index))?);
+            state = State::from_str(json::get_str(transition_json, "state", Some(index))?);
+            image = State::from_str(json::get_str(transition_json, "image", Some(index))?);
+            json::check_declared(&declared, &state, "state", Some(index))?;
+            json::check_declared(&declared, &image, "image", Some(index))?;
             // création de la transition: sur l'etat state, la lecture de state par symbol mene à image
             transition = Transition::new(symbol.clone(), state.clone());
             delta.insert(transition, image.clone());
@@ -275,8 +517,9 @@ impl AutomateJsonIO for DFA{
         states.insert(state_init.clone());
         // on reccupere les etats finaux
         let mut ends: BTSet<State> = BTSet::new();
-        for elem in content_json["ends"].as_array().unwrap(){
-            state = State::new(elem.as_str().unwrap().to_string());
+        for elem in json::get_strings(content_json, "ends", None)? {
+            state = State::from_str(elem);
+            json::check_declared(&declared, &state, "ends", None)?;
             ends.insert(state.clone());
             // on ajoute les etats finaux a la liste des states
             states.insert(state);
@@ -285,81 +528,103 @@ impl AutomateJsonIO for DFA{
         //on aurait pus directement utiliser l'interfasse de FSM pour enumerer les etat, l'alphabet etc. mais par precaution on le fait mannuellement par apport au contenu des transitions
         //let fsm = FSM::from_json(&content_json);
         let fsm : FSM = FSM::new(states, alphabet, ends);
-        DFA { 
+        Ok(DFA { 
             start: state_init, 
-            delta: delta, 
-            fsm: fsm
-        }
+            delta, 
+            fsm
+        })
     }
 
-    /// Créer un automate à état fini détérministe depuis un chemin vers un fichier json
+    /// Créer un automate à état fini détérministe depuis un chemin vers un fichier json sans paniquer
     /// 
     /// # Arguments
     ///
-    /// * `_start` - L'état initial de l'automate
-    /// * `_delta` - Une HashMap decrivant les differentes transition de l'automate
-    /// * `_fsm` - Une machine à état fini décrivant l'automate
+    /// * `path` - Le chemin vers le fichier json
     ///
     /// # Examples
     /// 
-    /// Le contenu du json
-    /// 
-    /// ```json
-    /// {
-    ///     "states" : ["q_0","q_1"],
-    ///     "alphabet" : ["b","a"],
-    ///     "ends" : ["q_0"],
-	///     "start" : "q_0", 
-	///     "delta" : [
-	///     	{
-	///     		"state" : "q_0",         
-	///     		"symbol" : "a",         
-	///     		"image" : "q_1"         
-	///     	},         
-	///     	{         
-	///     		"state" : "q_1",         
-	///     		"symbol" : "b",         
-	///     		"image" : "q_0"        
-	///     	}
-	///     ] 
+    /// ```
+    /// use automaters::*;
+    /// fn main() {
+    ///     let dfa : Result<DFA, AutomatonError> = DFA::try_from_json_file("src/automates/DFA1.json");
+    ///     assert!(dfa.is_ok());
     /// }
-    /// 
     /// ```
     /// 
-    /// Le chargement dans le code
+    /// # Return
+    ///
+    /// * `Result<DFA, AutomatonError>` - L'automate correspondant, ou l'erreur rencontrée
+    /// 
+    fn try_from_json_file(path: &str) -> Result<Self, AutomatonError> {
+        let content_json: Value = json::read_json_file(path)?;
+        //creation de la machine
+        DFA::try_from_json(&content_json)
+    }
+    /// Convertit l'automate au format json accepté par `from_json`
+    /// 
+    /// Les états, l'alphabet et les transitions sont triés afin que le json produit soit stable.
+    /// 
+    /// # Examples
     /// 
     /// ```
     /// use automaters::*;
-    /// use std::fs;
-    /// use serde_json::{Value, from_str};
+    /// use serde_json::Value;
     /// fn main() {
+    ///     let dfa : DFA = DFA::from_json_file("src/automates/DFA2.json");
+    ///     let content_json : Value = dfa.to_json();
+    ///     let dfa2 : DFA = DFA::from_json(&content_json);
+    ///     assert_eq!(dfa.get_delta(), dfa2.get_delta());
+    /// }
+    /// ```
     /// 
-    ///     let link_file: &str = "src/automates/DFA1.json";
-    ///     let content_json: Value = {
-    ///         // Charge le contenu du fichier en tant que String
-    ///         let content : String = fs::read_to_string(link_file).unwrap();
-    ///         // Parse le texte en structure Json
-    ///         from_str::<Value>(&content).unwrap()
-    ///     };
-    ///     //creation depuis un lien
-    ///     let dfa : DFA = DFA::from_json_file(link_file);  
+    /// # Return
+    ///
+    /// * `Value` - Le contenu json de l'automate
+    /// 
+    fn to_json(&self) -> Value {
+        // creation des transitions
+        let mut delta: Vec<Value> = Vec::new();
+        for (transition, image) in json::sorted_transitions(self.get_delta()) {
+            delta.push(json!({
+                "state" : transition.get_content().get_name(),
+                "symbol" : transition.get_symbol().get_value(),
+                "image" : image.get_name()
+            }));
+        }
+        json!({
+            "states" : json::states_to_json(self.get_states()),
+            "alphabet" : json::symbols_to_json(self.get_alphabet()),
+            "ends" : json::states_to_json(self.get_ends()),
+            "start" : self.get_start().get_name(),
+            "delta" : delta
+        })
+    }
+
+    /// Enregistre l'automate dans un fichier json
     /// 
+    /// # Arguments
+    ///
+    /// * `path` - Le chemin du fichier à écrire
+    ///
+    /// # Examples
+    /// 
+    /// ```
+    /// use automaters::*;
+    /// fn main() {
+    ///     let path : String = std::env::temp_dir().join("dfa_doc.json").display().to_string();
+    ///     let dfa : DFA = DFA::from_json_file("src/automates/DFA2.json");
+    ///     dfa.to_json_file(&path).unwrap();
+    ///     let dfa2 : DFA = DFA::from_json_file(&path);
+    ///     assert_eq!(dfa.get_ends(), dfa2.get_ends());
     /// }
     /// ```
     /// 
     /// # Return
     ///
-    /// * `DFA` - L'automate déterministe à état fini correspondante
+    /// * `Result<(), AutomatonError>` - Une erreur si le fichier n'a pas pu être écrit
     /// 
-    fn from_json_file(path: &str) -> Self {
-        let content_json: Value = {
-            // Charge le contenu du fichier en tant que String
-            let content : String = fs::read_to_string(path).unwrap();
-            // Parse le texte en structure Json
-            from_str::<Value>(&content).unwrap()
-        };
-        //creation de la machine
-        DFA::from_json(&content_json)
+    fn to_json_file(&self, path: &str) -> Result<(), AutomatonError> {
+        json::write_json_file(path, &self.to_json())
     }
 }
 
@@ -383,16 +648,19 @@ impl AutomateTrait<State> for DFA{
     }
 
     /// Retournes les differents états de l'automate
+    #[allow(clippy::needless_borrow)]
     fn get_states(&self) -> &BTSet<State> {
         &self.fsm.get_states()
     }
 
     /// Retourne l'alphabet de l'automate
+    #[allow(clippy::needless_borrow)]
     fn get_alphabet(&self) -> &BTSet<Symbol> {
         &self.fsm.get_alphabet()
     }
 
     /// Retourne les états finaux de l'automate
+    #[allow(clippy::needless_borrow)]
     fn get_ends(&self) -> &BTSet<State> {
         &self.fsm.get_ends()
     }
@@ -421,12 +689,27 @@ impl AutomateTrait<State> for DFA{
     }
 }
 
+impl ToNdfa for DFA {
+    /// Convertit le DFA en NDFA, chaque image devient un singleton
+    fn to_ndfa(&self) -> NDFA {
+        let mut _starts : BTSet<State> = BTSet::new();
+        _starts.insert(self.get_start().clone());
+        let _delta : HashMap<Transition<State>, BTSet<State>> = self.get_delta().iter()
+            .map(|(transition, image)| (transition.clone(), BTSet::new().from_vect(vec![image.clone()])))
+            .collect();
+        NDFA::new(_starts, _delta, self.get_fsm().clone())
+    }
+}
+
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use std::fs;
+    use serde_json::from_str;
 
     #[test]
+    #[allow(clippy::bool_assert_comparison)]
     fn creation_partial_eq_clone_accept() {      
         let mut link_file: &str = "src/automates/DFA1.json";
         let content_json: Value = {
@@ -485,4 +768,96 @@ mod test {
 
         dfa.to_minimize();
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn try_creation_errors() {
+        // les fichiers d'exemples sont valides
+        for link_file in ["src/automates/DFA1.json", "src/automates/DFA2.json", "src/automates/DFA3.json"] {
+            assert!(DFA::try_from_json_file(link_file).is_ok());
+        }
+        // fichier inexistant ou json invalide
+        assert!(matches!(DFA::try_from_json_file("src/automates/inexistant.json").unwrap_err(), AutomatonError::Io { .. }));
+        assert!(matches!(DFA::try_from_json_file("src/automates/res.txt").unwrap_err(), AutomatonError::MalformedJson { .. }));
+        // clé manquante
+        let mut content_json: Value = json!({"ends" : ["q_0"], "delta" : []});
+        assert_eq!(DFA::try_from_json(&content_json).unwrap_err(), AutomatonError::MissingKey { key: "start".to_string(), index: None });
+        // mauvais type dans une entrée de delta
+        content_json = json!({"ends" : ["q_0"], "start" : "q_0", "delta" : [
+            {"state" : "q_0", "symbol" : "a", "image" : "q_0"},
+            {"state" : "q_0", "symbol" : 1, "image" : "q_0"}
+        ]});
+        assert_eq!(DFA::try_from_json(&content_json).unwrap_err(), AutomatonError::WrongType { key: "symbol".to_string(), expected: "une chaîne de caractères".to_string(), index: Some(1) });
+        content_json = json!({"ends" : ["q_0"], "start" : "q_0", "delta" : ["q_0"]});
+        assert_eq!(DFA::try_from_json(&content_json).unwrap_err(), AutomatonError::WrongType { key: "delta".to_string(), expected: "un objet".to_string(), index: Some(0) });
+        // etat non declaré
+        content_json = json!({"states" : ["q_0"], "ends" : ["q_0"], "start" : "q_1", "delta" : []});
+        assert_eq!(DFA::try_from_json(&content_json).unwrap_err(), AutomatonError::UnknownState { key: "start".to_string(), state: "q_1".to_string(), index: None });
+        // les etats declarés sans transition sont conservés
+        content_json = json!({"states" : ["q_0", "q_1"], "alphabet" : ["a", "b"], "ends" : ["q_0"], "start" : "q_0", "delta" : []});
+        let dfa : DFA = DFA::try_from_json(&content_json).unwrap();
+        assert_eq!(dfa.get_states().len(), 2);
+        assert_eq!(dfa.get_alphabet().len(), 2);
+    }
+
+    #[test]
+    fn to_json_round_trip() {
+        let dfa : DFA = DFA::from_json_file("src/automates/DFA3.json");
+        let content_json : Value = dfa.to_json();
+        // le json produit respecte le schema lu par from_json
+        assert_eq!(content_json["start"], json!("q_0"));
+        assert_eq!(content_json["delta"][0], json!({"state" : "q_0", "symbol" : "a", "image" : "q_0"}));
+        let dfa2 : DFA = DFA::try_from_json(&content_json).unwrap();
+        assert_eq!(dfa.get_start(), dfa2.get_start());
+        assert_eq!(dfa.get_states(), dfa2.get_states());
+        assert_eq!(dfa.get_alphabet(), dfa2.get_alphabet());
+        assert_eq!(dfa.get_ends(), dfa2.get_ends());
+        assert_eq!(dfa.get_delta(), dfa2.get_delta());
+        // le resultat d'une minimisation peut etre sauvegardé puis rechargé
+        let minimized : DFA = DFA::from_json_file("src/automates/DFA2.json").to_minimize();
+        let path : String = std::env::temp_dir().join("automaters_dfa_minimized.json").display().to_string();
+        minimized.to_json_file(&path).unwrap();
+        let reloaded : DFA = DFA::from_json_file(&path);
+        assert_eq!(minimized.get_start(), reloaded.get_start());
+        assert_eq!(minimized.get_states(), reloaded.get_states());
+        assert_eq!(minimized.get_ends(), reloaded.get_ends());
+        assert_eq!(minimized.get_delta(), reloaded.get_delta());
+        fs::remove_file(&path).unwrap();
+        // ecriture impossible
+        assert!(matches!(dfa.to_json_file("src/automates/inexistant/dfa.json").unwrap_err(), AutomatonError::Io { .. }));
+    }
+
+    #[test]
+    fn complete() {
+        let dfa : DFA = DFA::from_json_file("src/automates/DFA3.json");
+        assert!(!dfa.is_complete());
+        let complete : DFA = dfa.complete("trap").unwrap();
+        assert!(complete.is_complete());
+        let trap : State = State::from_str("trap");
+        assert_eq!(complete.get_states().len(), 4);
+        assert!(!complete.get_ends().contains(&trap));
+        // les transitions manquantes menent au puits, qui boucle sur lui meme
+        assert_eq!(complete.apply_delta(Transition::new(Symbol::from_str("a"), State::from_str("q_1"))), Some(&trap));
+        assert_eq!(complete.apply_delta(Transition::new(Symbol::from_str("a"), trap.clone())), Some(&trap));
+        assert_eq!(complete.apply_delta(Transition::new(Symbol::from_str("b"), trap.clone())), Some(&trap));
+        // le langage est inchangé
+        for word in ["", "b", "ba", "bb", "bbab", "abba", "bbb"] {
+            assert_eq!(dfa.accept(word), complete.accept(word));
+        }
+        // un automate déjà complet n'est pas modifié
+        let dfa : DFA = DFA::from_json_file("src/automates/DFA2.json");
+        assert_eq!(dfa.complete("trap").unwrap().get_states(), dfa.get_states());
+        // le nom du puits ne doit pas être celui d'un état existant
+        assert_eq!(dfa.complete("A").unwrap_err(), AutomatonError::StateCollision { state: "A".to_string() });
+        // une image non declarée est un état à compléter
+        let (s, t) : (State, State) = (State::from_str("s"), State::from_str("t"));
+        let a : Symbol = Symbol::from_str("a");
+        let dfa : DFA = DFA::new(s.clone(), HashMap::from([(Transition::new(a.clone(), s.clone()), t.clone())]), FSM::new(BTSet::new().from_vect(vec![s]), BTSet::new().from_vect(vec![a.clone()]), BTSet::new().from_vect(vec![t.clone()])));
+        assert!(!dfa.is_complete());
+        let complete : DFA = dfa.complete("puits").unwrap();
+        assert!(complete.is_complete());
+        assert!(complete.get_states().contains(&t));
+        assert_eq!(complete.apply_delta(Transition::new(a, t)), Some(&State::from_str("puits")));
+        assert!(complete.accept("a") && !complete.accept("aa"));
+        assert_eq!(dfa.complete("t").unwrap_err(), AutomatonError::StateCollision { state: "t".to_string() });
+    }
+}
@@ -0,0 +1,267 @@
+use crate::state::fresh_state;
+use crate::{AutomateTrait, BTSet, State, Symbol};
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Debug;
+use std::fmt::Write;
+use std::hash::Hash;
+
+mod sealed {
+    use crate::{BTSet, State};
+
+    /// Les états désignés par un état initial ou une image de transition, `State` ou `BTSet<State>`
+    pub trait Targets {
+        fn targets(&self) -> Vec<State>;
+    }
+
+    impl Targets for State {
+        fn targets(&self) -> Vec<State> {
+            vec![self.clone()]
+        }
+    }
+
+    impl Targets for BTSet<State> {
+        fn targets(&self) -> Vec<State> {
+            self.get().iter().cloned().collect()
+        }
+    }
+}
+
+use sealed::Targets;
+
+// un identifiant valide pour Mermaid et PlantUML: une lettre ou un '_' suivi de lettres, chiffres ou '_'
+fn is_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    matches!(chars.next(), Some(first) if first.is_ascii_alphabetic() || first == '_') && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+// Mermaid remplace les codes #...; par le caractere correspondant
+fn escape_mermaid(text: &str) -> String {
+    text.chars()
+        .map(|c| match c {
+            '"' => "#quot;".to_string(),
+            '#' | ':' | ';' | '|' => format!("#{};", c as u32),
+            _ => c.to_string(),
+        })
+        .collect()
+}
+
+// PlantUML ne permet pas d'echapper un guillemet dans un libellé, on passe par son code unicode
+fn escape_plantuml(text: &str) -> String {
+    text.chars()
+        .map(|c| match c {
+            '"' | ':' | '|' => format!("<U+{:04X}>", c as u32),
+            _ => c.to_string(),
+        })
+        .collect()
+}
+
+// le contenu commun aux deux diagrammes: chaque etat avec son identifiant, et les transitions regroupées
+struct Diagram {
+    // (identifiant, nom) de chaque etat, dans l'ordre des noms
+    states: Vec<(String, String)>,
+    starts: Vec<String>,
+    ends: Vec<String>,
+    edges: Vec<(String, String, String)>,
+}
+
+impl Diagram {
+    fn new<T, A>(automate: &A) -> Self
+    where
+        T: Clone + Hash + Debug + Targets,
+        A: AutomateTrait<T> + ?Sized,
+    {
+        let mut all: BTSet<State> = automate.get_states().clone();
+        all.insert_all(BTSet::new().from_vect(automate.get_starts().targets()));
+        // les extrémités des transitions et les etats finaux non declarés ont eux aussi besoin d'un identifiant
+        all.insert_all(automate.get_ends().clone());
+        for (transition, images) in automate.get_delta() {
+            all.insert(transition.get_content().clone());
+            all.insert_all(BTSet::new().from_vect(images.targets()));
+        }
+        // les noms invalides sont remplacés par un identifiant qui n'entre en collision avec aucun autre
+        let mut used: BTSet<State> = BTSet::new();
+        for state in all.get() {
+            if is_identifier(state.get_name()) {
+                used.insert(state.clone());
+            }
+        }
+        let mut ids: HashMap<State, String> = HashMap::new();
+        for state in all.get() {
+            let id: String = if is_identifier(state.get_name()) {
+                state.get_name().clone()
+            } else {
+                let sanitized: String = state.get_name().chars().map(|c| if c.is_ascii_alphanumeric() { c } else { '_' }).collect();
+                let id: State = fresh_state(&used, &format!("s_{}", sanitized));
+                used.insert(id.clone());
+                id.get_name().clone()
+            };
+            ids.insert(state.clone(), id);
+        }
+        let id_of = |state: &State| -> String { ids[state].clone() };
+        let mut merged: BTreeMap<(String, String), BTSet<Symbol>> = BTreeMap::new();
+        for (transition, images) in automate.get_delta() {
+            for image in images.targets() {
+                merged.entry((id_of(transition.get_content()), id_of(&image))).or_default().insert(transition.get_symbol().clone());
+            }
+        }
+        Diagram {
+            states: all.get().iter().map(|state| (id_of(state), state.get_name().clone())).collect(),
+            starts: automate.get_starts().targets().iter().map(id_of).collect(),
+            ends: automate.get_ends().get().iter().map(id_of).collect(),
+            edges: merged
+                .into_iter()
+                .map(|((from, to), symbols)| {
+                    let label: Vec<&str> = symbols.get().iter().map(|symbol| symbol.get_value().as_str()).collect();
+                    (from, to, label.join(", "))
+                })
+                .collect(),
+        }
+    }
+
+    // ecrit le corps commun aux deux formats, `escape` protegeant les noms et les etiquettes affichés
+    fn write_body(&self, text: &mut String, escape: fn(&str) -> String) {
+        for (id, name) in &self.states {
+            if id != name {
+                writeln!(text, "    state \"{}\" as {}", escape(name), id).unwrap();
+            }
+        }
+        for start in &self.starts {
+            writeln!(text, "    [*] --> {}", start).unwrap();
+        }
+        for (from, to, label) in &self.edges {
+            writeln!(text, "    {} --> {} : {}", from, to, escape(label)).unwrap();
+        }
+        for end in &self.ends {
+            writeln!(text, "    {} --> [*]", end).unwrap();
+        }
+    }
+}
+
+/// L'export d'un automate en diagramme d'états Mermaid ou PlantUML
+///
+/// Les états initiaux sont reliés depuis le pseudo état `[*]` et les états finaux sont reliés vers lui. Les
+/// transitions reliant les mêmes états sont regroupées en une seule, étiquetée par la liste de leurs symboles.
+/// Un état dont le nom n'est pas un identifiant valide (lettres, chiffres et `_`, sans commencer par un chiffre)
+/// est déclaré avec un identifiant généré, et son nom est conservé comme libellé. Les caractères spéciaux des
+/// libellés et des étiquettes de transition sont échappés.
+pub trait AutomateDiagram<T: Clone + Hash + Debug + Targets>: AutomateTrait<T> {
+    /// Retourne l'automate sous forme de diagramme Mermaid `stateDiagram-v2`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use automaters::*;
+    /// fn main() {
+    ///     let dfa : DFA = DFA::from_json_file("src/automates/DFA2.json");
+    ///     let mermaid : String = dfa.to_mermaid();
+    ///     assert!(mermaid.starts_with("stateDiagram-v2\n"));
+    ///     assert!(mermaid.contains("    [*] --> A\n"));
+    ///     assert!(mermaid.contains("    D --> D : 0, 1\n"));
+    ///     assert!(mermaid.contains("    D --> [*]\n"));
+    /// }
+    /// ```
+    fn to_mermaid(&self) -> String {
+        let mut text: String = String::from("stateDiagram-v2\n    direction LR\n");
+        Diagram::new(self).write_body(&mut text, escape_mermaid);
+        text
+    }
+
+    /// Retourne l'automate sous forme de diagramme d'états PlantUML
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use automaters::*;
+    /// fn main() {
+    ///     let nfae : NDFAEpsilon = NDFAEpsilon::from_json_file("src/automates/NFA1e.json");
+    ///     let plantuml : String = nfae.to_plantuml();
+    ///     assert!(plantuml.starts_with("@startuml\n"));
+    ///     assert!(plantuml.ends_with("@enduml\n"));
+    /// }
+    /// ```
+    fn to_plantuml(&self) -> String {
+        let mut text: String = String::from("@startuml\nhide empty description\n");
+        Diagram::new(self).write_body(&mut text, escape_plantuml);
+        text.push_str("@enduml\n");
+        text
+    }
+}
+
+impl<T: Clone + Hash + Debug + Targets, A: AutomateTrait<T>> AutomateDiagram<T> for A {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{AutomateJsonIO, NDFAEpsilon, Transition, DFA, FSM, NDFA};
+
+    #[test]
+    fn mermaid_and_plantuml() {
+        // NFA3 a plusieurs etats initiaux et des noms déjà valides
+        let nfa: NDFA = NDFA::from_json_file("src/automates/NFA3.json");
+        let mermaid: String = nfa.to_mermaid();
+        assert_eq!(mermaid.matches("[*] --> ").count(), 3);
+        assert!(mermaid.contains("    q_0 --> [*]\n"));
+        assert!(mermaid.contains("    q_1 --> q_2 : a\n"));
+        assert!(!mermaid.contains("state \""));
+        // les noms invalides sont remplacés, sans collision avec un etat existant
+        let nfae: NDFAEpsilon = NDFAEpsilon::from_json(&serde_json::json!({
+            "states" : ["{q 0}", "s__q_0_", "2\"x"],
+            "alphabet" : ["a", "b", "ε"],
+            "ends" : ["2\"x"],
+            "starts" : ["{q 0}"],
+            "delta" : [
+                {"state" : "{q 0}", "symbol" : "a", "images" : ["2\"x", "s__q_0_"]},
+                {"state" : "{q 0}", "symbol" : "b", "images" : ["2\"x"]},
+                {"state" : "s__q_0_", "symbol" : "ε", "images" : ["2\"x"]}
+            ]
+        }));
+        let mermaid: String = nfae.to_mermaid();
+        assert!(mermaid.contains("    state \"{q 0}\" as s__q_0__1\n"));
+        assert!(mermaid.contains("    state \"2#quot;x\" as s_2_x\n"));
+        assert!(mermaid.contains("    [*] --> s__q_0__1\n"));
+        assert!(mermaid.contains("    s__q_0__1 --> s_2_x : a, b\n"));
+        assert!(mermaid.contains("    s__q_0_ --> s_2_x : ε\n"));
+        assert!(mermaid.contains("    s_2_x --> [*]\n"));
+        let plantuml: String = nfae.to_plantuml();
+        assert!(plantuml.contains("    state \"2<U+0022>x\" as s_2_x\n"));
+        assert!(plantuml.contains("    s__q_0__1 --> s__q_0_ : a\n"));
+        // un nom commençant par un chiffre n'est pas un identifiant
+        let dfa: DFA = DFA::from_json(&serde_json::json!({
+            "states" : ["0", "B"],
+            "alphabet" : ["a"],
+            "ends" : ["B"],
+            "start" : "0",
+            "delta" : [{"state" : "0", "symbol" : "a", "image" : "B"}]
+        }));
+        let plantuml: String = dfa.to_plantuml();
+        assert!(plantuml.contains("    state \"0\" as s_0\n    [*] --> s_0\n    s_0 --> B : a\n    B --> [*]\n"));
+        // les etiquettes sont échappées comme les noms
+        let dfa: DFA = DFA::from_json(&serde_json::json!({
+            "states" : ["A", "B"],
+            "alphabet" : ["a|b", "x:y", "q\"#"],
+            "ends" : ["B"],
+            "start" : "A",
+            "delta" : [
+                {"state" : "A", "symbol" : "a|b", "image" : "B"},
+                {"state" : "A", "symbol" : "x:y", "image" : "B"},
+                {"state" : "B", "symbol" : "q\"#", "image" : "B"}
+            ]
+        }));
+        let mermaid: String = dfa.to_mermaid();
+        assert!(mermaid.contains("    A --> B : a#124;b, x#58;y\n"));
+        assert!(mermaid.contains("    B --> B : q#quot;#35;\n"));
+        let plantuml: String = dfa.to_plantuml();
+        assert!(plantuml.contains("    A --> B : a<U+007C>b, x<U+003A>y\n"));
+        assert!(plantuml.contains("    B --> B : q<U+0022>#\n"));
+        // une image non declarée reçoit elle aussi un identifiant valide
+        let s: State = State::from_str("s");
+        let delta: HashMap<Transition<State>, State> = HashMap::from([(Transition::new(Symbol::from_str("a"), s.clone()), State::from_str("t x"))]);
+        let dfa: DFA = DFA::new(s.clone(), delta, FSM::new(BTSet::new().from_vect(vec![s]), BTSet::new().from_vect(vec![Symbol::from_str("a")]), BTSet::new()));
+        let mermaid: String = dfa.to_mermaid();
+        assert!(mermaid.contains("    state \"t x\" as s_t_x\n"));
+        assert!(mermaid.contains("    s --> s_t_x : a\n"));
+        let plantuml: String = dfa.to_plantuml();
+        assert!(plantuml.contains("    state \"t x\" as s_t_x\n"));
+        assert!(plantuml.contains("    s --> s_t_x : a\n"));
+    }
+}
@@ -0,0 +1,325 @@
+use crate::nfae::EPSILON;
+use crate::state::fresh_state;
+use crate::{AutomateTrait, BTSet, NDFAEpsilon, State, Symbol, Transition, DFA, NDFA};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt::Write;
+
+/// La direction dans laquelle Graphviz dispose les états
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum RankDir {
+    /// De gauche à droite
+    #[default]
+    LeftRight,
+    /// De haut en bas
+    TopBottom,
+    /// De droite à gauche
+    RightLeft,
+    /// De bas en haut
+    BottomTop,
+}
+
+impl RankDir {
+    fn as_str(&self) -> &'static str {
+        match self {
+            RankDir::LeftRight => "LR",
+            RankDir::TopBottom => "TB",
+            RankDir::RightLeft => "RL",
+            RankDir::BottomTop => "BT",
+        }
+    }
+}
+
+/// Les options de l'export au format DOT
+///
+/// # Examples
+///
+/// ```
+/// use automaters::*;
+/// fn main() {
+///     let nfa : NDFA = NDFA::from_json_file("src/automates/NFA3.json");
+///     let (dfa, subsets) = nfa.to_dfa_with_subsets();
+///     let options : DotOptions = DotOptions::new()
+///         .rankdir(RankDir::TopBottom)
+///         .subsets(subsets)
+///         .highlight_run(vec![Symbol::from_str("a")]);
+///     let dot : String = dfa.to_dot_with(&options);
+///     assert!(dot.contains("rankdir=TB;"));
+///     assert!(dot.contains("{q_0, q_1, q_2}"));
+/// }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct DotOptions {
+    rankdir: RankDir,
+    subsets: HashMap<State, BTSet<State>>,
+    run: Option<Vec<Symbol>>,
+}
+
+impl DotOptions {
+    /// Créer les options par défaut: disposition de gauche à droite, états nommés par leur nom, aucune exécution mise en évidence
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Choisit la direction de la disposition
+    pub fn rankdir(mut self, rankdir: RankDir) -> Self {
+        self.rankdir = rankdir;
+        self
+    }
+
+    /// Affiche chaque état présent dans `subsets` par le set d'états qu'il représente, par exemple `{q_0, q_1}`
+    ///
+    /// Les sets sont ceux retournés par `NDFA::to_dfa_with_subsets` ou `NDFAEpsilon::to_dfa_with_subsets`.
+    pub fn subsets(mut self, subsets: HashMap<State, BTSet<State>>) -> Self {
+        self.subsets = subsets;
+        self
+    }
+
+    /// Met en évidence les états et transitions parcourus lors de la lecture du mot
+    ///
+    /// Pour un automate non déterministe, toutes les exécutions sont mises en évidence, y compris les ε-transitions suivies.
+    pub fn highlight_run(mut self, word: Vec<Symbol>) -> Self {
+        self.run = Some(word);
+        self
+    }
+}
+
+/// L'export d'un automate au format DOT de Graphviz
+///
+/// Les états initiaux sont désignés par une flèche venant d'un point invisible, les états finaux sont dessinés par
+/// un double cercle. Les transitions reliant les mêmes états sont regroupées en un seul arc étiqueté par la liste
+/// de leurs symboles, et les ε-transitions sont dessinées en pointillés.
+pub trait AutomateDot {
+    /// Retourne le graphe de l'automate au format DOT, avec les options par défaut
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use automaters::*;
+    /// fn main() {
+    ///     let dfa : DFA = DFA::from_json_file("src/automates/DFA2.json");
+    ///     let dot : String = dfa.to_dot();
+    ///     assert!(dot.starts_with("digraph {"));
+    ///     assert!(dot.contains("\"D\" [shape=doublecircle];"));
+    ///     assert!(dot.contains("\"D\" -> \"D\" [label=\"0, 1\"];"));
+    /// }
+    /// ```
+    fn to_dot(&self) -> String {
+        self.to_dot_with(&DotOptions::default())
+    }
+
+    /// Retourne le graphe de l'automate au format DOT, avec les options données
+    fn to_dot_with(&self, options: &DotOptions) -> String;
+}
+
+impl AutomateDot for DFA {
+    fn to_dot_with(&self, options: &DotOptions) -> String {
+        let edges: Vec<(State, Symbol, State)> = self
+            .get_delta()
+            .iter()
+            .map(|(transition, image)| (transition.get_content().clone(), transition.get_symbol().clone(), image.clone()))
+            .collect();
+        render(self.get_states(), &BTSet::new().from_vect(vec![self.get_start().clone()]), self.get_ends(), edges, None, options)
+    }
+}
+
+impl AutomateDot for NDFA {
+    fn to_dot_with(&self, options: &DotOptions) -> String {
+        render(self.get_states(), self.get_starts(), self.get_ends(), edges(self.get_delta()), None, options)
+    }
+}
+
+impl AutomateDot for NDFAEpsilon {
+    /// ```
+    /// use automaters::*;
+    /// fn main() {
+    ///     let nfae : NDFAEpsilon = Regex::parse("a|b").unwrap().to_thompson();
+    ///     assert!(nfae.to_dot().contains("[label=\"ε\", style=dashed]"));
+    /// }
+    /// ```
+    fn to_dot_with(&self, options: &DotOptions) -> String {
+        let epsilon: Symbol = Symbol::from_str(EPSILON);
+        render(self.get_states(), self.get_starts(), self.get_ends(), edges(self.get_delta()), Some(&epsilon), options)
+    }
+}
+
+// les transitions (etat, symbole, image) d'un delta non deterministe
+fn edges(delta: &HashMap<Transition<State>, BTSet<State>>) -> Vec<(State, Symbol, State)> {
+    delta
+        .iter()
+        .flat_map(|(transition, images)| images.get().iter().map(move |image| (transition.get_content().clone(), transition.get_symbol().clone(), image.clone())))
+        .collect()
+}
+
+// entoure un identifiant de guillemets en echappant les caracteres speciaux
+fn quote(text: &str) -> String {
+    format!("\"{}\"", text.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+// ajoute à `states` la clôture par ε-transitions, en notant les transitions suivies
+fn close(states: &mut BTSet<State>, edges: &[(State, Symbol, State)], epsilon: Option<&Symbol>, used: &mut HashSet<usize>) {
+    let epsilon: &Symbol = match epsilon {
+        Some(epsilon) => epsilon,
+        None => return,
+    };
+    let mut stack: Vec<State> = states.get().iter().cloned().collect();
+    while let Some(state) = stack.pop() {
+        for (i, (from, symbol, to)) in edges.iter().enumerate() {
+            if *from == state && symbol == epsilon {
+                used.insert(i);
+                if states.insert(to.clone()) {
+                    stack.push(to.clone());
+                }
+            }
+        }
+    }
+}
+
+// les etats et les transitions parcourus lors de la lecture du mot
+fn run(starts: &BTSet<State>, edges: &[(State, Symbol, State)], epsilon: Option<&Symbol>, word: &[Symbol]) -> (BTSet<State>, HashSet<usize>) {
+    let mut used: HashSet<usize> = HashSet::new();
+    let mut current: BTSet<State> = starts.clone();
+    close(&mut current, edges, epsilon, &mut used);
+    let mut visited: BTSet<State> = current.clone();
+    for letter in word {
+        let mut next: BTSet<State> = BTSet::new();
+        for (i, (from, symbol, to)) in edges.iter().enumerate() {
+            if symbol == letter && current.contains(from) {
+                used.insert(i);
+                next.insert(to.clone());
+            }
+        }
+        close(&mut next, edges, epsilon, &mut used);
+        // la lecture s'arrete lorsque l'automate se bloque
+        if next.is_empty() {
+            break;
+        }
+        visited.insert_all(next.clone());
+        current = next;
+    }
+    (visited, used)
+}
+
+// écrit le graphe au format DOT, les etats etant triés par nom
+fn render(states: &BTSet<State>, starts: &BTSet<State>, ends: &BTSet<State>, edges: Vec<(State, Symbol, State)>, epsilon: Option<&Symbol>, options: &DotOptions) -> String {
+    const HIGHLIGHT: &str = "color=red, penwidth=2";
+    let (visited, used): (BTSet<State>, HashSet<usize>) = match &options.run {
+        Some(word) => run(starts, &edges, epsilon, word),
+        None => (BTSet::new(), HashSet::new()),
+    };
+    let mut all: BTSet<State> = states.clone();
+    all.insert_all(starts.clone());
+    let mut dot: String = String::new();
+    writeln!(dot, "digraph {{").unwrap();
+    writeln!(dot, "    rankdir={};", options.rankdir.as_str()).unwrap();
+    writeln!(dot, "    node [shape=circle];").unwrap();
+    for state in all.get() {
+        let mut attributes: Vec<String> = Vec::new();
+        if let Some(subset) = options.subsets.get(state) {
+            let names: Vec<&str> = subset.get().iter().map(|state| state.get_name().as_str()).collect();
+            attributes.push(format!("label={}", quote(&format!("{{{}}}", names.join(", ")))));
+        }
+        if ends.contains(state) {
+            attributes.push("shape=doublecircle".to_string());
+        }
+        if visited.contains(state) {
+            attributes.push(HIGHLIGHT.to_string());
+        }
+        if attributes.is_empty() {
+            writeln!(dot, "    {};", quote(state.get_name())).unwrap();
+        } else {
+            writeln!(dot, "    {} [{}];", quote(state.get_name()), attributes.join(", ")).unwrap();
+        }
+    }
+    // une fleche venant d'un point invisible designe chaque etat initial, le point ne devant pas porter le nom d'un etat
+    let mut taken: BTSet<State> = all.clone();
+    for (from, _, to) in &edges {
+        taken.insert(from.clone());
+        taken.insert(to.clone());
+    }
+    for (i, start) in starts.get().iter().enumerate() {
+        let point: State = fresh_state(&taken, &format!("__start{}", i));
+        taken.insert(point.clone());
+        writeln!(dot, "    {} [shape=point, style=invis];", quote(point.get_name())).unwrap();
+        writeln!(dot, "    {} -> {};", quote(point.get_name()), quote(start.get_name())).unwrap();
+    }
+    // les transitions reliant les memes etats sont regroupées, les ε-transitions à part
+    let mut merged: BTreeMap<(&State, &State, bool), (BTSet<&Symbol>, bool)> = BTreeMap::new();
+    for (i, (from, symbol, to)) in edges.iter().enumerate() {
+        let is_epsilon: bool = Some(symbol) == epsilon;
+        let entry: &mut (BTSet<&Symbol>, bool) = merged.entry((from, to, is_epsilon)).or_insert_with(|| (BTSet::new(), false));
+        entry.0.insert(symbol);
+        entry.1 |= used.contains(&i);
+    }
+    for ((from, to, is_epsilon), (symbols, highlighted)) in merged {
+        let label: Vec<&str> = symbols.get().iter().map(|symbol| symbol.get_value().as_str()).collect();
+        let mut attributes: Vec<String> = vec![format!("label={}", quote(&label.join(", ")))];
+        if is_epsilon {
+            attributes.push("style=dashed".to_string());
+        }
+        if highlighted {
+            attributes.push(HIGHLIGHT.to_string());
+        }
+        writeln!(dot, "    {} -> {} [{}];", quote(from.get_name()), quote(to.get_name()), attributes.join(", ")).unwrap();
+    }
+    dot.push_str("}\n");
+    dot
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{AutomateJsonIO, Regex, FSM};
+
+    #[test]
+    fn dot_export() {
+        // DFA2: les transitions paralleles sont regroupées et chaque etat n'est declaré qu'une fois
+        let dfa: DFA = DFA::from_json_file("src/automates/DFA2.json");
+        let dot: String = dfa.to_dot();
+        assert!(dot.contains("rankdir=LR;"));
+        assert!(dot.contains("    \"A\";\n"));
+        assert!(dot.contains("    \"D\" [shape=doublecircle];\n"));
+        assert!(dot.contains("    \"__start0\" [shape=point, style=invis];\n    \"__start0\" -> \"A\";\n"));
+        assert!(dot.contains("    \"D\" -> \"D\" [label=\"0, 1\"];\n"));
+        assert_eq!(dot.matches(" -> ").count(), 8);
+        assert!(dot.ends_with("}\n"));
+        // l'execution sur 01 passe par A, B et D
+        let dot: String = dfa.to_dot_with(&DotOptions::new().highlight_run(vec![Symbol::from_str("0"), Symbol::from_str("1")]));
+        assert!(dot.contains("    \"B\" [color=red, penwidth=2];\n"));
+        assert!(dot.contains("    \"D\" [shape=doublecircle, color=red, penwidth=2];\n"));
+        assert!(dot.contains("    \"C\";\n"));
+        assert!(dot.contains("    \"B\" -> \"D\" [label=\"1\", color=red, penwidth=2];\n"));
+        assert!(dot.contains("    \"A\" -> \"C\" [label=\"1\"];\n"));
+        // NFA3 a trois etats initiaux
+        let nfa: NDFA = NDFA::from_json_file("src/automates/NFA3.json");
+        assert_eq!(nfa.to_dot().matches("style=invis").count(), 3);
+        // le point invisible ne prend pas le nom d'un etat
+        let (s, t): (State, State) = (State::from_str("__start0"), State::from_str("t"));
+        let dfa: DFA = DFA::new(s.clone(), HashMap::from([(Transition::new(Symbol::from_str("a"), s.clone()), t.clone())]), FSM::new(BTSet::new().from_vect(vec![s, t]), BTSet::new().from_vect(vec![Symbol::from_str("a")]), BTSet::new()));
+        let dot: String = dfa.to_dot();
+        assert!(dot.contains("    \"__start0_1\" [shape=point, style=invis];\n    \"__start0_1\" -> \"__start0\";\n"));
+        assert!(dot.contains("    \"__start0\";\n"));
+        // les ε-transitions sont dessinées à part, meme entre les memes etats
+        let nfae: NDFAEpsilon = NDFAEpsilon::from_json(&serde_json::json!({
+            "states" : ["p", "q\"r"],
+            "alphabet" : ["a", "ε"],
+            "ends" : ["q\"r"],
+            "starts" : ["p"],
+            "delta" : [
+                {"state" : "p", "symbol" : "a", "images" : ["q\"r"]},
+                {"state" : "p", "symbol" : "ε", "images" : ["q\"r"]}
+            ]
+        }));
+        let dot: String = nfae.to_dot_with(&DotOptions::new().rankdir(RankDir::BottomTop).highlight_run(Vec::new()));
+        assert!(dot.contains("rankdir=BT;"));
+        assert!(dot.contains("    \"p\" -> \"q\\\"r\" [label=\"a\"];\n"));
+        assert!(dot.contains("    \"p\" -> \"q\\\"r\" [label=\"ε\", style=dashed, color=red, penwidth=2];\n"));
+        // les etats déterminisés sont nommés par leur set
+        let nfae: NDFAEpsilon = Regex::parse("ab").unwrap().to_thompson();
+        let (dfa, subsets): (DFA, HashMap<State, BTSet<State>>) = nfae.to_dfa_with_subsets();
+        let dot: String = dfa.to_dot_with(&DotOptions::new().subsets(subsets.clone()));
+        for (state, subset) in &subsets {
+            let names: Vec<&str> = subset.get().iter().map(|state| state.get_name().as_str()).collect();
+            assert!(dot.contains(&format!("    \"{}\" [label=\"{{{}}}\"", state.get_name(), names.join(", "))));
+        }
+    }
+}
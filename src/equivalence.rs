@@ -0,0 +1,438 @@
+use crate::{AutomateNdfa, AutomateTrait, BTSet, State, Symbol, Transition, NDFA};
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+use std::hash::Hash;
+use std::fmt::Debug;
+
+/// Un mot accepté par l'un des deux automates comparés et refusé par l'autre
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Counterexample {
+    word: Vec<Symbol>,
+    accepted_by_left: bool,
+}
+
+impl Counterexample {
+    /// Retourne le mot, sous forme de suite de symboles
+    pub fn get_word(&self) -> &Vec<Symbol> {
+        &self.word
+    }
+
+    /// Indique si le mot est accepté par le premier automate (et donc refusé par le second)
+    pub fn is_accepted_by_left(&self) -> bool {
+        self.accepted_by_left
+    }
+}
+
+impl fmt::Display for Counterexample {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let word: String = self.word.iter().map(|symbol| symbol.get_value().as_str()).collect();
+        let word: &str = if word.is_empty() { "ε" } else { &word };
+        if self.accepted_by_left {
+            write!(f, "le mot \"{}\" est accepté par le premier automate et refusé par le second", word)
+        } else {
+            write!(f, "le mot \"{}\" est accepté par le second automate et refusé par le premier", word)
+        }
+    }
+}
+
+// version indexée d'un NDFA sur un alphabet donné
+pub(crate) struct IndexedNDFA {
+    pub(crate) starts: Vec<usize>,
+    pub(crate) finals: Vec<bool>,
+    // transitions[q][a] contient les images de q par le symbole a
+    pub(crate) transitions: Vec<Vec<Vec<usize>>>,
+}
+
+impl IndexedNDFA {
+    pub(crate) fn new(nfa: &NDFA, alphabet: &[Symbol]) -> Self {
+        let states: Vec<State> = nfa.used_states().get().iter().cloned().collect();
+        let index: HashMap<&State, usize> = states.iter().enumerate().map(|(i, state)| (state, i)).collect();
+        let mut transitions: Vec<Vec<Vec<usize>>> = Vec::with_capacity(states.len());
+        for state in &states {
+            transitions.push(
+                alphabet
+                    .iter()
+                    .map(|symbol| match nfa.apply_delta(Transition::new(symbol.clone(), state.clone())) {
+                        Some(images) => images.get().iter().map(|image| index[image]).collect(),
+                        None => Vec::new(),
+                    })
+                    .collect(),
+            );
+        }
+        IndexedNDFA {
+            starts: nfa.get_starts().get().iter().map(|state| index[state]).collect(),
+            finals: states.iter().map(|state| nfa.get_ends().contains(state)).collect(),
+            transitions,
+        }
+    }
+
+    // indique si l'automate est déterministe (un seul etat initial et au plus une image par transition)
+    fn is_deterministic(&self) -> bool {
+        self.starts.len() == 1 && self.transitions.iter().flatten().all(|images| images.len() <= 1)
+    }
+
+    // l'ensemble des images d'un set d'etats par le symbole a, trié et sans doublons
+    pub(crate) fn step(&self, states: &[usize], a: usize) -> Vec<usize> {
+        let mut images: Vec<usize> = states.iter().flat_map(|&q| self.transitions[q][a].iter().copied()).collect();
+        images.sort_unstable();
+        images.dedup();
+        images
+    }
+
+    pub(crate) fn any_final(&self, states: &[usize]) -> bool {
+        states.iter().any(|&q| self.finals[q])
+    }
+}
+
+// convertit les deux automates en NDFA indexés sur l'union de leurs alphabets
+fn index_both<T, U, A, B>(a: &A, b: &B) -> (Vec<Symbol>, IndexedNDFA, IndexedNDFA)
+where
+    T: Clone + Hash + Debug,
+    U: Clone + Hash + Debug,
+    A: AutomateTrait<T> + AutomateNdfa,
+    B: AutomateTrait<U> + AutomateNdfa,
+{
+    let (left, right): (NDFA, NDFA) = (a.to_ndfa(), b.to_ndfa());
+    let mut alphabet: BTSet<Symbol> = left.get_alphabet().clone();
+    alphabet.insert_all(right.get_alphabet().clone());
+    let alphabet: Vec<Symbol> = alphabet.get().iter().cloned().collect();
+    let left: IndexedNDFA = IndexedNDFA::new(&left, &alphabet);
+    let right: IndexedNDFA = IndexedNDFA::new(&right, &alphabet);
+    (alphabet, left, right)
+}
+
+// reconstruit le mot menant au noeud `node` de l'exploration
+pub(crate) fn rebuild_word(parents: &[Option<(usize, usize)>], alphabet: &[Symbol], mut node: usize) -> Vec<Symbol> {
+    let mut word: Vec<Symbol> = Vec::new();
+    while let Some((parent, a)) = parents[node] {
+        word.push(alphabet[a].clone());
+        node = parent;
+    }
+    word.reverse();
+    word
+}
+
+// inclusion par antichaines: on explore en largeur les paires (p, S) ou p est un etat de `left` et S le set d'etats
+// de `right` atteint par le meme mot. Une paire (p, S) est ignorée si une paire (p, S') avec S' ⊆ S a déjà été visitée,
+// puisque tout contre-exemple depuis (p, S) en est aussi un depuis (p, S'). Le parcours en largeur donne un plus court mot.
+fn antichain_inclusion(alphabet: &[Symbol], left: &IndexedNDFA, right: &IndexedNDFA) -> Option<Vec<Symbol>> {
+    let mut nodes: Vec<(usize, Vec<usize>)> = Vec::new();
+    let mut parents: Vec<Option<(usize, usize)>> = Vec::new();
+    // antichain[p] contient les sets minimaux visités avec p
+    let mut antichain: Vec<Vec<Vec<usize>>> = vec![Vec::new(); left.finals.len()];
+    let mut queue: VecDeque<usize> = VecDeque::new();
+    let mut starts: Vec<usize> = right.starts.clone();
+    starts.sort_unstable();
+    for &p in &left.starts {
+        if insert_antichain(&mut antichain[p], &starts) {
+            nodes.push((p, starts.clone()));
+            parents.push(None);
+            queue.push_back(nodes.len() - 1);
+        }
+    }
+    while let Some(node) = queue.pop_front() {
+        let (p, set): (usize, Vec<usize>) = nodes[node].clone();
+        if left.finals[p] && !right.any_final(&set) {
+            return Some(rebuild_word(&parents, alphabet, node));
+        }
+        for a in 0..alphabet.len() {
+            let images: Vec<usize> = right.step(&set, a);
+            for &image in &left.transitions[p][a] {
+                if insert_antichain(&mut antichain[image], &images) {
+                    nodes.push((image, images.clone()));
+                    parents.push(Some((node, a)));
+                    queue.push_back(nodes.len() - 1);
+                }
+            }
+        }
+    }
+    None
+}
+
+// ajoute le set à l'antichaine s'il n'est subsumé par aucun set déjà present, et retire ceux qu'il subsume
+fn insert_antichain(antichain: &mut Vec<Vec<usize>>, set: &[usize]) -> bool {
+    let subset = |small: &[usize], big: &[usize]| small.iter().all(|q| big.binary_search(q).is_ok());
+    if antichain.iter().any(|known| subset(known, set)) {
+        return false;
+    }
+    antichain.retain(|known| !subset(set, known));
+    antichain.push(set.to_vec());
+    true
+}
+
+// cherche le representant de la classe de x, avec compression de chemin
+fn find(parent: &mut [usize], x: usize) -> usize {
+    let mut root: usize = x;
+    while parent[root] != root {
+        root = parent[root];
+    }
+    let mut current: usize = x;
+    while parent[current] != root {
+        let next: usize = parent[current];
+        parent[current] = root;
+        current = next;
+    }
+    root
+}
+
+// algorithme de Hopcroft-Karp: les etats des deux DFA (completés par un puits chacun) sont fusionnés par union-find
+// à partir de la paire des etats initiaux. Les DFA sont equivalents si aucune paire fusionnée ne melange final et non final.
+fn hopcroft_karp(alphabet: &[Symbol], left: &IndexedNDFA, right: &IndexedNDFA) -> bool {
+    // les etats de droite sont decalés apres ceux de gauche, chaque automate a son puits en dernier
+    let left_sink: usize = left.finals.len();
+    let offset: usize = left_sink + 1;
+    let right_sink: usize = offset + right.finals.len();
+    let is_final = |x: usize| {
+        if x < left_sink {
+            left.finals[x]
+        } else if x >= offset && x < right_sink {
+            right.finals[x - offset]
+        } else {
+            false
+        }
+    };
+    let next = |x: usize, a: usize| {
+        if x < left_sink {
+            left.transitions[x][a].first().copied().unwrap_or(left_sink)
+        } else if x >= offset && x < right_sink {
+            right.transitions[x - offset][a].first().map(|&q| q + offset).unwrap_or(right_sink)
+        } else {
+            x
+        }
+    };
+    let mut parent: Vec<usize> = (0..=right_sink).collect();
+    let start: (usize, usize) = (left.starts[0], right.starts[0] + offset);
+    parent[start.0] = start.1;
+    let mut stack: Vec<(usize, usize)> = vec![start];
+    while let Some((p, q)) = stack.pop() {
+        if is_final(p) != is_final(q) {
+            return false;
+        }
+        for a in 0..alphabet.len() {
+            let (p_root, q_root): (usize, usize) = (find(&mut parent, next(p, a)), find(&mut parent, next(q, a)));
+            if p_root != q_root {
+                parent[p_root] = q_root;
+                stack.push((next(p, a), next(q, a)));
+            }
+        }
+    }
+    true
+}
+
+/// Verifie que le langage de `a` est inclus dans celui de `b`
+///
+/// Les deux automates sont convertis en NDFA sur l'union de leurs alphabets, puis l'inclusion est decidée par
+/// l'algorithme des antichaines, sans determiniser `b`.
+///
+/// # Arguments
+///
+/// * `a` - L'automate dont le langage doit être inclus
+/// * `b` - L'automate dont le langage doit contenir celui de `a`
+///
+/// # Examples
+///
+/// ```
+/// use automaters::*;
+/// fn main() {
+///     let nfa : NDFA = NDFA::from_json_file("src/automates/NFA1.json");
+///     let dfa : DFA = nfa.to_dfa();
+///     assert!(included_in(&nfa, &dfa).is_ok());
+///     // l'intersection est incluse dans chacun des deux automates
+///     let other : DFA = DFA::from_json_file("src/automates/DFA3.json");
+///     assert!(included_in(&dfa.intersection(&other), &dfa).is_ok());
+/// }
+/// ```
+///
+/// # Return
+///
+/// * `Result<(), Counterexample>` - Un plus court mot accepté par `a` et refusé par `b` si l'inclusion est fausse
+///
+pub fn included_in<T, U, A, B>(a: &A, b: &B) -> Result<(), Counterexample>
+where
+    T: Clone + Hash + Debug,
+    U: Clone + Hash + Debug,
+    A: AutomateTrait<T> + AutomateNdfa,
+    B: AutomateTrait<U> + AutomateNdfa,
+{
+    let (alphabet, left, right): (Vec<Symbol>, IndexedNDFA, IndexedNDFA) = index_both(a, b);
+    match antichain_inclusion(&alphabet, &left, &right) {
+        Some(word) => Err(Counterexample { word, accepted_by_left: true }),
+        None => Ok(()),
+    }
+}
+
+/// Verifie que deux automates reconnaissent le même langage
+///
+/// Lorsque les deux automates sont déterministes, l'équivalence est decidée par l'algorithme de Hopcroft-Karp.
+/// Sinon, elle est decidée par deux tests d'inclusion par antichaines. Les alphabets sont unifiés.
+///
+/// # Arguments
+///
+/// * `a` - Le premier automate
+/// * `b` - Le second automate
+///
+/// # Examples
+///
+/// ```
+/// use automaters::*;
+/// fn main() {
+///     let nfa : NDFA = NDFA::from_json_file("src/automates/NFA1.json");
+///     assert!(equivalent(&nfa, &nfa.to_dfa()).is_ok());
+///     let dfa : DFA = DFA::from_json_file("src/automates/DFA3.json");
+///     // le mot vide est accepté par le complémentaire de DFA3 mais pas par DFA3
+///     let counterexample : Counterexample = equivalent(&dfa, &dfa.complement()).unwrap_err();
+///     assert_eq!(counterexample.get_word(), &vec![]);
+///     assert!(!counterexample.is_accepted_by_left());
+/// }
+/// ```
+///
+/// # Return
+///
+/// * `Result<(), Counterexample>` - Un plus court mot accepté par un seul des deux automates s'ils ne sont pas équivalents
+///
+pub fn equivalent<T, U, A, B>(a: &A, b: &B) -> Result<(), Counterexample>
+where
+    T: Clone + Hash + Debug,
+    U: Clone + Hash + Debug,
+    A: AutomateTrait<T> + AutomateNdfa,
+    B: AutomateTrait<U> + AutomateNdfa,
+{
+    let (alphabet, left, right): (Vec<Symbol>, IndexedNDFA, IndexedNDFA) = index_both(a, b);
+    if left.is_deterministic() && right.is_deterministic() && hopcroft_karp(&alphabet, &left, &right) {
+        return Ok(());
+    }
+    // le plus court contre-exemple est le plus court des deux sens d'inclusion
+    let left_only: Option<Vec<Symbol>> = antichain_inclusion(&alphabet, &left, &right);
+    let right_only: Option<Vec<Symbol>> = antichain_inclusion(&alphabet, &right, &left);
+    match (left_only, right_only) {
+        (None, None) => Ok(()),
+        (Some(word), None) => Err(Counterexample { word, accepted_by_left: true }),
+        (None, Some(word)) => Err(Counterexample { word, accepted_by_left: false }),
+        (Some(left_word), Some(right_word)) => {
+            if left_word.len() <= right_word.len() {
+                Err(Counterexample { word: left_word, accepted_by_left: true })
+            } else {
+                Err(Counterexample { word: right_word, accepted_by_left: false })
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::interfaces::ToNdfa;
+    use crate::test_utils::{undeclared_intermediate, undeclared_intermediate_epsilon};
+    use crate::{AutomateJsonIO, NDFAEpsilon, DFA};
+    use serde_json::json;
+
+    #[test]
+    fn equivalence_and_inclusion() {
+        // les mots sur {a, b} dont l'avant dernier symbole est a
+        let nfa: NDFA = NDFA::from_json(&json!({
+            "states" : ["0", "1", "2"],
+            "alphabet" : ["a", "b"],
+            "ends" : ["2"],
+            "starts" : ["0"],
+            "delta" : [
+                {"state" : "0", "symbol" : "a", "images" : ["0", "1"]},
+                {"state" : "0", "symbol" : "b", "images" : ["0"]},
+                {"state" : "1", "symbol" : "a", "images" : ["2"]},
+                {"state" : "1", "symbol" : "b", "images" : ["2"]}
+            ]
+        }));
+        let dfa: DFA = nfa.to_dfa();
+        let minimized: DFA = dfa.minimize_hopcroft().0;
+        assert!(equivalent(&nfa, &dfa).is_ok());
+        assert!(equivalent(&dfa, &minimized).is_ok());
+        assert!(equivalent(&minimized, &nfa.to_ndfa()).is_ok());
+        // la meme chose avec des ε-transitions
+        let nfae: NDFAEpsilon = NDFAEpsilon::from_json(&json!({
+            "states" : ["s", "0", "1", "2"],
+            "alphabet" : ["a", "b", "ε"],
+            "ends" : ["2"],
+            "starts" : ["s"],
+            "delta" : [
+                {"state" : "s", "symbol" : "ε", "images" : ["0"]},
+                {"state" : "0", "symbol" : "a", "images" : ["0", "1"]},
+                {"state" : "0", "symbol" : "b", "images" : ["0"]},
+                {"state" : "1", "symbol" : "a", "images" : ["2"]},
+                {"state" : "1", "symbol" : "b", "images" : ["2"]}
+            ]
+        }));
+        assert!(equivalent(&nfae, &minimized).is_ok());
+        // les mots dont l'avant dernier symbole est a et qui finissent par a
+        let ends_with_aa: DFA = dfa.intersection(&DFA::from_json(&json!({
+            "states" : ["0", "1"],
+            "alphabet" : ["a", "b"],
+            "ends" : ["1"],
+            "start" : "0",
+            "delta" : [
+                {"state" : "0", "symbol" : "a", "image" : "1"},
+                {"state" : "0", "symbol" : "b", "image" : "0"},
+                {"state" : "1", "symbol" : "a", "image" : "1"},
+                {"state" : "1", "symbol" : "b", "image" : "0"}
+            ]
+        })));
+        assert!(included_in(&ends_with_aa, &nfa).is_ok());
+        // le plus court mot accepté par nfa et pas par ends_with_aa est "ab"
+        let counterexample: Counterexample = included_in(&nfa, &ends_with_aa).unwrap_err();
+        assert_eq!(counterexample.get_word(), &vec![Symbol::from_str("a"), Symbol::from_str("b")]);
+        let counterexample: Counterexample = equivalent(&ends_with_aa, &nfa).unwrap_err();
+        assert!(!counterexample.is_accepted_by_left());
+        assert_eq!(counterexample.get_word().len(), 2);
+        assert_eq!(counterexample.to_string(), "le mot \"ab\" est accepté par le second automate et refusé par le premier");
+        // un symbole absent de l'un des alphabets est refusé par cet automate
+        let with_c: NDFA = NDFA::from_json(&json!({
+            "states" : ["0", "1"],
+            "alphabet" : ["c"],
+            "ends" : ["1"],
+            "starts" : ["0"],
+            "delta" : [{"state" : "0", "symbol" : "c", "images" : ["1"]}]
+        }));
+        let counterexample: Counterexample = equivalent(&dfa, &with_c).unwrap_err();
+        assert_eq!(counterexample.get_word(), &vec![Symbol::from_str("c")]);
+        assert!(included_in(&with_c, &dfa.union(&with_c.to_dfa())).is_ok());
+    }
+
+    #[test]
+    fn undeclared_intermediate_state() {
+        // l'etat t n'est pas declaré mais le mot "ab" passe par lui
+        let undeclared: DFA = undeclared_intermediate();
+        assert!(undeclared.accept("ab"));
+        let empty: DFA = DFA::from_json(&json!({
+            "states" : ["0"],
+            "alphabet" : ["a", "b"],
+            "ends" : [],
+            "start" : "0",
+            "delta" : []
+        }));
+        let counterexample: Counterexample = equivalent(&undeclared, &empty).unwrap_err();
+        assert!(counterexample.is_accepted_by_left());
+        assert_eq!(counterexample.get_word(), &vec![Symbol::from_str("a"), Symbol::from_str("b")]);
+        assert!(included_in(&undeclared, &empty).is_err());
+        assert!(included_in(&empty, &undeclared).is_ok());
+        // la meme chose avec des ε-transitions, t et v ne sont pas declarés
+        let nfae: NDFAEpsilon = undeclared_intermediate_epsilon();
+        assert!(nfae.accept("ab"));
+        assert!(equivalent(&nfae, &undeclared).is_ok());
+        assert!(equivalent(&nfae, &NDFA::from_json(&json!({
+            "states" : ["s", "t", "u"],
+            "alphabet" : ["a", "b"],
+            "ends" : ["u"],
+            "starts" : ["s"],
+            "delta" : [
+                {"state" : "s", "symbol" : "a", "images" : ["t"]},
+                {"state" : "t", "symbol" : "b", "images" : ["u"]}
+            ]
+        })))
+        .is_ok());
+        // un symbole des transitions absent de l'alphabet n'est pas perdu
+        let mut delta: HashMap<Transition<State>, BTSet<State>> = nfae.get_delta().clone();
+        delta.insert(Transition::new(Symbol::from_str("c"), State::from_str("s")), BTSet::new().from_vect(vec![State::from_str("u")]));
+        let with_c: NDFAEpsilon = NDFAEpsilon::new(nfae.get_starts().clone(), delta, nfae.get_fsm().clone());
+        assert!(with_c.accept("c"));
+        let counterexample: Counterexample = equivalent(&with_c, &nfae).unwrap_err();
+        assert_eq!(counterexample.get_word(), &vec![Symbol::from_str("c")]);
+    }
+}
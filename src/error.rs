@@ -0,0 +1,85 @@
+use crate::Diagnostic;
+use std::fmt;
+
+/// Les erreurs pouvant survenir lors du chargement ou de la transformation d'un automate
+///
+/// Chaque variante indique la clé json fautive et, lorsque l'erreur se trouve dans `delta`, l'indice de l'entrée concernée.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AutomatonError {
+    /// Le fichier n'a pas pu être lu
+    Io { path: String, message: String },
+    /// Le contenu n'est pas un json valide
+    MalformedJson { message: String },
+    /// Une clé obligatoire est absente
+    MissingKey { key: String, index: Option<usize> },
+    /// Une valeur n'a pas le type attendu
+    WrongType { key: String, expected: String, index: Option<usize> },
+    /// Un état est utilisé sans avoir été déclaré dans `states`
+    UnknownState { key: String, state: String, index: Option<usize> },
+    /// L'automate a été refusé par le chargement strict
+    Invalid { diagnostics: Vec<Diagnostic> },
+    /// Le nom d'un nouvel état est déjà utilisé par l'automate
+    StateCollision { state: String },
+    /// Le contenu n'est pas un xml valide, ou ne décrit pas un automate
+    MalformedXml { message: String },
+    /// Le contenu n'est pas un document HOA valide
+    MalformedHoa { message: String },
+    /// Le contenu n'est pas un texte AT&T ou une table de symboles OpenFst valide
+    MalformedAtt { message: String },
+    /// L'automate ne peut pas être représenté dans le type ou le format demandé
+    Unsupported { message: String },
+}
+
+// decrit l'emplacement de l'erreur dans le json
+fn location(key: &str, index: &Option<usize>) -> String {
+    match index {
+        Some(i) => format!("la clé \"{}\" de l'entrée {} de \"delta\"", key, i),
+        None => format!("la clé \"{}\"", key),
+    }
+}
+
+impl fmt::Display for AutomatonError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AutomatonError::Io { path, message } => {
+                write!(f, "impossible de lire le fichier \"{}\": {}", path, message)
+            }
+            AutomatonError::MalformedJson { message } => {
+                write!(f, "json invalide: {}", message)
+            }
+            AutomatonError::MissingKey { key, index } => {
+                write!(f, "{} est absente", location(key, index))
+            }
+            AutomatonError::WrongType { key, expected, index } => {
+                write!(f, "{} devrait contenir {}", location(key, index), expected)
+            }
+            AutomatonError::UnknownState { key, state, index } => {
+                write!(f, "{} utilise l'état \"{}\" qui n'est pas déclaré dans \"states\"", location(key, index), state)
+            }
+            AutomatonError::Invalid { diagnostics } => {
+                write!(f, "automate invalide")?;
+                for diagnostic in diagnostics.iter().filter(|diagnostic| diagnostic.is_error()) {
+                    write!(f, "\n  {}", diagnostic)?;
+                }
+                Ok(())
+            }
+            AutomatonError::StateCollision { state } => {
+                write!(f, "l'état \"{}\" existe déjà dans l'automate", state)
+            }
+            AutomatonError::MalformedXml { message } => {
+                write!(f, "xml invalide: {}", message)
+            }
+            AutomatonError::MalformedHoa { message } => {
+                write!(f, "HOA invalide: {}", message)
+            }
+            AutomatonError::MalformedAtt { message } => {
+                write!(f, "AT&T invalide: {}", message)
+            }
+            AutomatonError::Unsupported { message } => {
+                write!(f, "non supporté: {}", message)
+            }
+        }
+    }
+}
+
+impl std::error::Error for AutomatonError {}
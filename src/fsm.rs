@@ -1,6 +1,6 @@
-use super::{State, Symbol, BTSet};
-use std::fs;
-use serde_json::{Value, from_str};
+use super::{State, Symbol, BTSet, AutomatonError};
+use crate::json;
+use serde_json::{json, Value};
 
 
 /// Machine à état fini 
@@ -111,33 +111,60 @@ impl FSM {
     /// * `FSM` - La machine à état fini correspondante
     /// 
     pub fn from_json(content_json: &Value) -> Self {
+        match FSM::try_from_json(content_json) {
+            Ok(fsm) => fsm,
+            Err(error) => panic!("{}", error),
+        }
+    }
+
+    /// Creer une machine à etat fini depuis un json sans paniquer
+    /// 
+    /// # Arguments
+    ///
+    /// * `content_json` - Le contenu json
+    ///
+    /// # Examples
+    /// 
+    /// ```
+    /// use automaters::*;
+    /// use serde_json::json;
+    /// fn main() {
+    ///     let fsm : Result<FSM, AutomatonError> = FSM::try_from_json(&json!({"states" : ["q_0"], "alphabet" : ["a"]}));
+    ///     assert_eq!(fsm.unwrap_err(), AutomatonError::MissingKey { key: "ends".to_string(), index: None });
+    /// }
+    /// ```
+    /// 
+    /// # Return
+    ///
+    /// * `Result<FSM, AutomatonError>` - La machine à état fini correspondante, ou l'erreur rencontrée
+    /// 
+    pub fn try_from_json(content_json: &Value) -> Result<Self, AutomatonError> {
         //creation de la machine à l'aide du content_json
-        
+        json::expect_root(content_json)?;
         let mut alphabet: BTSet<Symbol> = BTSet::new();
-        let mut symbol: Symbol;
-        for elem in content_json["alphabet"].as_array().unwrap(){
-            symbol = Symbol::new(elem.as_str().unwrap().to_string()); //Création du symbol
-            alphabet.insert(symbol);//ajout du symbol 
+        for elem in json::get_strings(content_json, "alphabet", None)? {
+            alphabet.insert(Symbol::from_str(elem));//ajout du symbol 
         }
         // reccupere les differents etats
-        let mut state: State; 
         let mut states: BTSet<State> = BTSet::new();
-        for elem in content_json["states"].as_array().unwrap(){
-            state = State::new(elem.as_str().unwrap().to_string());
-            states.insert(state);
+        for elem in json::get_strings(content_json, "states", None)? {
+            states.insert(State::from_str(elem));
         }
-        // reccupere les etats finaux
+        // reccupere les etats finaux, qui doivent faire partie des etats
+        let declared: Option<BTSet<State>> = Some(states.clone());
+        let mut state: State;
         let mut ends: BTSet<State> = BTSet::new();
-        for elem in content_json["ends"].as_array().unwrap(){
-            state = State::new(elem.as_str().unwrap().to_string());
+        for elem in json::get_strings(content_json, "ends", None)? {
+            state = State::from_str(elem);
+            json::check_declared(&declared, &state, "ends", None)?;
             ends.insert(state);
         }
 
-        FSM {
-            alphabet : alphabet,
-            states : states,
-            ends: ends,
-        }
+        Ok(FSM {
+            alphabet,
+            states,
+            ends,
+        })
     }
 
     /// Créer une machine à état fini depuis un chemin vers un fichier json
@@ -177,14 +204,62 @@ impl FSM {
     /// * `FSM` - La machine à état fini correspondante
     /// 
     pub fn from_json_file(path: &str) -> Self {
-        let content_json: Value = {
-            // Charge le contenu du fichier en tant que String
-            let content : String = fs::read_to_string(path).unwrap();
-            // Parse le texte en structure Json
-            from_str::<Value>(&content).unwrap()
-        };
+        match FSM::try_from_json_file(path) {
+            Ok(fsm) => fsm,
+            Err(error) => panic!("{}", error),
+        }
+    }
+
+    /// Créer une machine à état fini depuis un chemin vers un fichier json sans paniquer
+    /// 
+    /// # Arguments
+    ///
+    /// * `path` - Le schemin vers le fichier json
+    ///
+    /// # Examples
+    /// 
+    /// ```
+    /// use automaters::*;
+    /// fn main() {
+    ///     let fsm : Result<FSM, AutomatonError> = FSM::try_from_json_file("src/automates/DFA1.json");
+    ///     assert!(fsm.is_ok());
+    ///     assert!(FSM::try_from_json_file("src/automates/inexistant.json").is_err());
+    /// }
+    /// ```
+    /// 
+    /// # Return
+    ///
+    /// * `Result<FSM, AutomatonError>` - La machine à état fini correspondante, ou l'erreur rencontrée
+    /// 
+    pub fn try_from_json_file(path: &str) -> Result<Self, AutomatonError> {
+        let content_json: Value = json::read_json_file(path)?;
         //creation de la machine
-        FSM::from_json(&content_json)
+        FSM::try_from_json(&content_json)
+    }
+
+    /// Convertit la machine au format json accepté par `from_json`
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use automaters::*;
+    /// fn main() {
+    ///     let fsm : FSM = FSM::from_json_file("src/automates/DFA1.json");  
+    ///     let fsm2 : FSM = FSM::from_json(&fsm.to_json());
+    ///     assert_eq!(fsm.get_states(), fsm2.get_states());
+    /// }
+    /// ```
+    /// 
+    /// # Return
+    ///
+    /// * `Value` - Le contenu json de la machine
+    /// 
+    pub fn to_json(&self) -> Value {
+        json!({
+            "states" : json::states_to_json(self.get_states()),
+            "alphabet" : json::symbols_to_json(self.get_alphabet()),
+            "ends" : json::states_to_json(self.get_ends())
+        })
     }
 
     /// Retourne les états de la machine
@@ -206,6 +281,8 @@ impl FSM {
 #[cfg(test)]
 mod test {
     use super::*;
+    use std::fs;
+    use serde_json::from_str;
 
     #[test]
     fn creation_fsm() {
@@ -230,4 +307,21 @@ mod test {
         assert_eq!(fsm.get_ends(), fsm3.get_ends());
         assert_eq!(fsm.get_alphabet(), fsm3.get_alphabet());
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn try_creation_fsm() {
+        // fichier inexistant
+        let error : AutomatonError = FSM::try_from_json_file("src/automates/inexistant.json").unwrap_err();
+        assert!(matches!(error, AutomatonError::Io { .. }));
+        // mauvais type
+        let error : AutomatonError = FSM::try_from_json(&json!({"states" : ["q_0"], "alphabet" : "a", "ends" : []})).unwrap_err();
+        assert_eq!(error, AutomatonError::WrongType { key: "alphabet".to_string(), expected: "un tableau".to_string(), index: None });
+        let error : AutomatonError = FSM::try_from_json(&json!({"states" : ["q_0", 1], "alphabet" : [], "ends" : []})).unwrap_err();
+        assert!(matches!(error, AutomatonError::WrongType { .. }));
+        // etat final non declaré
+        let error : AutomatonError = FSM::try_from_json(&json!({"states" : ["q_0"], "alphabet" : ["a"], "ends" : ["q_1"]})).unwrap_err();
+        assert_eq!(error, AutomatonError::UnknownState { key: "ends".to_string(), state: "q_1".to_string(), index: None });
+        // la racine n'est pas un objet
+        assert!(matches!(FSM::try_from_json(&json!([1, 2])).unwrap_err(), AutomatonError::MalformedJson { .. }));
+    }
+}
@@ -0,0 +1,741 @@
+use crate::{AutomateTrait, AutomatonError, BTSet, NDFAEpsilon, State, Symbol, Transition, DFA, FSM, NDFA};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fmt::Write;
+use std::fs;
+
+/// L'import et l'export d'automates au format HOA (Hanoi Omega-Automata)
+///
+/// Les symboles de l'alphabet sont représentés par des propositions atomiques (`AP`), avec un codage "one-hot":
+/// lire le symbole `a` correspond à la valuation où seule la proposition `a` est vraie. À la lecture, une étiquette
+/// (explicite `[0 & !1]`, ou implicite selon l'ordre des transitions) produit une transition pour chaque symbole
+/// dont la valuation la satisfait; les autres valuations sont ignorées. À l'écriture, les symboles des transitions
+/// absents de l'alphabet sont ajoutés aux propositions, aucune transition n'est perdue.
+///
+/// Seule l'acceptation par états est supportée: les états de l'ensemble d'acceptation `0` (condition `Inf(0)`)
+/// sont les états finaux, et les conditions `t` et `f` rendent tout les états finaux ou aucun. Les autres
+/// conditions, l'acceptation sur les transitions et les automates alternants sont refusés par une erreur
+/// `AutomatonError::Unsupported`.
+pub trait AutomateHoa: Sized {
+    /// Créer un automate depuis un texte au format HOA
+    fn from_hoa(content: &str) -> Result<Self, AutomatonError>;
+
+    /// Créer un automate depuis le chemin d'un fichier HOA
+    fn from_hoa_file(path: &str) -> Result<Self, AutomatonError> {
+        let content: String = fs::read_to_string(path).map_err(|error| AutomatonError::Io {
+            path: path.to_string(),
+            message: error.to_string(),
+        })?;
+        Self::from_hoa(&content)
+    }
+
+    /// Convertit l'automate au format HOA
+    fn to_hoa(&self) -> String;
+
+    /// Ecrit l'automate dans un fichier HOA
+    fn to_hoa_file(&self, path: &str) -> Result<(), AutomatonError> {
+        fs::write(path, self.to_hoa()).map_err(|error| AutomatonError::Io {
+            path: path.to_string(),
+            message: error.to_string(),
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Token {
+    // un nom d'en-tête suivi de ':', comme `States:` ou `State:`
+    Header(String),
+    Str(String),
+    Int(usize),
+    Ident(String),
+    Alias(String),
+    Punct(char),
+    Body,
+    End,
+}
+
+fn malformed(line: usize, message: &str) -> AutomatonError {
+    AutomatonError::MalformedHoa { message: format!("ligne {}: {}", line, message) }
+}
+
+// decoupe le texte en jetons, chacun avec sa ligne
+fn tokenize(content: &str) -> Result<Vec<(Token, usize)>, AutomatonError> {
+    let chars: Vec<char> = content.chars().collect();
+    let mut tokens: Vec<(Token, usize)> = Vec::new();
+    let mut line: usize = 1;
+    let mut i: usize = 0;
+    while i < chars.len() {
+        let c: char = chars[i];
+        if c == '\n' {
+            line += 1;
+            i += 1;
+        } else if c.is_whitespace() {
+            i += 1;
+        } else if c == '/' && chars.get(i + 1) == Some(&'*') {
+            // les commentaires peuvent etre imbriqués
+            let mut depth: usize = 0;
+            loop {
+                match (chars.get(i), chars.get(i + 1)) {
+                    (Some('/'), Some('*')) => {
+                        depth += 1;
+                        i += 2;
+                    }
+                    (Some('*'), Some('/')) => {
+                        depth -= 1;
+                        i += 2;
+                        if depth == 0 {
+                            break;
+                        }
+                    }
+                    (Some(&c), _) => {
+                        line += usize::from(c == '\n');
+                        i += 1;
+                    }
+                    (None, _) => return Err(malformed(line, "commentaire non terminé")),
+                }
+            }
+        } else if c == '"' {
+            let mut value: String = String::new();
+            i += 1;
+            loop {
+                match chars.get(i) {
+                    Some('"') => break,
+                    Some('\\') => {
+                        value.extend(chars.get(i + 1));
+                        i += 2;
+                    }
+                    Some(&c) => {
+                        line += usize::from(c == '\n');
+                        value.push(c);
+                        i += 1;
+                    }
+                    None => return Err(malformed(line, "chaîne non terminée")),
+                }
+            }
+            i += 1;
+            tokens.push((Token::Str(value), line));
+        } else if c.is_ascii_digit() {
+            let start: usize = i;
+            while chars.get(i).is_some_and(|c| c.is_ascii_digit()) {
+                i += 1;
+            }
+            let digits: String = chars[start..i].iter().collect();
+            let value: usize = digits.parse().map_err(|_| malformed(line, &format!("entier \"{}\" trop grand", digits)))?;
+            tokens.push((Token::Int(value), line));
+        } else if c.is_ascii_alphabetic() || c == '_' || c == '@' || c == '-' {
+            let start: usize = i;
+            i += 1;
+            while chars.get(i).is_some_and(|&c| c.is_ascii_alphanumeric() || c == '_' || c == '-') {
+                i += 1;
+            }
+            let word: String = chars[start..i].iter().collect();
+            let token: Token = match word.as_str() {
+                "--BODY--" => Token::Body,
+                "--END--" => Token::End,
+                "--ABORT--" => return Err(malformed(line, "l'automate a été abandonné (--ABORT--)")),
+                _ if c == '@' => Token::Alias(word),
+                _ if chars.get(i) == Some(&':') => {
+                    i += 1;
+                    Token::Header(word)
+                }
+                _ => Token::Ident(word),
+            };
+            tokens.push((token, line));
+        } else if "[]{}()&|!".contains(c) {
+            tokens.push((Token::Punct(c), line));
+            i += 1;
+        } else {
+            return Err(malformed(line, &format!("caractère '{}' inattendu", c)));
+        }
+    }
+    Ok(tokens)
+}
+
+// une étiquette booléenne sur les propositions atomiques
+#[derive(Debug, Clone)]
+enum Label {
+    True,
+    False,
+    Ap(usize),
+    Not(Box<Label>),
+    And(Box<Label>, Box<Label>),
+    Or(Box<Label>, Box<Label>),
+}
+
+impl Label {
+    // evalue l'etiquette pour la valuation où seule la proposition `ap` est vraie
+    fn accepts(&self, ap: usize) -> bool {
+        match self {
+            Label::True => true,
+            Label::False => false,
+            Label::Ap(i) => *i == ap,
+            Label::Not(label) => !label.accepts(ap),
+            Label::And(left, right) => left.accepts(ap) && right.accepts(ap),
+            Label::Or(left, right) => left.accepts(ap) || right.accepts(ap),
+        }
+    }
+}
+
+struct Reader {
+    tokens: Vec<(Token, usize)>,
+    position: usize,
+    aliases: HashMap<String, Label>,
+    aps: usize,
+}
+
+impl Reader {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position).map(|(token, _)| token)
+    }
+
+    fn line(&self) -> usize {
+        self.tokens.get(self.position).or(self.tokens.last()).map_or(1, |(_, line)| *line)
+    }
+
+    fn error(&self, message: &str) -> AutomatonError {
+        malformed(self.line(), message)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token: Option<Token> = self.peek().cloned();
+        self.position += 1;
+        token
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        if self.peek() == Some(&Token::Punct(c)) {
+            self.position += 1;
+            return true;
+        }
+        false
+    }
+
+    fn expect(&mut self, c: char) -> Result<(), AutomatonError> {
+        if !self.eat(c) {
+            return Err(self.error(&format!("'{}' attendu", c)));
+        }
+        Ok(())
+    }
+
+    fn int(&mut self, what: &str) -> Result<usize, AutomatonError> {
+        match self.next() {
+            Some(Token::Int(value)) => Ok(value),
+            _ => {
+                self.position -= 1;
+                Err(self.error(&format!("{} attendu", what)))
+            }
+        }
+    }
+
+    // la position du prochain en-tête (ou de --BODY--)
+    fn header_end(&self) -> usize {
+        (self.position..self.tokens.len())
+            .find(|&i| matches!(self.tokens[i].0, Token::Header(_) | Token::Body))
+            .unwrap_or(self.tokens.len())
+    }
+
+    // ou := et ('|' et)*, et := non ('&' non)*
+    fn label_or(&mut self) -> Result<Label, AutomatonError> {
+        let mut label: Label = self.label_and()?;
+        while self.eat('|') {
+            label = Label::Or(Box::new(label), Box::new(self.label_and()?));
+        }
+        Ok(label)
+    }
+
+    fn label_and(&mut self) -> Result<Label, AutomatonError> {
+        let mut label: Label = self.label_not()?;
+        while self.eat('&') {
+            label = Label::And(Box::new(label), Box::new(self.label_not()?));
+        }
+        Ok(label)
+    }
+
+    fn label_not(&mut self) -> Result<Label, AutomatonError> {
+        match self.next() {
+            Some(Token::Punct('!')) => Ok(Label::Not(Box::new(self.label_not()?))),
+            Some(Token::Punct('(')) => {
+                let label: Label = self.label_or()?;
+                self.expect(')')?;
+                Ok(label)
+            }
+            Some(Token::Ident(word)) if word == "t" => Ok(Label::True),
+            Some(Token::Ident(word)) if word == "f" => Ok(Label::False),
+            Some(Token::Int(ap)) if ap < self.aps => Ok(Label::Ap(ap)),
+            Some(Token::Int(ap)) => {
+                self.position -= 1;
+                Err(self.error(&format!("la proposition {} n'est pas déclarée dans AP", ap)))
+            }
+            Some(Token::Alias(alias)) => match self.aliases.get(&alias) {
+                Some(label) => Ok(label.clone()),
+                None => {
+                    self.position -= 1;
+                    Err(self.error(&format!("l'alias {} n'est pas déclaré", alias)))
+                }
+            },
+            _ => {
+                self.position -= 1;
+                Err(self.error("étiquette attendue"))
+            }
+        }
+    }
+
+    // un ensemble d'acceptation optionnel {i j ...}
+    fn acceptance_sets(&mut self) -> Result<Option<Vec<usize>>, AutomatonError> {
+        if !self.eat('{') {
+            return Ok(None);
+        }
+        let mut sets: Vec<usize> = Vec::new();
+        while !self.eat('}') {
+            sets.push(self.int("ensemble d'acceptation")?);
+        }
+        Ok(Some(sets))
+    }
+
+    // une destination, les conjonctions d'etats (automates alternants) ne sont pas supportées
+    fn target(&mut self, states: usize) -> Result<usize, AutomatonError> {
+        let target: usize = self.int("numéro d'état")?;
+        if target >= states {
+            self.position -= 1;
+            return Err(self.error(&format!("l'état {} dépasse le nombre d'états déclaré", target)));
+        }
+        if self.peek() == Some(&Token::Punct('&')) {
+            return Err(AutomatonError::Unsupported { message: "les automates alternants (conjonction d'états) ne sont pas supportés".to_string() });
+        }
+        Ok(target)
+    }
+}
+
+// le contenu d'un fichier HOA
+struct Hoa {
+    // le nom de chaque etat référencé, par numéro
+    names: BTreeMap<usize, State>,
+    starts: Vec<usize>,
+    finals: BTreeSet<usize>,
+    aps: Vec<Symbol>,
+    // (etat, proposition, image)
+    edges: Vec<(usize, usize, usize)>,
+}
+
+impl Hoa {
+    fn read(content: &str) -> Result<Hoa, AutomatonError> {
+        let mut reader: Reader = Reader { tokens: tokenize(content)?, position: 0, aliases: HashMap::new(), aps: 0 };
+        if reader.next() != Some(Token::Header("HOA".to_string())) || reader.next() != Some(Token::Ident("v1".to_string())) {
+            return Err(malformed(1, "le document doit commencer par \"HOA: v1\""));
+        }
+        let mut states: Option<usize> = None;
+        let mut starts: Vec<usize> = Vec::new();
+        let mut aps: Vec<Symbol> = Vec::new();
+        // None si tout les etats sont finaux, sinon l'ensemble d'acceptation des etats finaux
+        let mut accepting: Option<Option<usize>> = None;
+        while let Some(Token::Header(name)) = reader.peek().cloned() {
+            reader.position += 1;
+            let end: usize = reader.header_end();
+            match name.as_str() {
+                "States" => states = Some(reader.int("nombre d'états")?),
+                "Start" => {
+                    starts.push(reader.int("numéro d'état")?);
+                    if reader.peek() == Some(&Token::Punct('&')) {
+                        return Err(AutomatonError::Unsupported { message: "les automates alternants (conjonction d'états initiaux) ne sont pas supportés".to_string() });
+                    }
+                }
+                "AP" => {
+                    let count: usize = reader.int("nombre de propositions")?;
+                    for _ in 0..count {
+                        match reader.next() {
+                            Some(Token::Str(ap)) => aps.push(Symbol::new(ap)),
+                            _ => return Err(reader.error(&format!("{} propositions attendues dans AP", count))),
+                        }
+                    }
+                    reader.aps = count;
+                }
+                "Alias" => {
+                    let alias: String = match reader.next() {
+                        Some(Token::Alias(alias)) => alias,
+                        _ => return Err(reader.error("nom d'alias attendu")),
+                    };
+                    let label: Label = reader.label_or()?;
+                    reader.aliases.insert(alias, label);
+                }
+                "Acceptance" => {
+                    let condition: Vec<String> = reader.tokens[reader.position..end]
+                        .iter()
+                        .map(|(token, _)| match token {
+                            Token::Int(value) => value.to_string(),
+                            Token::Ident(word) => word.clone(),
+                            Token::Punct(c) => c.to_string(),
+                            _ => String::new(),
+                        })
+                        .collect();
+                    let condition: String = match condition.split_first() {
+                        Some((count, rest)) => format!("{} {}", count, rest.concat()),
+                        None => String::new(),
+                    };
+                    accepting = match condition.as_str() {
+                        "0 t" => Some(None),
+                        "0 f" => Some(Some(usize::MAX)),
+                        "1 Inf(0)" => Some(Some(0)),
+                        _ => {
+                            return Err(AutomatonError::Unsupported {
+                                message: format!("la condition d'acceptation \"{}\" n'est pas supportée, seules \"1 Inf(0)\", \"0 t\" et \"0 f\" le sont", condition),
+                            })
+                        }
+                    };
+                    reader.position = end;
+                }
+                // les autres en-têtes (name, tool, acc-name, properties, ...) sont ignorés
+                _ => reader.position = end,
+            }
+            if reader.position != end {
+                return Err(reader.error(&format!("contenu inattendu dans l'en-tête {}", name)));
+            }
+        }
+        let accepting: Option<usize> = accepting.ok_or_else(|| reader.error("l'en-tête Acceptance est obligatoire"))?;
+        if reader.next() != Some(Token::Body) {
+            reader.position -= 1;
+            return Err(reader.error("--BODY-- attendu"));
+        }
+        // sans l'en-tête States, le nombre d'etats est déduit du corps
+        let count: usize = states.unwrap_or(usize::MAX);
+        let mut hoa: Hoa = Hoa { names: BTreeMap::new(), starts, finals: BTreeSet::new(), aps, edges: Vec::new() };
+        let mut declared: HashMap<usize, (Option<String>, bool)> = HashMap::new();
+        // les numéros d'etats apparaissant dans le fichier, meme comme image d'une valuation sans symbole
+        let mut referenced: BTreeSet<usize> = hoa.starts.iter().copied().collect();
+        while reader.peek() == Some(&Token::Header("State".to_string())) {
+            reader.position += 1;
+            let state_label: Option<Label> = if reader.eat('[') {
+                let label: Label = reader.label_or()?;
+                reader.expect(']')?;
+                Some(label)
+            } else {
+                None
+            };
+            let state: usize = reader.target(count)?;
+            let name: Option<String> = match reader.peek() {
+                Some(Token::Str(name)) => {
+                    let name: String = name.clone();
+                    reader.position += 1;
+                    Some(name)
+                }
+                _ => None,
+            };
+            let is_final: bool = match (reader.acceptance_sets()?, accepting) {
+                (_, None) => true,
+                (Some(sets), Some(set)) => sets.contains(&set),
+                (None, Some(_)) => false,
+            };
+            if declared.insert(state, (name, is_final)).is_some() {
+                return Err(reader.error(&format!("l'état {} est déclaré deux fois", state)));
+            }
+            // les transitions de l'etat, l'etiquette implicite de la k-ieme etant la valuation k
+            let mut implicit: usize = 0;
+            while !matches!(reader.peek(), Some(Token::Header(_)) | Some(Token::End) | None) {
+                let label: Option<Label> = if reader.eat('[') {
+                    let label: Label = reader.label_or()?;
+                    reader.expect(']')?;
+                    Some(label)
+                } else {
+                    state_label.clone()
+                };
+                let target: usize = reader.target(count)?;
+                referenced.insert(target);
+                if reader.acceptance_sets()?.is_some() {
+                    return Err(AutomatonError::Unsupported { message: "l'acceptation sur les transitions n'est pas supportée".to_string() });
+                }
+                match label {
+                    Some(label) => {
+                        for ap in 0..hoa.aps.len() {
+                            if label.accepts(ap) {
+                                hoa.edges.push((state, ap, target));
+                            }
+                        }
+                    }
+                    None => {
+                        // seules les valuations ou une unique proposition est vraie correspondent à un symbole
+                        if implicit.is_power_of_two() {
+                            hoa.edges.push((state, implicit.trailing_zeros() as usize, target));
+                        }
+                        implicit += 1;
+                    }
+                }
+            }
+        }
+        if reader.next() != Some(Token::End) {
+            reader.position -= 1;
+            return Err(reader.error("--END-- attendu"));
+        }
+        if let Some(&start) = hoa.starts.iter().find(|&&start| start >= count) {
+            return Err(AutomatonError::MalformedHoa { message: format!("l'état initial {} dépasse le nombre d'états déclaré", start) });
+        }
+        // seuls les etats référencés sont créés: les etats non decrits n'ont aucune transition et sont nommés par
+        // leur numéro, ceux qui n'apparaissent nulle part ne changent pas le langage et sont ignorés
+        referenced.extend(declared.keys().copied());
+        let mut names: BTSet<State> = BTSet::new();
+        for q in referenced {
+            let (name, is_final): (Option<String>, bool) = declared.remove(&q).unwrap_or((None, accepting.is_none()));
+            let state: State = State::new(name.unwrap_or_else(|| q.to_string()));
+            if !names.insert(state.clone()) {
+                return Err(AutomatonError::StateCollision { state: state.get_name().clone() });
+            }
+            hoa.names.insert(q, state);
+            if is_final {
+                hoa.finals.insert(q);
+            }
+        }
+        Ok(hoa)
+    }
+
+    fn starts(&self) -> BTSet<State> {
+        BTSet::new().from_vect(self.starts.iter().map(|q| self.names[q].clone()).collect())
+    }
+
+    fn fsm(&self) -> FSM {
+        let ends: Vec<State> = self.finals.iter().map(|q| self.names[q].clone()).collect();
+        FSM::new(BTSet::new().from_vect(self.names.values().cloned().collect()), BTSet::new().from_vect(self.aps.clone()), BTSet::new().from_vect(ends))
+    }
+
+    fn delta(&self) -> HashMap<Transition<State>, BTSet<State>> {
+        let mut delta: HashMap<Transition<State>, BTSet<State>> = HashMap::new();
+        for &(from, ap, to) in &self.edges {
+            delta.entry(Transition::new(self.aps[ap].clone(), self.names[&from].clone())).or_default().insert(self.names[&to].clone());
+        }
+        delta
+    }
+}
+
+impl AutomateHoa for DFA {
+    /// ```
+    /// use automaters::*;
+    /// fn main() {
+    ///     let hoa : &str = r#"
+    ///         HOA: v1
+    ///         States: 2
+    ///         Start: 0
+    ///         AP: 2 "a" "b"
+    ///         acc-name: Buchi
+    ///         Acceptance: 1 Inf(0)
+    ///         --BODY--
+    ///         State: 0
+    ///         [0 & !1] 1
+    ///         [1 & !0] 0
+    ///         State: 1 {0}
+    ///         [t] 1
+    ///         --END--
+    ///     "#;
+    ///     let dfa : DFA = DFA::from_hoa(hoa).unwrap();
+    ///     assert!(dfa.accept("bbab"));
+    ///     assert!(!dfa.accept("bb"));
+    ///     assert!(dfa.to_hoa().contains("Acceptance: 1 Inf(0)"));
+    /// }
+    /// ```
+    fn from_hoa(content: &str) -> Result<Self, AutomatonError> {
+        let hoa: Hoa = Hoa::read(content)?;
+        let start: State = match hoa.starts().get().iter().collect::<Vec<&State>>()[..] {
+            [start] => start.clone(),
+            _ => return Err(AutomatonError::Unsupported { message: format!("un DFA doit avoir exactement un état initial, {} trouvés", hoa.starts().len()) }),
+        };
+        let mut delta: HashMap<Transition<State>, State> = HashMap::new();
+        for (transition, images) in hoa.delta() {
+            if images.len() > 1 {
+                return Err(AutomatonError::Unsupported {
+                    message: format!("l'état \"{}\" a plusieurs transitions par \"{}\", il doit être chargé en NDFA", transition.get_content().get_name(), transition.get_symbol().get_value()),
+                });
+            }
+            let image: State = images.get().iter().next().unwrap().clone();
+            delta.insert(transition, image);
+        }
+        Ok(DFA::new(start, delta, hoa.fsm()))
+    }
+
+    fn to_hoa(&self) -> String {
+        let delta: HashMap<Transition<State>, BTSet<State>> = self
+            .get_delta()
+            .iter()
+            .map(|(transition, image)| (transition.clone(), BTSet::new().from_vect(vec![image.clone()])))
+            .collect();
+        write(self.get_states(), &BTSet::new().from_vect(vec![self.get_start().clone()]), self.get_ends(), self.get_alphabet(), &delta, true)
+    }
+}
+
+impl AutomateHoa for NDFA {
+    /// ```
+    /// use automaters::*;
+    /// fn main() {
+    ///     // NFA3 a trois états initiaux
+    ///     let nfa : NDFA = NDFA::from_json_file("src/automates/NFA3.json");
+    ///     let nfa2 : NDFA = NDFA::from_hoa(&nfa.to_hoa()).unwrap();
+    ///     assert_eq!(nfa2.get_starts(), nfa.get_starts());
+    ///     assert_eq!(nfa2.get_delta(), nfa.get_delta());
+    /// }
+    /// ```
+    fn from_hoa(content: &str) -> Result<Self, AutomatonError> {
+        let hoa: Hoa = Hoa::read(content)?;
+        Ok(NDFA::new(hoa.starts(), hoa.delta(), hoa.fsm()))
+    }
+
+    fn to_hoa(&self) -> String {
+        write(self.get_states(), self.get_starts(), self.get_ends(), self.get_alphabet(), self.get_delta(), false)
+    }
+}
+
+impl AutomateHoa for NDFAEpsilon {
+    /// Le format HOA n'a pas d'ε-transitions: l'automate est lu comme un NDFA, et l'export écrit l'automate obtenu
+    /// par `remove_epsilon`.
+    ///
+    /// ```
+    /// use automaters::*;
+    /// fn main() {
+    ///     let nfae : NDFAEpsilon = NDFAEpsilon::from_json_file("src/automates/NFA1e.json");
+    ///     let nfae2 : NDFAEpsilon = NDFAEpsilon::from_hoa(&nfae.to_hoa()).unwrap();
+    ///     assert!(equivalent(&nfae, &nfae2).is_ok());
+    /// }
+    /// ```
+    fn from_hoa(content: &str) -> Result<Self, AutomatonError> {
+        let nfa: NDFA = NDFA::from_hoa(content)?;
+        let fsm: FSM = nfa.get_fsm().clone();
+        Ok(NDFAEpsilon::new(nfa.get_starts().clone(), nfa.get_delta().clone(), fsm))
+    }
+
+    fn to_hoa(&self) -> String {
+        self.remove_epsilon().to_hoa()
+    }
+}
+
+// entoure une chaîne de guillemets en echappant les caracteres speciaux
+fn quote(text: &str) -> String {
+    format!("\"{}\"", text.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+// écrit l'automate, les etats triés par nom etant numérotés à partir de 0
+fn write(states: &BTSet<State>, starts: &BTSet<State>, ends: &BTSet<State>, alphabet: &BTSet<Symbol>, delta: &HashMap<Transition<State>, BTSet<State>>, deterministic: bool) -> String {
+    let mut all: BTSet<State> = states.clone();
+    all.insert_all(starts.clone());
+    // les symboles des transitions absents de l'alphabet deviennent eux aussi des propositions
+    let mut symbols: BTSet<Symbol> = alphabet.clone();
+    for (transition, images) in delta {
+        all.insert(transition.get_content().clone());
+        all.insert_all(images.clone());
+        symbols.insert(transition.get_symbol().clone());
+    }
+    let ids: HashMap<&State, usize> = all.get().iter().enumerate().map(|(i, state)| (state, i)).collect();
+    let symbols: Vec<&Symbol> = symbols.get().iter().collect();
+    let mut hoa: String = String::from("HOA: v1\n");
+    writeln!(hoa, "States: {}", all.len()).unwrap();
+    for start in starts.get() {
+        writeln!(hoa, "Start: {}", ids[start]).unwrap();
+    }
+    let names: Vec<String> = symbols.iter().map(|symbol| quote(symbol.get_value())).collect();
+    writeln!(hoa, "AP: {}{}", symbols.len(), names.iter().map(|name| format!(" {}", name)).collect::<String>()).unwrap();
+    hoa.push_str("acc-name: Buchi\nAcceptance: 1 Inf(0)\n");
+    hoa.push_str(if deterministic { "properties: trans-labels explicit-labels state-acc deterministic\n" } else { "properties: trans-labels explicit-labels state-acc\n" });
+    hoa.push_str("--BODY--\n");
+    for state in all.get() {
+        write!(hoa, "State: {} {}", ids[state], quote(state.get_name())).unwrap();
+        hoa.push_str(if ends.contains(state) { " {0}\n" } else { "\n" });
+        for (ap, symbol) in symbols.iter().enumerate() {
+            // la valuation où seule la proposition du symbole est vraie
+            let label: Vec<String> = (0..symbols.len()).map(|other| if other == ap { other.to_string() } else { format!("!{}", other) }).collect();
+            if let Some(images) = delta.get(&Transition::new((*symbol).clone(), state.clone())) {
+                for image in images.get() {
+                    writeln!(hoa, "[{}] {}", label.join("&"), ids[image]).unwrap();
+                }
+            }
+        }
+    }
+    hoa.push_str("--END--\n");
+    hoa
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{equivalent, AutomateJsonIO, Regex};
+
+    #[test]
+    fn hoa_read_write() {
+        // aller retour pour chaque type d'automate
+        let dfa: DFA = DFA::from_json_file("src/automates/DFA2.json");
+        let dfa2: DFA = DFA::from_hoa(&dfa.to_hoa()).unwrap();
+        assert_eq!(dfa2.get_delta(), dfa.get_delta());
+        assert_eq!(dfa2.get_ends(), dfa.get_ends());
+        let nfa: NDFA = Regex::parse("(a|b)*a(a|b)").unwrap().to_glushkov().0;
+        assert!(equivalent(&NDFA::from_hoa(&nfa.to_hoa()).unwrap(), &nfa).is_ok());
+        assert!(matches!(DFA::from_hoa(&nfa.to_hoa()), Err(AutomatonError::Unsupported { .. })));
+        let nfae: NDFAEpsilon = NDFAEpsilon::from_json_file("src/automates/NFA3e.json");
+        assert!(equivalent(&NDFAEpsilon::from_hoa(&nfae.to_hoa()).unwrap(), &nfae).is_ok());
+        // etiquettes implicites, alias, commentaires, en-têtes inconnus et etats sans nom ni description
+        let hoa: &str = "HOA: v1 /* commentaire /* imbriqué */ */\n\
+                         tool: \"spot\" \"2.11\"\n\
+                         States: 4\n\
+                         Start: 0\n\
+                         AP: 2 \"a\" \"b\"\n\
+                         Alias: @a 0 & !1\n\
+                         Acceptance: 1 Inf(0)\n\
+                         properties: implicit-labels\n\
+                         --BODY--\n\
+                         State: 0 \"init\"\n\
+                         3 1 2 3\n\
+                         State: 1 {0}\n\
+                         [@a | (t & f)] 1\n\
+                         State: [!0] 2 {0}\n\
+                         0\n\
+                         --END--\n";
+        let nfa: NDFA = NDFA::from_hoa(hoa).unwrap();
+        let expected: [(&str, &str, &[&str]); 4] = [("init", "a", &["1"]), ("init", "b", &["2"]), ("1", "a", &["1"]), ("2", "b", &["init"])];
+        for (state, symbol, images) in expected {
+            let images: BTSet<State> = BTSet::new().from_vect(images.iter().map(|image| State::from_str(image)).collect());
+            assert_eq!(nfa.apply_delta(Transition::new(Symbol::from_str(symbol), State::from_str(state))), Some(images));
+        }
+        assert_eq!(nfa.get_delta().len(), 4);
+        assert_eq!(nfa.get_states().len(), 4);
+        assert!(nfa.get_states().contains(&State::from_str("3")));
+        assert_eq!(nfa.get_ends(), &BTSet::new().from_vect(vec![State::from_str("1"), State::from_str("2")]));
+        // les conditions "t" et "f"
+        let all: NDFA = NDFA::from_hoa("HOA: v1 States: 2 Start: 0 AP: 1 \"a\" Acceptance: 0 t --BODY-- State: 0 [0] 1 --END--").unwrap();
+        assert_eq!(all.get_ends().len(), 2);
+        let none: NDFA = NDFA::from_hoa("HOA: v1 Start: 0 AP: 1 \"a\" Acceptance: 0 f --BODY-- State: 0 {0} [0] 1 --END--").unwrap();
+        assert!(none.get_ends().is_empty());
+        assert_eq!(none.get_states().len(), 2);
+        // les erreurs
+        let unsupported = |hoa: &str| match NDFA::from_hoa(hoa) {
+            Err(AutomatonError::Unsupported { message }) => message,
+            other => panic!("{:?}", other.map(|nfa| nfa.get_states().len())),
+        };
+        assert_eq!(
+            unsupported("HOA: v1 Start: 0 AP: 0 Acceptance: 2 Inf(0)&Inf(1) --BODY-- --END--"),
+            "la condition d'acceptation \"2 Inf(0)&Inf(1)\" n'est pas supportée, seules \"1 Inf(0)\", \"0 t\" et \"0 f\" le sont"
+        );
+        assert_eq!(unsupported("HOA: v1 Start: 0 AP: 1 \"a\" Acceptance: 1 Inf(0) --BODY-- State: 0 [0] 0 {0} --END--"), "l'acceptation sur les transitions n'est pas supportée");
+        assert!(unsupported("HOA: v1 Start: 0&1 AP: 0 Acceptance: 0 t --BODY-- --END--").contains("alternants"));
+        assert!(unsupported("HOA: v1 States: 2 Start: 0 AP: 1 \"a\" Acceptance: 0 t --BODY-- State: 0 [0] 0&1 --END--").contains("alternants"));
+        let malformed = |hoa: &str| match NDFA::from_hoa(hoa) {
+            Err(AutomatonError::MalformedHoa { message }) => message,
+            other => panic!("{:?}", other.map(|nfa| nfa.get_states().len())),
+        };
+        assert_eq!(malformed("HOA: v2"), "ligne 1: le document doit commencer par \"HOA: v1\"");
+        assert_eq!(malformed("HOA: v1\nStart: 0\nAP: 1 \"a\"\n--BODY--\n--END--"), "ligne 4: l'en-tête Acceptance est obligatoire");
+        assert_eq!(malformed("HOA: v1\nStates: 1\nAP: 1 \"a\"\nAcceptance: 0 t\n--BODY--\nState: 0\n[1] 0\n--END--"), "ligne 7: la proposition 1 n'est pas déclarée dans AP");
+        assert_eq!(malformed("HOA: v1\nStates: 1\nAP: 0\nAcceptance: 0 t\n--BODY--\nState: 0\n2\n--END--"), "ligne 7: l'état 2 dépasse le nombre d'états déclaré");
+        assert!(malformed("HOA: v1 AP: 0 Acceptance: 0 t --BODY-- State: 0 --ABORT--").contains("--ABORT--"));
+        assert!(matches!(NDFA::from_hoa("HOA: v1 AP: 0 Acceptance: 0 t --BODY-- State: 0 \"q\" State: 1 \"q\" --END--"), Err(AutomatonError::StateCollision { .. })));
+        // les numéros extrêmes ne débordent pas et un grand nombre d'états n'est pas alloué
+        assert!(matches!(NDFA::from_hoa("HOA: v1 Start: 18446744073709551615 AP: 0 Acceptance: 0 t --BODY-- --END--"), Err(AutomatonError::MalformedHoa { .. })));
+        let sparse: NDFA = NDFA::from_hoa("HOA: v1 States: 99999999999 Start: 0 AP: 1 \"a\" Acceptance: 0 t --BODY-- State: 0 [0] 99999999998 --END--").unwrap();
+        assert_eq!(sparse.get_states(), &BTSet::new().from_vect(vec![State::from_str("0"), State::from_str("99999999998")]));
+        assert!(sparse.accept("a"));
+    }
+
+    #[test]
+    fn undeclared_symbol() {
+        // la boucle sur z n'est pas perdue bien que z soit absent de l'alphabet
+        let dfa: DFA = DFA::from_json_file("src/automates/DFA2.json");
+        let start: State = dfa.get_start().clone();
+        let mut delta: HashMap<Transition<State>, State> = dfa.get_delta().clone();
+        delta.insert(Transition::new(Symbol::from_str("z"), start.clone()), start.clone());
+        let with_z: DFA = DFA::new(start, delta, dfa.get_fsm().clone());
+        let hoa: String = with_z.to_hoa();
+        assert!(hoa.contains("\"z\""));
+        let read: DFA = DFA::from_hoa(&hoa).unwrap();
+        assert_eq!(read.get_delta(), with_z.get_delta());
+        assert!(read.get_alphabet().contains(&Symbol::from_str("z")));
+    }
+}
@@ -1,11 +1,53 @@
 use std::{collections::HashMap, hash::Hash, fmt::Debug};
 
 use serde_json::Value;
-use crate::{FSM, State, Transition, BTSet, Symbol, DFA};
+use crate::{FSM, State, Transition, BTSet, Symbol, DFA, AutomatonError};
+use crate::regex::{self, EliminationOrder, Regex};
 
-pub trait AutomateJsonIO{
+mod sealed {
+    use crate::NDFA;
+
+    /// La conversion en NDFA sur laquelle reposent les algorithmes génériques (équivalence, décision, énumération, ...)
+    pub trait ToNdfa {
+        fn to_ndfa(&self) -> NDFA;
+    }
+}
+
+pub(crate) use sealed::ToNdfa;
+
+/// Les automates acceptés par les algorithmes génériques (`equivalent`, `included_in`, `concat`, `star`,
+/// `AutomateDecision`, `AutomateWords`, ...)
+///
+/// Ce trait permet d'écrire des fonctions génériques qui appellent ces algorithmes. Il est implémenté par `DFA`,
+/// `NDFA` et `NDFAEpsilon`, et ne peut pas être implémenté en dehors de la crate.
+///
+/// # Examples
+///
+/// ```
+/// use automaters::*;
+/// use std::fmt::Debug;
+/// use std::hash::Hash;
+///
+/// fn same_language<T: Clone + Hash + Debug, A: AutomateTrait<T> + AutomateNdfa>(a: &A, b: &A) -> bool {
+///     equivalent(a, b).is_ok()
+/// }
+///
+/// fn main() {
+///     let nfa : NDFA = NDFA::from_json_file("src/automates/NFA1.json");
+///     assert!(same_language(&nfa.to_dfa(), &nfa.to_dfa().minimize_hopcroft().0));
+/// }
+/// ```
+pub trait AutomateNdfa: ToNdfa {}
+
+impl<A: ToNdfa + ?Sized> AutomateNdfa for A {}
+
+pub trait AutomateJsonIO : Sized {
     fn from_json(content_json : &Value) -> Self;
     fn from_json_file(path : &str) -> Self;
+    fn try_from_json(content_json : &Value) -> Result<Self, AutomatonError>;
+    fn try_from_json_file(path : &str) -> Result<Self, AutomatonError>;
+    fn to_json(&self) -> Value;
+    fn to_json_file(&self, path : &str) -> Result<(), AutomatonError>;
 }
 
 pub trait AutomateTrait<T : Clone + Hash +Debug>{
@@ -24,4 +66,33 @@ pub trait AutomateTrait<T : Clone + Hash +Debug>{
     }
     fn accept(&self, _word: &str) -> bool;
     fn to_dfa(&self) -> DFA;
+
+    /// Convertit l'automate en expression régulière par élimination d'états, en éliminant d'abord les états ayant le moins de transitions
+    ///
+    /// ```
+    /// use automaters::*;
+    /// fn main() {
+    ///     let dfa : DFA = DFA::from_json_file("src/automates/DFA2.json");
+    ///     let regex : Regex = dfa.to_regex();
+    ///     assert!(equivalent(&regex.to_thompson(), &dfa).is_ok());
+    /// }
+    /// ```
+    fn to_regex(&self) -> Regex
+    where
+        Self: ToNdfa,
+    {
+        self.to_regex_with(EliminationOrder::default())
+    }
+
+    /// Convertit l'automate en expression régulière par élimination d'états, dans l'ordre donné
+    ///
+    /// L'automate est complété par un unique état initial et un unique état final, puis chaque état est retiré en
+    /// reportant ses chemins sur les transitions de ses voisins. L'expression obtenue est simplifiée par `Regex::simplify`,
+    /// et peut être relue par `Regex::parse` lorsque chaque symbole est un unique caractère.
+    fn to_regex_with(&self, order: EliminationOrder) -> Regex
+    where
+        Self: ToNdfa,
+    {
+        regex::eliminate(&self.to_ndfa(), order)
+    }
 }
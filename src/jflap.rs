@@ -0,0 +1,357 @@
+use crate::nfae::EPSILON;
+use crate::state::fresh_state;
+use crate::xml::{self, Element};
+use crate::{AutomateTrait, AutomatonError, BTSet, NDFAEpsilon, State, Symbol, Transition, DFA, FSM, NDFA};
+use std::collections::HashMap;
+use std::f64::consts::PI;
+use std::fmt::Write;
+use std::fs;
+
+/// L'import et l'export d'automates au format `.jff` de JFLAP
+///
+/// Seuls les automates finis (`<type>fa</type>`) sont supportés. Une transition dont la lecture est vide (ou vaut `λ`
+/// ou `ε`) est une ε-transition, et chaque état est nommé par son attribut `name` (à défaut `q` suivi de son `id`).
+/// JFLAP n'autorise qu'un état initial, mais plusieurs états marqués `<initial/>` sont lus comme les états initiaux
+/// d'un NDFA. A l'écriture, plusieurs états initiaux sont remplacés par un nouvel état initial `init`, relié à chacun
+/// d'eux par une transition λ pour un NDFAEpsilon, ou reprenant leurs transitions pour un NDFA.
+pub trait AutomateJflap: Sized {
+    /// Créer un automate depuis le contenu d'un fichier JFLAP
+    fn from_jflap(content: &str) -> Result<Self, AutomatonError>;
+
+    /// Créer un automate depuis le chemin d'un fichier JFLAP
+    fn from_jflap_file(path: &str) -> Result<Self, AutomatonError> {
+        let content: String = fs::read_to_string(path).map_err(|error| AutomatonError::Io {
+            path: path.to_string(),
+            message: error.to_string(),
+        })?;
+        Self::from_jflap(&content)
+    }
+
+    /// Convertit l'automate au format JFLAP, les états étant disposés en cercle
+    fn to_jflap(&self) -> String;
+
+    /// Ecrit l'automate dans un fichier JFLAP
+    fn to_jflap_file(&self, path: &str) -> Result<(), AutomatonError> {
+        fs::write(path, self.to_jflap()).map_err(|error| AutomatonError::Io {
+            path: path.to_string(),
+            message: error.to_string(),
+        })
+    }
+}
+
+// le contenu d'un fichier JFLAP, une transition sans symbole etant une ε-transition
+struct Jflap {
+    states: BTSet<State>,
+    starts: BTSet<State>,
+    ends: BTSet<State>,
+    transitions: Vec<(State, Option<Symbol>, State)>,
+}
+
+impl Jflap {
+    fn read(content: &str) -> Result<Jflap, AutomatonError> {
+        let root: Element = xml::parse(content)?;
+        if root.name != "structure" {
+            return Err(AutomatonError::MalformedXml { message: format!("élément <structure> attendu, <{}> trouvé", root.name) });
+        }
+        let kind: &str = root.child("type").map(|kind| kind.text.trim()).unwrap_or("");
+        if kind != "fa" {
+            return Err(AutomatonError::Unsupported { message: format!("le fichier JFLAP décrit un automate de type \"{}\", seul le type \"fa\" est supporté", kind) });
+        }
+        // JFLAP 7 regroupe les etats et transitions dans <automaton>, JFLAP 6 les place directement dans <structure>
+        let automaton: &Element = root.child("automaton").unwrap_or(&root);
+        let mut jflap: Jflap = Jflap { states: BTSet::new(), starts: BTSet::new(), ends: BTSet::new(), transitions: Vec::new() };
+        let mut ids: HashMap<&str, State> = HashMap::new();
+        for element in automaton.children_named("state") {
+            let id: &str = element.attribute("id").ok_or(AutomatonError::MalformedXml { message: "un état n'a pas d'attribut id".to_string() })?;
+            let state: State = match element.attribute("name") {
+                Some(name) => State::from_str(name),
+                None => State::new(format!("q{}", id)),
+            };
+            if !jflap.states.insert(state.clone()) {
+                return Err(AutomatonError::StateCollision { state: state.get_name().clone() });
+            }
+            if element.child("initial").is_some() {
+                jflap.starts.insert(state.clone());
+            }
+            if element.child("final").is_some() {
+                jflap.ends.insert(state.clone());
+            }
+            ids.insert(id, state);
+        }
+        for (i, element) in automaton.children_named("transition").enumerate() {
+            let state_of = |key: &str| -> Result<State, AutomatonError> {
+                let id: &str = element
+                    .child(key)
+                    .map(|id| id.text.trim())
+                    .ok_or(AutomatonError::MalformedXml { message: format!("la transition {} n'a pas de <{}>", i, key) })?;
+                ids.get(id)
+                    .cloned()
+                    .ok_or(AutomatonError::MalformedXml { message: format!("la transition {} utilise l'état d'identifiant \"{}\" qui n'est pas déclaré", i, id) })
+            };
+            let symbol: Option<Symbol> = match element.child("read").map(|read| read.text.as_str()) {
+                None | Some("") | Some("λ") | Some(EPSILON) => None,
+                Some(read) => Some(Symbol::from_str(read)),
+            };
+            jflap.transitions.push((state_of("from")?, symbol, state_of("to")?));
+        }
+        Ok(jflap)
+    }
+
+    fn alphabet(&self) -> BTSet<Symbol> {
+        BTSet::new().from_vect(self.transitions.iter().filter_map(|(_, symbol, _)| symbol.clone()).collect())
+    }
+
+    // les transitions regroupées par etat et symbole, les ε-transitions etant lues avec `epsilon`
+    fn delta(&self, epsilon: Option<&Symbol>) -> Result<HashMap<Transition<State>, BTSet<State>>, AutomatonError> {
+        let mut delta: HashMap<Transition<State>, BTSet<State>> = HashMap::new();
+        for (from, symbol, to) in &self.transitions {
+            let symbol: Symbol = match (symbol, epsilon) {
+                (Some(symbol), _) => symbol.clone(),
+                (None, Some(epsilon)) => epsilon.clone(),
+                (None, None) => return Err(AutomatonError::Unsupported { message: "le fichier contient des transitions λ, il doit être chargé en NDFAEpsilon".to_string() }),
+            };
+            delta.entry(Transition::new(symbol, from.clone())).or_default().insert(to.clone());
+        }
+        Ok(delta)
+    }
+}
+
+impl AutomateJflap for DFA {
+    /// ```
+    /// use automaters::*;
+    /// fn main() {
+    ///     let dfa : DFA = DFA::from_json_file("src/automates/DFA2.json");
+    ///     let dfa2 : DFA = DFA::from_jflap(&dfa.to_jflap()).unwrap();
+    ///     assert_eq!(dfa.get_delta(), dfa2.get_delta());
+    ///     // JFLAP1 contient une transition λ
+    ///     assert!(DFA::from_jflap_file("src/automates/JFLAP1.jff").is_err());
+    /// }
+    /// ```
+    fn from_jflap(content: &str) -> Result<Self, AutomatonError> {
+        let jflap: Jflap = Jflap::read(content)?;
+        let start: State = match jflap.starts.get().iter().collect::<Vec<&State>>()[..] {
+            [start] => start.clone(),
+            _ => return Err(AutomatonError::Unsupported { message: format!("un DFA doit avoir exactement un état initial, {} trouvés", jflap.starts.len()) }),
+        };
+        let mut delta: HashMap<Transition<State>, State> = HashMap::new();
+        for (transition, images) in jflap.delta(None)? {
+            if images.len() > 1 {
+                return Err(AutomatonError::Unsupported {
+                    message: format!("l'état \"{}\" a plusieurs transitions par \"{}\", il doit être chargé en NDFA", transition.get_content().get_name(), transition.get_symbol().get_value()),
+                });
+            }
+            let image: State = images.get().iter().next().unwrap().clone();
+            delta.insert(transition, image);
+        }
+        let fsm: FSM = FSM::new(jflap.states.clone(), jflap.alphabet(), jflap.ends.clone());
+        Ok(DFA::new(start, delta, fsm))
+    }
+
+    fn to_jflap(&self) -> String {
+        let edges: Vec<(State, Symbol, State)> = self
+            .get_delta()
+            .iter()
+            .map(|(transition, image)| (transition.get_content().clone(), transition.get_symbol().clone(), image.clone()))
+            .collect();
+        write(self.get_states(), &BTSet::new().from_vect(vec![self.get_start().clone()]), self.get_ends(), edges, None)
+    }
+}
+
+impl AutomateJflap for NDFA {
+    /// ```
+    /// use automaters::*;
+    /// fn main() {
+    ///     // NFA3 a trois états initiaux, remplacés par un nouvel état initial
+    ///     let nfa : NDFA = NDFA::from_json_file("src/automates/NFA3.json");
+    ///     let nfa2 : NDFA = NDFA::from_jflap(&nfa.to_jflap()).unwrap();
+    ///     assert_eq!(nfa2.get_starts().len(), 1);
+    ///     assert!(equivalent(&nfa, &nfa2).is_ok());
+    /// }
+    /// ```
+    fn from_jflap(content: &str) -> Result<Self, AutomatonError> {
+        let jflap: Jflap = Jflap::read(content)?;
+        let delta: HashMap<Transition<State>, BTSet<State>> = jflap.delta(None)?;
+        let fsm: FSM = FSM::new(jflap.states.clone(), jflap.alphabet(), jflap.ends.clone());
+        Ok(NDFA::new(jflap.starts, delta, fsm))
+    }
+
+    fn to_jflap(&self) -> String {
+        write(self.get_states(), self.get_starts(), self.get_ends(), edges(self.get_delta()), None)
+    }
+}
+
+impl AutomateJflap for NDFAEpsilon {
+    /// ```
+    /// use automaters::*;
+    /// fn main() {
+    ///     let nfae : NDFAEpsilon = NDFAEpsilon::from_jflap_file("src/automates/JFLAP1.jff").unwrap();
+    ///     assert!(nfae.accept("bab"));
+    ///     assert!(!nfae.accept("ba"));
+    ///     // les ε-transitions sont écrites avec une lecture vide
+    ///     assert!(nfae.to_jflap().contains("<read/>"));
+    /// }
+    /// ```
+    fn from_jflap(content: &str) -> Result<Self, AutomatonError> {
+        let jflap: Jflap = Jflap::read(content)?;
+        let epsilon: Symbol = Symbol::from_str(EPSILON);
+        let delta: HashMap<Transition<State>, BTSet<State>> = jflap.delta(Some(&epsilon))?;
+        let mut alphabet: BTSet<Symbol> = jflap.alphabet();
+        alphabet.insert(epsilon);
+        let fsm: FSM = FSM::new(jflap.states.clone(), alphabet, jflap.ends.clone());
+        Ok(NDFAEpsilon::new(jflap.starts, delta, fsm))
+    }
+
+    fn to_jflap(&self) -> String {
+        write(self.get_states(), self.get_starts(), self.get_ends(), edges(self.get_delta()), Some(&Symbol::from_str(EPSILON)))
+    }
+}
+
+// les transitions (etat, symbole, image) d'un delta non deterministe
+fn edges(delta: &HashMap<Transition<State>, BTSet<State>>) -> Vec<(State, Symbol, State)> {
+    delta
+        .iter()
+        .flat_map(|(transition, images)| images.get().iter().map(move |image| (transition.get_content().clone(), transition.get_symbol().clone(), image.clone())))
+        .collect()
+}
+
+// écrit le fichier JFLAP, les etats triés par nom etant numérotés et disposés en cercle
+fn write(states: &BTSet<State>, starts: &BTSet<State>, ends: &BTSet<State>, mut edges: Vec<(State, Symbol, State)>, epsilon: Option<&Symbol>) -> String {
+    let mut all: BTSet<State> = states.clone();
+    all.insert_all(starts.clone());
+    // les extrémités des transitions sont écrites meme si elles ne sont pas declarées dans les etats
+    for (from, _, to) in &edges {
+        all.insert(from.clone());
+        all.insert(to.clone());
+    }
+    // JFLAP ne garde qu'un etat initial, un nouvel etat mene donc a chacun des etats initiaux
+    let mut ends: BTSet<State> = ends.clone();
+    let start: Option<State> = match starts.get().iter().collect::<Vec<&State>>()[..] {
+        [] => None,
+        [start] => Some(start.clone()),
+        _ => {
+            let init: State = fresh_state(&all, "init");
+            match epsilon {
+                Some(epsilon) => edges.extend(starts.get().iter().map(|start| (init.clone(), epsilon.clone(), start.clone()))),
+                None => {
+                    let copied: Vec<(State, Symbol, State)> = edges
+                        .iter()
+                        .filter(|(from, _, _)| starts.contains(from))
+                        .map(|(_, symbol, to)| (init.clone(), symbol.clone(), to.clone()))
+                        .collect();
+                    edges.extend(copied);
+                    if starts.get().iter().any(|start| ends.contains(start)) {
+                        ends.insert(init.clone());
+                    }
+                }
+            }
+            all.insert(init.clone());
+            Some(init)
+        }
+    };
+    let ids: HashMap<&State, usize> = all.get().iter().enumerate().map(|(i, state)| (state, i)).collect();
+    // le rayon laisse environ 80 points entre deux etats voisins
+    let radius: f64 = (80.0 * all.len() as f64 / (2.0 * PI)).max(100.0);
+    let mut jff: String = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\" standalone=\"no\"?><!--Created with automaters.--><structure>\n");
+    jff.push_str("\t<type>fa</type>\n\t<automaton>\n");
+    for (i, state) in all.get().iter().enumerate() {
+        let angle: f64 = 2.0 * PI * i as f64 / all.len() as f64 + PI;
+        writeln!(jff, "\t\t<state id=\"{}\" name=\"{}\">", i, xml::escape(state.get_name())).unwrap();
+        writeln!(jff, "\t\t\t<x>{:.1}</x>", radius + 100.0 + radius * angle.cos()).unwrap();
+        writeln!(jff, "\t\t\t<y>{:.1}</y>", radius + 100.0 + radius * angle.sin()).unwrap();
+        if start.as_ref() == Some(state) {
+            jff.push_str("\t\t\t<initial/>\n");
+        }
+        if ends.contains(state) {
+            jff.push_str("\t\t\t<final/>\n");
+        }
+        jff.push_str("\t\t</state>\n");
+    }
+    edges.sort();
+    for (from, symbol, to) in edges {
+        jff.push_str("\t\t<transition>\n");
+        writeln!(jff, "\t\t\t<from>{}</from>\n\t\t\t<to>{}</to>", ids[&from], ids[&to]).unwrap();
+        if Some(&symbol) == epsilon {
+            jff.push_str("\t\t\t<read/>\n");
+        } else {
+            writeln!(jff, "\t\t\t<read>{}</read>", xml::escape(symbol.get_value())).unwrap();
+        }
+        jff.push_str("\t\t</transition>\n");
+    }
+    jff.push_str("\t</automaton>\n</structure>\n");
+    jff
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{equivalent, AutomateJsonIO, Regex};
+
+    #[test]
+    fn jflap_import_export() {
+        // un fichier produit par JFLAP 7, avec une transition λ
+        let nfae: NDFAEpsilon = NDFAEpsilon::from_jflap_file("src/automates/JFLAP1.jff").unwrap();
+        assert_eq!(nfae.get_states().len(), 4);
+        assert!(nfae.get_starts().contains(&State::from_str("q0")));
+        assert!(nfae.get_ends().contains(&State::from_str("q3")));
+        assert!(nfae.get_alphabet().contains(&Symbol::from_str(EPSILON)));
+        assert!(equivalent(&nfae, &Regex::parse("(a|b)*ab").unwrap().to_thompson()).is_ok());
+        assert_eq!(
+            NDFA::from_jflap_file("src/automates/JFLAP1.jff").unwrap_err(),
+            AutomatonError::Unsupported { message: "le fichier contient des transitions λ, il doit être chargé en NDFAEpsilon".to_string() }
+        );
+        // aller retour pour chaque type d'automate, la disposition etant generée
+        for name in ["NFA1e", "NFA2e"] {
+            let nfae: NDFAEpsilon = NDFAEpsilon::from_json_file(&format!("src/automates/{}.json", name));
+            let jff: String = nfae.to_jflap();
+            assert_eq!(jff.matches("<x>").count(), nfae.get_states().len());
+            let nfae2: NDFAEpsilon = NDFAEpsilon::from_jflap(&jff).unwrap();
+            assert_eq!(nfae2.get_delta(), nfae.get_delta(), "{}", name);
+            assert_eq!(nfae2.get_ends(), nfae.get_ends(), "{}", name);
+        }
+        let nfa: NDFA = NDFA::from_json_file("src/automates/NFA1.json");
+        assert_eq!(NDFA::from_jflap(&nfa.to_jflap()).unwrap().get_delta(), nfa.get_delta());
+        // plusieurs etats initiaux sont remplacés par un unique etat initial, relié a chacun par une transition λ
+        let nfae: NDFAEpsilon = NDFAEpsilon::from_json_file("src/automates/NFA3e.json");
+        let jff: String = nfae.to_jflap();
+        assert_eq!(jff.matches("<initial/>").count(), 1);
+        let nfae2: NDFAEpsilon = NDFAEpsilon::from_jflap(&jff).unwrap();
+        assert_eq!(nfae2.get_starts(), &BTSet::new().from_vect(vec![State::from_str("init")]));
+        assert_eq!(nfae2.get_delta()[&Transition::new(Symbol::from_str(EPSILON), State::from_str("init"))], nfae.get_starts().clone());
+        assert!(equivalent(&nfae, &nfae2).is_ok());
+        let nfa: NDFA = NDFA::from_json_file("src/automates/NFA3.json");
+        let jff: String = nfa.to_jflap();
+        assert_eq!(jff.matches("<initial/>").count(), 1);
+        assert!(equivalent(&nfa, &NDFA::from_jflap(&jff).unwrap()).is_ok());
+        // un DFA doit avoir un unique etat initial et des transitions deterministes
+        let nfa: NDFA = NDFA::from_json_file("src/automates/NFA3.json");
+        assert!(matches!(DFA::from_jflap(&nfa.to_jflap()), Err(AutomatonError::Unsupported { .. })));
+        let nfa: NDFA = Regex::parse("a|ab").unwrap().to_glushkov().0;
+        assert!(matches!(DFA::from_jflap(&nfa.to_jflap()), Err(AutomatonError::Unsupported { .. })));
+        // les noms sont echappés et JFLAP 6 n'a pas d'element <automaton>
+        let jff: &str = "<structure><type>fa</type><state id=\"0\" name=\"a&lt;b\"><initial/></state><state id=\"1\"><final/></state>\
+                         <transition><from>0</from><to>1</to><read>x</read></transition></structure>";
+        let dfa: DFA = DFA::from_jflap(jff).unwrap();
+        assert_eq!(dfa.get_start(), &State::from_str("a<b"));
+        assert!(dfa.accept("x"));
+        assert!(dfa.get_ends().contains(&State::from_str("q1")));
+        assert!(dfa.to_jflap().contains("name=\"a&lt;b\""));
+        // une image absente des etats est tout de meme écrite
+        let (s, t): (State, State) = (State::from_str("s"), State::from_str("t"));
+        let dfa: DFA = DFA::new(s.clone(), HashMap::from([(Transition::new(Symbol::from_str("a"), s.clone()), t.clone())]), FSM::new(BTSet::new().from_vect(vec![s]), BTSet::new().from_vect(vec![Symbol::from_str("a")]), BTSet::new()));
+        let dfa2: DFA = DFA::from_jflap(&dfa.to_jflap()).unwrap();
+        assert_eq!(dfa2.get_delta(), dfa.get_delta());
+        assert!(dfa2.get_states().contains(&t));
+        // les erreurs
+        assert!(matches!(DFA::from_jflap("<structure><type>turing</type></structure>"), Err(AutomatonError::Unsupported { .. })));
+        assert!(matches!(DFA::from_jflap("<automaton/>"), Err(AutomatonError::MalformedXml { .. })));
+        assert_eq!(
+            NDFA::from_jflap("<structure><type>fa</type><state id=\"0\"/><transition><from>0</from><to>3</to><read>a</read></transition></structure>").unwrap_err(),
+            AutomatonError::MalformedXml { message: "la transition 0 utilise l'état d'identifiant \"3\" qui n'est pas déclaré".to_string() }
+        );
+        assert_eq!(
+            NDFA::from_jflap("<structure><type>fa</type><state id=\"0\" name=\"q\"/><state id=\"1\" name=\"q\"/></structure>").unwrap_err(),
+            AutomatonError::StateCollision { state: "q".to_string() }
+        );
+        assert!(matches!(NDFA::from_jflap_file("src/automates/absent.jff"), Err(AutomatonError::Io { .. })));
+    }
+}
@@ -0,0 +1,139 @@
+// fonctions utilitaires pour la lecture des automates au format json
+use crate::{AutomatonError, BTSet, State, Symbol, Transition};
+use serde_json::{from_str, to_string_pretty, Value};
+use std::collections::HashMap;
+use std::fs;
+
+/// Charge le contenu d'un fichier json
+pub(crate) fn read_json_file(path: &str) -> Result<Value, AutomatonError> {
+    // Charge le contenu du fichier en tant que String
+    let content: String = fs::read_to_string(path).map_err(|error| AutomatonError::Io {
+        path: path.to_string(),
+        message: error.to_string(),
+    })?;
+    // Parse le texte en structure Json
+    from_str::<Value>(&content).map_err(|error| AutomatonError::MalformedJson {
+        message: error.to_string(),
+    })
+}
+
+/// Verifie que la racine du json est bien un objet
+pub(crate) fn expect_root(content_json: &Value) -> Result<(), AutomatonError> {
+    if content_json.is_object() {
+        return Ok(());
+    }
+    Err(AutomatonError::MalformedJson {
+        message: "la racine du document doit être un objet".to_string(),
+    })
+}
+
+/// Reccupere la valeur associée à `key`, `index` indique l'entrée de delta en cours de lecture
+pub(crate) fn get_value<'a>(content_json: &'a Value, key: &str, index: Option<usize>) -> Result<&'a Value, AutomatonError> {
+    content_json.get(key).ok_or(AutomatonError::MissingKey { key: key.to_string(), index })
+}
+
+/// Reccupere la chaîne de caractères associée à `key`
+pub(crate) fn get_str<'a>(content_json: &'a Value, key: &str, index: Option<usize>) -> Result<&'a str, AutomatonError> {
+    get_value(content_json, key, index)?.as_str().ok_or(AutomatonError::WrongType {
+        key: key.to_string(),
+        expected: "une chaîne de caractères".to_string(),
+        index,
+    })
+}
+
+/// Reccupere le tableau associé à `key`
+pub(crate) fn get_array<'a>(content_json: &'a Value, key: &str, index: Option<usize>) -> Result<&'a Vec<Value>, AutomatonError> {
+    get_value(content_json, key, index)?.as_array().ok_or(AutomatonError::WrongType {
+        key: key.to_string(),
+        expected: "un tableau".to_string(),
+        index,
+    })
+}
+
+/// Reccupere le tableau de chaînes de caractères associé à `key`
+pub(crate) fn get_strings<'a>(content_json: &'a Value, key: &str, index: Option<usize>) -> Result<Vec<&'a str>, AutomatonError> {
+    let mut strings: Vec<&str> = Vec::new();
+    for elem in get_array(content_json, key, index)? {
+        strings.push(elem.as_str().ok_or(AutomatonError::WrongType {
+            key: key.to_string(),
+            expected: "un tableau de chaînes de caractères".to_string(),
+            index,
+        })?);
+    }
+    Ok(strings)
+}
+
+/// Reccupere le tableau de chaînes de caractères associé à `key` si la clé est présente
+pub(crate) fn get_optional_strings<'a>(content_json: &'a Value, key: &str) -> Result<Option<Vec<&'a str>>, AutomatonError> {
+    if content_json.get(key).is_none() {
+        return Ok(None);
+    }
+    get_strings(content_json, key, None).map(Some)
+}
+
+/// Reccupere l'entrée `index` de delta en verifiant qu'il s'agit d'un objet
+pub(crate) fn expect_entry(entry: &Value, index: usize) -> Result<&Value, AutomatonError> {
+    if entry.is_object() {
+        return Ok(entry);
+    }
+    Err(AutomatonError::WrongType {
+        key: "delta".to_string(),
+        expected: "un objet".to_string(),
+        index: Some(index),
+    })
+}
+
+/// Verifie qu'un état a bien été déclaré, lorsque la clé `states` est présente
+pub(crate) fn check_declared(declared: &Option<BTSet<State>>, state: &State, key: &str, index: Option<usize>) -> Result<(), AutomatonError> {
+    if let Some(states) = declared {
+        if !states.contains(state) {
+            return Err(AutomatonError::UnknownState {
+                key: key.to_string(),
+                state: state.get_name().clone(),
+                index,
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Reccupere les états déclarés dans la clé `states`
+pub(crate) fn get_declared_states(content_json: &Value) -> Result<Option<BTSet<State>>, AutomatonError> {
+    let names: Option<Vec<&str>> = get_optional_strings(content_json, "states")?;
+    Ok(names.map(|names| {
+        let mut states: BTSet<State> = BTSet::new();
+        for name in names {
+            states.insert(State::from_str(name));
+        }
+        states
+    }))
+}
+
+/// Ecrit le contenu json dans un fichier
+pub(crate) fn write_json_file(path: &str, content_json: &Value) -> Result<(), AutomatonError> {
+    // le json est indenté pour rester lisible
+    let content: String = to_string_pretty(content_json).map_err(|error| AutomatonError::MalformedJson {
+        message: error.to_string(),
+    })?;
+    fs::write(path, content).map_err(|error| AutomatonError::Io {
+        path: path.to_string(),
+        message: error.to_string(),
+    })
+}
+
+/// Convertit un set d'états en tableau json
+pub(crate) fn states_to_json(states: &BTSet<State>) -> Value {
+    Value::Array(states.get().iter().map(|state| Value::String(state.get_name().clone())).collect())
+}
+
+/// Convertit un set de symboles en tableau json
+pub(crate) fn symbols_to_json(symbols: &BTSet<Symbol>) -> Value {
+    Value::Array(symbols.get().iter().map(|symbol| Value::String(symbol.get_value().clone())).collect())
+}
+
+/// Trie les transitions par état puis par symbole, pour que le json produit soit stable
+pub(crate) fn sorted_transitions<T>(delta: &HashMap<Transition<State>, T>) -> Vec<(&Transition<State>, &T)> {
+    let mut transitions: Vec<(&Transition<State>, &T)> = delta.iter().collect();
+    transitions.sort_by(|(a, _), (b, _)| (a.get_content(), a.get_symbol()).cmp(&(b.get_content(), b.get_symbol())));
+    transitions
+}
@@ -1,3 +1,7 @@
+mod error;
+pub use error::AutomatonError;
+mod json;
+mod xml;
 mod state;
 pub use state::State;
 mod symbol;
@@ -13,6 +17,35 @@ pub use nfa::NDFA;
 mod nfae;
 pub use nfae::NDFAEpsilon;
 mod btset;
+#[cfg(test)]
+mod test_utils;
 pub use btset::{BTSet};
 mod interfaces;
-pub use interfaces::{AutomateJsonIO, AutomateTrait};
\ No newline at end of file
+pub use interfaces::{AutomateJsonIO, AutomateNdfa, AutomateTrait};
+mod minimization;
+mod product;
+mod equivalence;
+pub use equivalence::{equivalent, included_in, Counterexample};
+mod rational;
+pub use rational::{concat, optional, plus, star};
+mod decision;
+pub use decision::{AutomateDecision, Finiteness, PumpingWitness};
+mod words;
+pub use words::{AutomateWords, Words};
+mod trim;
+pub use trim::{AutomateTrim, TrimReport, UselessReason};
+mod dot;
+pub use dot::{AutomateDot, DotOptions, RankDir};
+mod diagram;
+pub use diagram::AutomateDiagram;
+mod jflap;
+pub use jflap::AutomateJflap;
+mod hoa;
+pub use hoa::AutomateHoa;
+mod att;
+pub use att::{AttMode, AutomateAtt, SymbolTable};
+pub mod regex;
+pub use regex::{EliminationOrder, ParseError, PositionSets, Regex};
+pub use minimization::Distinguishability;
+mod validation;
+pub use validation::{AutomateValidation, Diagnostic, DiagnosticKind, Severity};
\ No newline at end of file
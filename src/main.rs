@@ -1,3 +1,4 @@
+#[allow(clippy::print_literal)]
 fn main() {
     println!("Rust Automate par {}.", "@Au = Or[79]");
 }
\ No newline at end of file
@@ -0,0 +1,508 @@
+use crate::{AutomateTrait, BTSet, State, Symbol, Transition, DFA, FSM};
+use std::collections::{HashMap, HashSet, VecDeque};
+
+// version indexée d'un DFA complet et accessible, utilisée par les algorithmes de minimisation
+pub(crate) struct IndexedDFA {
+    // les etats, dans l'ordre de BTSet
+    pub(crate) states: Vec<State>,
+    pub(crate) alphabet: Vec<Symbol>,
+    // transitions[q][a] est l'indice de l'image de q par le symbole a
+    pub(crate) transitions: Vec<Vec<usize>>,
+    pub(crate) finals: Vec<bool>,
+    pub(crate) start: usize,
+    // l'etat puits ajouté par la completion, s'il a été ajouté
+    pub(crate) sink: Option<usize>,
+}
+
+impl IndexedDFA {
+    /// Complete le DFA avec un puits, retire les états inaccessibles et numérote les états restants
+    pub(crate) fn new(dfa: &DFA) -> Self {
+        let (complete, sink): (DFA, State) = dfa.completed_with_fresh_sink();
+        let reachable: BTSet<State> = complete.reachable_states();
+        IndexedDFA::build(&complete, &sink, reachable)
+    }
+
+    /// Complete le DFA avec un puits et numérote tout ses états, y compris les états inaccessibles
+    pub(crate) fn with_all_states(dfa: &DFA) -> Self {
+        let (complete, sink): (DFA, State) = dfa.completed_with_fresh_sink();
+        let states: BTSet<State> = complete.get_states().clone();
+        IndexedDFA::build(&complete, &sink, states)
+    }
+
+    // numérote les états donnés d'un DFA complet
+    fn build(complete: &DFA, sink: &State, states: BTSet<State>) -> Self {
+        let states: Vec<State> = states.get().iter().cloned().collect();
+        let alphabet: Vec<Symbol> = complete.get_alphabet().get().iter().cloned().collect();
+        let index: HashMap<&State, usize> = states.iter().enumerate().map(|(i, state)| (state, i)).collect();
+        let mut transitions: Vec<Vec<usize>> = Vec::with_capacity(states.len());
+        for state in &states {
+            transitions.push(
+                alphabet
+                    .iter()
+                    .map(|symbol| index[complete.apply_delta(Transition::new(symbol.clone(), state.clone())).unwrap()])
+                    .collect(),
+            );
+        }
+        IndexedDFA {
+            finals: states.iter().map(|state| complete.get_ends().contains(state)).collect(),
+            start: index[complete.get_start()],
+            sink: index.get(sink).copied(),
+            states,
+            alphabet,
+            transitions,
+        }
+    }
+
+    /// Construit le DFA quotient d'une partition des états
+    ///
+    /// Chaque classe prend le nom du plus petit état d'origine qu'elle contient (le puits n'est utilisé que s'il est seul).
+    ///
+    /// # Return
+    ///
+    /// * `(DFA, HashMap<State, State>)` - Le DFA quotient et la classe de chaque état d'origine
+    ///
+    pub(crate) fn quotient(&self, block_of: &[usize], block_count: usize) -> (DFA, HashMap<State, State>) {
+        // choix du nom de chaque classe
+        let mut names: Vec<Option<&State>> = vec![None; block_count];
+        for (q, state) in self.states.iter().enumerate() {
+            let name: &mut Option<&State> = &mut names[block_of[q]];
+            let is_sink: bool = Some(q) == self.sink;
+            match name {
+                None => *name = Some(state),
+                Some(current) => {
+                    let current_is_sink: bool = self.sink.map(|sink| &self.states[sink]) == Some(*current);
+                    if current_is_sink || (!is_sink && state < *current) {
+                        *name = Some(state);
+                    }
+                }
+            }
+        }
+        let names: Vec<State> = names.into_iter().map(|name| name.unwrap().clone()).collect();
+        let mut _states: BTSet<State> = BTSet::new();
+        let mut _ends: BTSet<State> = BTSet::new();
+        let mut _delta: HashMap<Transition<State>, State> = HashMap::new();
+        let mut classes: HashMap<State, State> = HashMap::new();
+        for (q, state) in self.states.iter().enumerate() {
+            let class: &State = &names[block_of[q]];
+            _states.insert(class.clone());
+            if self.finals[q] {
+                _ends.insert(class.clone());
+            }
+            for (a, symbol) in self.alphabet.iter().enumerate() {
+                _delta.insert(Transition::new(symbol.clone(), class.clone()), names[block_of[self.transitions[q][a]]].clone());
+            }
+            if Some(q) != self.sink {
+                classes.insert(state.clone(), class.clone());
+            }
+        }
+        let _alphabet: BTSet<Symbol> = BTSet::new().from_vect(self.alphabet.clone());
+        let _fsm: FSM = FSM::new(_states, _alphabet, _ends);
+        (DFA::new(names[block_of[self.start]].clone(), _delta, _fsm), classes)
+    }
+}
+
+impl DFA {
+    /// Minimise l'automate par raffinement de partition (algorithme de Hopcroft), en O(n log n)
+    ///
+    /// L'automate est d'abord complété par un état puits, puis les états inaccessibles sont retirés: ils n'ont pas de
+    /// classe et sont absents de la table retournée. Une image absente des états déclarés est traitée comme un état.
+    /// Contrairement à `to_minimize`, les états ne sont pas renommés: chaque classe d'équivalence
+    /// porte le nom du plus petit état d'origine qu'elle contient.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use automaters::*;
+    /// use std::collections::HashMap;
+    /// fn main() {
+    ///     let dfa : DFA = DFA::from_json_file("src/automates/DFA2.json");
+    ///     let (minimal, classes) : (DFA, HashMap<State, State>) = dfa.minimize_hopcroft();
+    ///     assert_eq!(minimal.get_states().len(), 4);
+    ///     assert_eq!(classes[&State::from_str("A")], State::from_str("A"));
+    ///     assert!(minimal.accept("00011"));
+    /// }
+    /// ```
+    ///
+    /// # Return
+    ///
+    /// * `(DFA, HashMap<State, State>)` - Le DFA minimal complet, et la classe de chaque état accessible d'origine
+    ///
+    pub fn minimize_hopcroft(&self) -> (DFA, HashMap<State, State>) {
+        let dfa: IndexedDFA = IndexedDFA::new(self);
+        let n: usize = dfa.states.len();
+        let k: usize = dfa.alphabet.len();
+        // transitions inverses: inverse[a][q] contient les etats qui menent à q par a
+        let mut inverse: Vec<Vec<Vec<usize>>> = vec![vec![Vec::new(); n]; k];
+        for q in 0..n {
+            for a in 0..k {
+                inverse[a][dfa.transitions[q][a]].push(q);
+            }
+        }
+        // la partition est rangée dans `elements`: la classe b occupe elements[first[b]..end[b]], et les etats
+        // marqués d'une classe sont placés en tete, avant split[b], pour la couper sans la parcourir entierement
+        let mut elements: Vec<usize> = (0..n).filter(|&q| dfa.finals[q]).collect();
+        let final_count: usize = elements.len();
+        elements.extend((0..n).filter(|&q| !dfa.finals[q]));
+        let mut location: Vec<usize> = vec![0; n];
+        for (i, &q) in elements.iter().enumerate() {
+            location[q] = i;
+        }
+        // partition initiale: etats finaux et non finaux
+        let mut first: Vec<usize> = Vec::new();
+        let mut end: Vec<usize> = Vec::new();
+        let mut block_of: Vec<usize> = vec![0; n];
+        for (from, to) in [(0, final_count), (final_count, n)] {
+            if from < to {
+                for &q in &elements[from..to] {
+                    block_of[q] = first.len();
+                }
+                first.push(from);
+                end.push(to);
+            }
+        }
+        let mut split: Vec<usize> = first.clone();
+        // les separateurs (classe, symbole) restant à traiter
+        let mut waiting: VecDeque<(usize, usize)> = VecDeque::new();
+        let mut in_waiting: HashSet<(usize, usize)> = HashSet::new();
+        if first.len() == 2 {
+            // il suffit d'ajouter la plus petite des deux classes
+            let smallest: usize = if end[0] - first[0] <= end[1] - first[1] { 0 } else { 1 };
+            for a in 0..k {
+                waiting.push_back((smallest, a));
+                in_waiting.insert((smallest, a));
+            }
+        }
+        while let Some((splitter, a)) = waiting.pop_front() {
+            in_waiting.remove(&(splitter, a));
+            // les etats qui menent dans le separateur par a sont marqués dans leur classe
+            let predecessors: Vec<usize> = elements[first[splitter]..end[splitter]].iter().flat_map(|&q| inverse[a][q].iter().copied()).collect();
+            let mut touched: Vec<usize> = Vec::new();
+            for p in predecessors {
+                let b: usize = block_of[p];
+                if location[p] < split[b] {
+                    // deja marqué
+                    continue;
+                }
+                if split[b] == first[b] {
+                    touched.push(b);
+                }
+                let other: usize = elements[split[b]];
+                elements.swap(location[p], split[b]);
+                location[other] = location[p];
+                location[p] = split[b];
+                split[b] += 1;
+            }
+            touched.sort_unstable();
+            for b in touched {
+                if split[b] == end[b] {
+                    // la classe n'est pas coupée
+                    split[b] = first[b];
+                    continue;
+                }
+                // la partie marquée de la classe b forme une nouvelle classe, seuls ses etats sont renumérotés
+                let new_block: usize = first.len();
+                for &q in &elements[first[b]..split[b]] {
+                    block_of[q] = new_block;
+                }
+                first.push(first[b]);
+                end.push(split[b]);
+                split.push(first[b]);
+                first[b] = split[b];
+                let inside_len: usize = end[new_block] - first[new_block];
+                let outside_len: usize = end[b] - first[b];
+                for c in 0..k {
+                    if in_waiting.contains(&(b, c)) {
+                        waiting.push_back((new_block, c));
+                        in_waiting.insert((new_block, c));
+                    } else {
+                        // on ne traite que la plus petite moitié
+                        let smallest: usize = if inside_len <= outside_len { new_block } else { b };
+                        waiting.push_back((smallest, c));
+                        in_waiting.insert((smallest, c));
+                    }
+                }
+            }
+        }
+        dfa.quotient(&block_of, first.len())
+    }
+}
+
+/// Le résultat de l'algorithme de Moore (remplissage de table) sur un DFA
+///
+/// Il contient les classes d'équivalence des états et, pour chaque paire d'états distinguables,
+/// un plus court mot qui mene l'un des deux états dans un état final et pas l'autre.
+#[derive(Debug, Clone)]
+pub struct Distinguishability {
+    classes: Vec<BTSet<State>>,
+    witnesses: HashMap<(State, State), Vec<Symbol>>,
+}
+
+impl Distinguishability {
+    /// Retourne les classes d'équivalence des états, triées par leur plus petit état
+    pub fn get_classes(&self) -> &Vec<BTSet<State>> {
+        &self.classes
+    }
+
+    /// Retourne le mot distinguant chaque paire d'états distinguables, la paire étant rangée dans l'ordre des états
+    pub fn get_witnesses(&self) -> &HashMap<(State, State), Vec<Symbol>> {
+        &self.witnesses
+    }
+
+    /// Indique si deux états sont équivalents
+    pub fn are_equivalent(&self, p: &State, q: &State) -> bool {
+        self.explain_distinguishable(p, q).is_none()
+    }
+
+    /// Retourne un plus court mot qui distingue `p` de `q`, ou `None` si les deux états sont équivalents
+    ///
+    /// # Arguments
+    ///
+    /// * `p` - Le premier état
+    /// * `q` - Le second état
+    ///
+    /// # Return
+    ///
+    /// * `Option<&Vec<Symbol>>` - Le mot accepté depuis l'un des états et refusé depuis l'autre
+    ///
+    pub fn explain_distinguishable(&self, p: &State, q: &State) -> Option<&Vec<Symbol>> {
+        if p <= q {
+            self.witnesses.get(&(p.clone(), q.clone()))
+        } else {
+            self.witnesses.get(&(q.clone(), p.clone()))
+        }
+    }
+}
+
+impl DFA {
+    /// Calcule l'équivalence des états par l'algorithme de Moore (remplissage de table)
+    ///
+    /// Les paires d'états sont marquées longueur par longueur: une paire est distinguée par le mot vide si
+    /// un seul des deux états est final, puis par `a·w` si leurs images par `a` sont distinguées par `w`.
+    /// Le mot retenu pour chaque paire est donc un plus court mot distinguant. Tout les états de l'automate
+    /// sont considérés, y compris les états inaccessibles. Les transitions manquantes menent à un puits implicite.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use automaters::*;
+    /// fn main() {
+    ///     let dfa : DFA = DFA::from_json_file("src/automates/DFA2.json");
+    ///     let result : Distinguishability = dfa.moore_equivalence();
+    ///     assert_eq!(result.get_classes().len(), 4);
+    ///     // depuis C, le mot "0" mene à l'etat final D, depuis B il mene à B
+    ///     let word : &Vec<Symbol> = result.explain_distinguishable(&State::from_str("B"), &State::from_str("C")).unwrap();
+    ///     assert_eq!(word, &vec![Symbol::from_str("0")]);
+    /// }
+    /// ```
+    ///
+    /// # Return
+    ///
+    /// * `Distinguishability` - Les classes d'équivalence et les mots distinguant les états
+    ///
+    pub fn moore_equivalence(&self) -> Distinguishability {
+        let dfa: IndexedDFA = IndexedDFA::with_all_states(self);
+        let n: usize = dfa.states.len();
+        // word[p][q] est le plus court mot distinguant p et q, s'il est connu
+        let mut word: Vec<Vec<Option<Vec<usize>>>> = dfa
+            .finals
+            .iter()
+            .map(|p| dfa.finals.iter().map(|q| if p != q { Some(Vec::new()) } else { None }).collect())
+            .collect();
+        // a chaque tour, on ne s'appuie que sur les paires marquées aux tours precedents pour garder les mots les plus courts
+        let mut changed: bool = true;
+        while changed {
+            changed = false;
+            let previous: Vec<Vec<Option<Vec<usize>>>> = word.clone();
+            for (p, row) in word.iter_mut().enumerate() {
+                for (q, cell) in row.iter_mut().enumerate() {
+                    if cell.is_some() {
+                        continue;
+                    }
+                    for a in 0..dfa.alphabet.len() {
+                        if let Some(suffix) = &previous[dfa.transitions[p][a]][dfa.transitions[q][a]] {
+                            let mut distinguishing: Vec<usize> = vec![a];
+                            distinguishing.extend(suffix);
+                            *cell = Some(distinguishing);
+                            changed = true;
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+        // les classes d'equivalence, sans le puits
+        let mut classes: Vec<BTSet<State>> = Vec::new();
+        let mut witnesses: HashMap<(State, State), Vec<Symbol>> = HashMap::new();
+        let mut assigned: Vec<bool> = vec![false; n];
+        for p in 0..n {
+            if Some(p) == dfa.sink {
+                continue;
+            }
+            if !assigned[p] {
+                let mut class: BTSet<State> = BTSet::new();
+                for q in p..n {
+                    if Some(q) != dfa.sink && word[p][q].is_none() {
+                        assigned[q] = true;
+                        class.insert(dfa.states[q].clone());
+                    }
+                }
+                classes.push(class);
+            }
+            for (q, cell) in word[p].iter().enumerate().skip(p + 1) {
+                if Some(q) == dfa.sink {
+                    continue;
+                }
+                if let Some(distinguishing) = cell {
+                    witnesses.insert(
+                        (dfa.states[p].clone(), dfa.states[q].clone()),
+                        distinguishing.iter().map(|&a| dfa.alphabet[a].clone()).collect(),
+                    );
+                }
+            }
+        }
+        Distinguishability { classes, witnesses }
+    }
+
+    /// Retourne un plus court mot qui distingue les états `p` et `q`, ou `None` s'ils sont équivalents
+    ///
+    /// # Arguments
+    ///
+    /// * `p` - Le premier état
+    /// * `q` - Le second état
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use automaters::*;
+    /// fn main() {
+    ///     let dfa : DFA = DFA::from_json_file("src/automates/DFA3.json");
+    ///     let word : Vec<Symbol> = dfa.explain_distinguishable(&State::from_str("q_0"), &State::from_str("q_2")).unwrap();
+    ///     assert_eq!(word, vec![]);
+    /// }
+    /// ```
+    ///
+    /// # Return
+    ///
+    /// * `Option<Vec<Symbol>>` - Le mot accepté depuis l'un des états et refusé depuis l'autre
+    ///
+    pub fn explain_distinguishable(&self, p: &State, q: &State) -> Option<Vec<Symbol>> {
+        self.moore_equivalence().explain_distinguishable(p, q).cloned()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::AutomateJsonIO;
+    use crate::test_utils::words;
+    use serde_json::json;
+
+    #[test]
+    fn hopcroft() {
+        // B et C sont equivalents, Z est inaccessible
+        let dfa: DFA = DFA::from_json(&json!({
+            "states" : ["A", "B", "C", "D", "Z"],
+            "alphabet" : ["a", "b"],
+            "ends" : ["D"],
+            "start" : "A",
+            "delta" : [
+                {"state" : "A", "symbol" : "a", "image" : "B"},
+                {"state" : "A", "symbol" : "b", "image" : "C"},
+                {"state" : "B", "symbol" : "a", "image" : "D"},
+                {"state" : "C", "symbol" : "a", "image" : "D"},
+                {"state" : "Z", "symbol" : "a", "image" : "D"}
+            ]
+        }));
+        let (minimal, classes): (DFA, HashMap<State, State>) = dfa.minimize_hopcroft();
+        // A, {B, C}, D et le puits
+        assert_eq!(minimal.get_states().len(), 4);
+        assert_eq!(classes[&State::from_str("B")], State::from_str("B"));
+        assert_eq!(classes[&State::from_str("C")], State::from_str("B"));
+        assert_eq!(classes[&State::from_str("D")], State::from_str("D"));
+        assert!(!classes.contains_key(&State::from_str("Z")));
+        // une image et un etat initial non declarés sont des etats comme les autres
+        let (s, t): (State, State) = (State::from_str("s"), State::from_str("t"));
+        let undeclared: DFA = DFA::new(s.clone(), HashMap::from([(Transition::new(Symbol::from_str("a"), s.clone()), t.clone())]), FSM::new(BTSet::new(), BTSet::new().from_vect(vec![Symbol::from_str("a")]), BTSet::new().from_vect(vec![t.clone()])));
+        let (undeclared_minimal, undeclared_classes): (DFA, HashMap<State, State>) = undeclared.minimize_hopcroft();
+        assert_eq!(undeclared_minimal.get_states().len(), 3);
+        assert_eq!(undeclared_classes[&t], t);
+        assert!(undeclared_minimal.accept("a") && !undeclared_minimal.accept("aa"));
+        assert_eq!(undeclared.moore_equivalence().get_classes().len(), 2);
+        assert!(minimal.get_states().contains(&State::from_str("puits")));
+        for word in words(&["a", "b"], 6) {
+            assert_eq!(dfa.accept(&word), minimal.accept(&word), "{:?}", word);
+        }
+        // un DFA déjà minimal et complet garde ses états
+        let dfa: DFA = DFA::from_json_file("src/automates/DFA2.json");
+        let (minimal, _): (DFA, HashMap<State, State>) = dfa.minimize_hopcroft();
+        assert_eq!(minimal.get_states(), dfa.get_states());
+        assert_eq!(minimal.get_delta(), dfa.get_delta());
+        // même langage que la minimisation de Brzozowski
+        for link_file in ["src/automates/DFA1.json", "src/automates/DFA3.json"] {
+            let dfa: DFA = DFA::from_json_file(link_file);
+            let (minimal, _): (DFA, HashMap<State, State>) = dfa.minimize_hopcroft();
+            let brzozowski: DFA = dfa.to_minimize();
+            for word in words(&["a", "b"], 8) {
+                assert_eq!(dfa.accept(&word), minimal.accept(&word), "{} {:?}", link_file, word);
+                assert_eq!(brzozowski.accept(&word), minimal.accept(&word), "{} {:?}", link_file, word);
+            }
+        }
+        // le resultat est minimal: ses etats sont deux à deux distinguables
+        for pattern in ["(a|b)*abb", "(a|b)*a(a|b)(a|b)", "((a|b)(a|b))*", "(ab|ba)*a?", "a*b*a*"] {
+            let dfa: DFA = crate::Regex::parse(pattern).unwrap().to_thompson().to_dfa();
+            let (minimal, _): (DFA, HashMap<State, State>) = dfa.minimize_hopcroft();
+            assert_eq!(minimal.moore_equivalence().get_classes().len(), minimal.get_states().len(), "{}", pattern);
+            assert!(crate::equivalent(&dfa, &minimal).is_ok(), "{}", pattern);
+        }
+    }
+
+    // execute un mot depuis un etat quelconque
+    fn accept_from(dfa: &DFA, state: &State, word: &[Symbol]) -> bool {
+        let mut current: State = state.clone();
+        for symbol in word {
+            current = match dfa.apply_delta(Transition::new(symbol.clone(), current)) {
+                Some(image) => image.clone(),
+                None => return false,
+            };
+        }
+        dfa.get_ends().contains(&current)
+    }
+
+    #[test]
+    fn moore() {
+        let dfa: DFA = DFA::from_json(&json!({
+            "states" : ["A", "B", "C", "D", "E", "Z"],
+            "alphabet" : ["a", "b"],
+            "ends" : ["E"],
+            "start" : "A",
+            "delta" : [
+                {"state" : "A", "symbol" : "a", "image" : "B"},
+                {"state" : "A", "symbol" : "b", "image" : "C"},
+                {"state" : "B", "symbol" : "a", "image" : "D"},
+                {"state" : "C", "symbol" : "a", "image" : "D"},
+                {"state" : "D", "symbol" : "b", "image" : "E"},
+                {"state" : "Z", "symbol" : "a", "image" : "Z"}
+            ]
+        }));
+        let result: Distinguishability = dfa.moore_equivalence();
+        // B et C sont equivalents, Z est equivalent au puits donc seul dans sa classe
+        assert!(result.are_equivalent(&State::from_str("B"), &State::from_str("C")));
+        assert_eq!(result.get_classes().len(), 5);
+        // le plus court mot distinguant A et B est "ab"
+        assert_eq!(result.explain_distinguishable(&State::from_str("B"), &State::from_str("A")), Some(&vec![Symbol::from_str("a"), Symbol::from_str("b")]));
+        assert_eq!(dfa.explain_distinguishable(&State::from_str("D"), &State::from_str("E")), Some(vec![]));
+        // chaque mot distingue effectivement sa paire, et aucun mot plus court ne le fait
+        for ((p, q), word) in result.get_witnesses() {
+            assert_ne!(accept_from(&dfa, p, word), accept_from(&dfa, q, word));
+            if !word.is_empty() {
+                for shorter in words(&["a", "b"], word.len() - 1) {
+                    let shorter: Vec<Symbol> = shorter.chars().map(|c| Symbol::new(c.to_string())).collect();
+                    assert_eq!(accept_from(&dfa, p, &shorter), accept_from(&dfa, q, &shorter));
+                }
+            }
+        }
+        // coherent avec Hopcroft sur les etats accessibles
+        let (minimal, _): (DFA, HashMap<State, State>) = dfa.minimize_hopcroft();
+        assert_eq!(minimal.get_states().len(), 5);
+    }
+}
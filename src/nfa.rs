@@ -1,10 +1,11 @@
 use crate::interfaces::AutomateJsonIO;
-use crate::{DFA, AutomateTrait};
+use crate::{DFA, AutomateTrait, AutomatonError};
+use crate::json;
+use crate::interfaces::ToNdfa;
 
 use super::{Transition, State,Symbol, FSM, BTSet};
 use std::collections::{HashMap};
-use std::{fs};
-use serde_json::{Value, from_str};
+use serde_json::{json, Value};
 
 /// Automate a état fini déterministe
 #[derive(Debug, Clone)]
@@ -97,6 +98,7 @@ impl NDFA {
         }
     }
       /// Applique une transition et renvoie un set d'etat (representant l'image de la transition)
+      #[allow(clippy::needless_return)]
       pub fn apply_delta(&self, transition : Transition<State>)-> Option<BTSet<State>>{
         if let Some(n) = self.get_delta().get(&transition) {
             return Some(n.clone());
@@ -104,7 +106,19 @@ impl NDFA {
         return None;
     }
 
+    /// Retourne les états déclarés, les états initiaux et les extrémités des transitions, meme s'ils ne sont pas déclarés
+    pub(crate) fn used_states(&self) -> BTSet<State> {
+        let mut states : BTSet<State> = self.get_states().clone();
+        states.insert_all(self.get_starts().clone());
+        for (transition, images) in self.get_delta() {
+            states.insert(transition.get_content().clone());
+            states.insert_all(images.clone());
+        }
+        states
+    }
+
     /// Applique les transition et renvoie un set d'etat (representant l'image de la transition)
+    #[allow(clippy::needless_else, clippy::needless_return, clippy::partialeq_to_none)]
     pub fn apply_deltas(&self,set_transition : Transition<BTSet<State>>) -> Option<BTSet<State>>{
         let mut images : BTSet<State> = BTSet::new();
         let mut current: Option<BTSet<State>>;
@@ -124,6 +138,24 @@ impl NDFA {
         return Some(images);
     }
 
+    /// Déterminise l'automate comme `to_dfa`, en retournant aussi le set d'états représenté par chaque état du DFA
+    ///
+    /// ```
+    /// use automaters::*;
+    /// use std::collections::HashMap;
+    /// fn main() {
+    ///     let nfa : NDFA = NDFA::from_json_file("src/automates/NFA3.json");
+    ///     let (dfa, subsets) : (DFA, HashMap<State, BTSet<State>>) = nfa.to_dfa_with_subsets();
+    ///     assert_eq!(subsets[dfa.get_start()], *nfa.get_starts());
+    /// }
+    /// ```
+    pub fn to_dfa_with_subsets(&self) -> (DFA, HashMap<State, BTSet<State>>) {
+        // determinisation classique: l'image d'un set d'etats est l'union des images de chaque etat
+        subset_construction(self.get_starts().clone(), self.get_alphabet().clone(), self.get_ends(), |states, letter| {
+            self.apply_deltas(Transition::new(letter.clone(), states.clone()))
+        })
+    }
+
 }
 impl AutomateJsonIO for NDFA{
     /// Créer un automate à état fini non détérministe depuis un chemin du json
@@ -196,59 +228,9 @@ impl AutomateJsonIO for NDFA{
     /// * `NDFA` - L'automate non déterministe à état fini correspondante
     /// 
     fn from_json(content_json: &Value) -> Self {
-        //creation du NFA à l'aide du content_json
-        let mut symbol: Symbol;
-        let mut state: State;
-        let mut image: State;
-        let mut transition: Transition<State>;
-        
-        let mut alphabet: BTSet<Symbol> = BTSet::new();
-        let mut states: BTSet<State> = BTSet::new();
-        // réccuperation des states de départs
-        let mut starts : BTSet<State> = BTSet::new();
-        for start in content_json["starts"].as_array().unwrap(){
-            state = State::new(start.as_str().unwrap().to_string());
-            starts.insert(state.clone());
-            states.insert(state);
-        }
-        // réccuperation du delta
-        let mut delta: HashMap<Transition<State>, BTSet<State>> = HashMap::new();
-        let mut transition_json: &Value;
-        let mut images : BTSet<State> ;
-
-        for element_delta in content_json["delta"].as_array().unwrap(){
-            transition_json = element_delta;
-            symbol = Symbol::new(transition_json["symbol"].as_str().unwrap().to_string());
-            state = State::new(transition_json["state"].as_str().unwrap().to_string());
-            // generation des images du state
-            images = BTSet::new();
-            for img in transition_json["images"].as_array().unwrap(){
-                image = State::new(img.as_str().unwrap().to_string());
-                states.insert(image.clone());
-                images.insert(image);
-            }
-
-            transition = Transition::new(symbol.clone(), state.clone()); //création de la transition: sur l'etat state, la lecture de state par symbol mene à un set d'images
-            delta.insert(transition, images.clone());
-            
-            states.insert(state);
-            alphabet.insert(symbol);
-        }
-        // reccuperation des etats finaux
-        let mut ends: BTSet<State> = BTSet::new();
-        for elem in content_json["ends"].as_array().unwrap(){
-            state = State::new(elem.as_str().unwrap().to_string());
-            ends.insert(state.clone());
-            states.insert(state);
-        }
-        
-        //on aurait pus directement utiliser l'interfasse de FSM pour enumerer les etat, l'alphabet etc. mais par precaution on le fait mannuellement par apport au contenu des transitions
-        //let fsm = FSM::from_json(content_json);
-        let fsm : FSM = FSM::new(states, alphabet, ends);
-        NDFA { 
-            starts: starts, 
-            delta: delta, 
-            fsm: fsm
+        match NDFA::try_from_json(content_json) {
+            Ok(nfa) => nfa,
+            Err(error) => panic!("{}", error),
         }
     }
 
@@ -322,14 +304,201 @@ impl AutomateJsonIO for NDFA{
     /// * `NDFA` - L'automate déterministe à état fini correspondante
     /// 
     fn from_json_file(path: &str) -> Self {
-        let content_json: Value = {
-            // Charge le contenu du fichier en tant que String
-            let content : String = fs::read_to_string(path).unwrap();
-            // Parse le texte en structure Json
-            from_str::<Value>(&content).unwrap()
-        };
+        match NDFA::try_from_json_file(path) {
+            Ok(nfa) => nfa,
+            Err(error) => panic!("{}", error),
+        }
+    }
+
+    /// Créer un automate à état fini non détérministe depuis du json sans paniquer
+    /// 
+    /// Les clés `states` et `alphabet` sont facultatives, mais lorsque `states` est présente, 
+    /// tout les états utilisés par `starts`, `ends` et `delta` doivent y être déclarés.
+    /// 
+    /// # Arguments
+    ///
+    /// * `content_json` - Le contenu json
+    ///
+    /// # Examples
+    /// 
+    /// ```
+    /// use automaters::*;
+    /// use serde_json::json;
+    /// fn main() {
+    ///     let content_json = json!({
+    ///         "states" : ["s", "t"],
+    ///         "ends" : ["t"],
+    ///         "starts" : ["s"],
+    ///         "delta" : [{"state" : "s", "symbol" : "a", "images" : ["t", "u"]}]
+    ///     });
+    ///     let error : AutomatonError = NDFA::try_from_json(&content_json).unwrap_err();
+    ///     assert_eq!(error, AutomatonError::UnknownState { key: "images".to_string(), state: "u".to_string(), index: Some(0) });
+    /// }
+    /// ```
+    /// 
+    /// # Return
+    ///
+    /// * `Result<NDFA, AutomatonError>` - L'automate correspondant, ou l'erreur rencontrée
+    /// 
+    fn try_from_json(content_json: &Value) -> Result<Self, AutomatonError> {
+        //creation du NFA à l'aide du content_json
+        json::expect_root(content_json)?;
+        // les etats declarés, s'ils sont precisés
+        let declared: Option<BTSet<State>> = json::get_declared_states(content_json)?;
+        let mut symbol: Symbol;
+        let mut state: State;
+        let mut image: State;
+        let mut transition: Transition<State>;
+        
+        let mut alphabet: BTSet<Symbol> = BTSet::new();
+        if let Some(symbols) = json::get_optional_strings(content_json, "alphabet")? {
+            for elem in symbols {
+                alphabet.insert(Symbol::from_str(elem));
+            }
+        }
+        let mut states: BTSet<State> = declared.clone().unwrap_or_default();
+        // réccuperation des states de départs
+        let mut starts : BTSet<State> = BTSet::new();
+        for start in json::get_strings(content_json, "starts", None)? {
+            state = State::from_str(start);
+            json::check_declared(&declared, &state, "starts", None)?;
+            starts.insert(state.clone());
+            states.insert(state);
+        }
+        // réccuperation du delta
+        let mut delta: HashMap<Transition<State>, BTSet<State>> = HashMap::new();
+        let mut transition_json: &Value;
+        let mut images : BTSet<State> ;
+
+        for (index, element_delta) in json::get_array(content_json, "delta", None)?.iter().enumerate(){
+            transition_json = json::expect_entry(element_delta, index)?;
+            symbol = Symbol::from_str(json::get_str(transition_json, "symbol", Some(index))?);
+            state = State::from_str(json::get_str(transition_json, "state", Some(index))?);
+            json::check_declared(&declared, &state, "state", Some(index))?;
+            // generation des images du state
+            images = BTSet::new();
+            for img in json::get_strings(transition_json, "images", Some(index))? {
+                image = State::from_str(img);
+                json::check_declared(&declared, &image, "images", Some(index))?;
+                states.insert(image.clone());
+                images.insert(image);
+            }
+
+            transition = Transition::new(symbol.clone(), state.clone()); //création de la transition: sur l'etat state, la lecture de state par symbol mene à un set d'images
+            delta.insert(transition, images.clone());
+            
+            states.insert(state);
+            alphabet.insert(symbol);
+        }
+        // reccuperation des etats finaux
+        let mut ends: BTSet<State> = BTSet::new();
+        for elem in json::get_strings(content_json, "ends", None)? {
+            state = State::from_str(elem);
+            json::check_declared(&declared, &state, "ends", None)?;
+            ends.insert(state.clone());
+            states.insert(state);
+        }
+        
+        //on aurait pus directement utiliser l'interfasse de FSM pour enumerer les etat, l'alphabet etc. mais par precaution on le fait mannuellement par apport au contenu des transitions
+        //let fsm = FSM::from_json(content_json);
+        let fsm : FSM = FSM::new(states, alphabet, ends);
+        Ok(NDFA { 
+            starts, 
+            delta, 
+            fsm
+        })
+    }
+
+    /// Créer un automate à état fini non détérministe depuis un chemin vers un fichier json sans paniquer
+    /// 
+    /// # Arguments
+    ///
+    /// * `path` - Le chemin vers le fichier json
+    ///
+    /// # Examples
+    /// 
+    /// ```
+    /// use automaters::*;
+    /// fn main() {
+    ///     let nfa : Result<NDFA, AutomatonError> = NDFA::try_from_json_file("src/automates/NFA1.json");
+    ///     assert!(nfa.is_ok());
+    /// }
+    /// ```
+    /// 
+    /// # Return
+    ///
+    /// * `Result<NDFA, AutomatonError>` - L'automate correspondant, ou l'erreur rencontrée
+    /// 
+    fn try_from_json_file(path: &str) -> Result<Self, AutomatonError> {
+        let content_json: Value = json::read_json_file(path)?;
         //creation de la machine
-        NDFA::from_json(&content_json)
+        NDFA::try_from_json(&content_json)
+    }
+    /// Convertit l'automate au format json accepté par `from_json`
+    /// 
+    /// Les états, l'alphabet et les transitions sont triés afin que le json produit soit stable.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use automaters::*;
+    /// use serde_json::Value;
+    /// fn main() {
+    ///     let nfa : NDFA = NDFA::from_json_file("src/automates/NFA1.json");
+    ///     let content_json : Value = nfa.to_json();
+    ///     let nfa2 : NDFA = NDFA::from_json(&content_json);
+    ///     assert_eq!(nfa.get_delta(), nfa2.get_delta());
+    /// }
+    /// ```
+    /// 
+    /// # Return
+    ///
+    /// * `Value` - Le contenu json de l'automate
+    /// 
+    fn to_json(&self) -> Value {
+        // creation des transitions
+        let mut delta: Vec<Value> = Vec::new();
+        for (transition, images) in json::sorted_transitions(self.get_delta()) {
+            delta.push(json!({
+                "state" : transition.get_content().get_name(),
+                "symbol" : transition.get_symbol().get_value(),
+                "images" : json::states_to_json(images)
+            }));
+        }
+        json!({
+            "states" : json::states_to_json(self.get_states()),
+            "alphabet" : json::symbols_to_json(self.get_alphabet()),
+            "ends" : json::states_to_json(self.get_ends()),
+            "starts" : json::states_to_json(self.get_starts()),
+            "delta" : delta
+        })
+    }
+
+    /// Enregistre l'automate dans un fichier json
+    /// 
+    /// # Arguments
+    ///
+    /// * `path` - Le chemin du fichier à écrire
+    ///
+    /// # Examples
+    /// 
+    /// ```
+    /// use automaters::*;
+    /// fn main() {
+    ///     let path : String = std::env::temp_dir().join("nfa_doc.json").display().to_string();
+    ///     let nfa : NDFA = NDFA::from_json_file("src/automates/NFA1.json");
+    ///     nfa.to_json_file(&path).unwrap();
+    ///     let nfa2 : NDFA = NDFA::from_json_file(&path);
+    ///     assert_eq!(nfa.get_ends(), nfa2.get_ends());
+    /// }
+    /// ```
+    /// 
+    /// # Return
+    ///
+    /// * `Result<(), AutomatonError>` - Une erreur si le fichier n'a pas pu être écrit
+    /// 
+    fn to_json_file(&self, path: &str) -> Result<(), AutomatonError> {
+        json::write_json_file(path, &self.to_json())
     }
 }
 
@@ -368,6 +537,7 @@ impl AutomateTrait<BTSet<State>> for NDFA{
     }
     
     /// indique si un mot est accepté dans la langue de l'automate
+    #[allow(clippy::needless_borrow, clippy::needless_return, clippy::partialeq_to_none)]
     fn accept(&self, _word : &str) -> bool {
         let mut symbol : Symbol;
         let mut currents : BTSet<State> = self.get_starts().clone();//etats de depart
@@ -420,109 +590,133 @@ impl AutomateTrait<BTSet<State>> for NDFA{
     /// * `NDFA` - L'automate déterministe à état fini qui correspondante
     /// 
     fn to_dfa(&self) -> DFA {
-        // Un set des images que renvoie une transition
-        let mut state_image : BTSet<State>;
-        let _alphabet :BTSet<Symbol>  = self.get_alphabet().clone();
-        let mut transition :Transition<BTSet<State>>;
-        // les nouveaux states qui seront les states du nouvel automate
-        let mut new_states : BTSet<BTSet<State>> = BTSet::new();
-        let mut temp :Option<BTSet<State>>;
-        // un set de state dont on ne connait pas les images
-        let mut set_state_search_image : BTSet<BTSet<State>> =  BTSet::new();
-        // la table de transition regroupant l'ensemble des transitions du nouvel automate, equivalent à delta
-        let mut table_de_transition : HashMap<Transition<BTSet<State>>, BTSet<State>> = HashMap::new();
-        // HashMap<Transition<State>, BTSet<State>> 
-        let mut set_state_image : BTSet<BTSet<State>> = BTSet::new();
-        // le set de states de departs de self sera le state de depart du nouvel automate
-        let first_state : BTSet<State> = self.get_starts().clone();
-        // ajoute le premier element dans les images
-        new_states.insert(first_state.clone()); 
-        set_state_search_image.insert(first_state.clone()); 
-        let mut continuer : bool = true;
-        // calculs des nouveaux etats, transitions, images
-        while continuer {
-            // parcour l'ensemble des states et des symboles pour creer des transitions et calculer les images
-            for state in set_state_search_image.get() {
-                for letter in _alphabet.get() {
-                    // creation de la transition
-                    transition = Transition::new(letter.clone(),state.clone());
-                    // reccuperation de l'image
-                    temp = self.apply_deltas(transition.clone());
-                    if temp!=None {
-                        // reccuperation du contenu
-                        state_image =temp.unwrap();
-                        // sauvegarde de la transition
-                        table_de_transition.insert(transition, state_image.clone());
-                        // on enregistre le state, plutard on pourra verifier si on le connaissais deja ou pas (si on ne le connaissais pas on l'ajoute dans set_state_search_image pour rechercher ses images au prochain tour)
-                        set_state_image.insert(state_image);
-                    }
-                    // si aucune image n'a ete trouver, on ignore et passe au tour suivant
-                }
-            }
-            // prepare la liste des states dont on ne connait pas les images
-            set_state_search_image =  BTSet::new();
-            for state in set_state_image.get(){
-                // si le state est inconnu
-                if !new_states.contains(state){
-                    // on l'enregistre et on cherche ses transitions/images aux prochains tour
-                    set_state_search_image.insert(state.clone());
-                    new_states.insert(state.clone());
+        self.to_dfa_with_subsets().0
+    }
+}
+
+impl ToNdfa for NDFA {
+    /// renvoie un clone de l'automate actuel puisqu'il est déjà non determinist
+    fn to_ndfa(&self) -> NDFA {
+        self.clone()
+    }
+}
+
+/// Réalise la construction par sous-ensembles d'un automate non déterministe
+///
+/// Chaque set d'états accessible depuis `first_state` devient un état `q_i` du DFA, numéroté dans l'ordre des sets.
+/// Un set est final dès qu'il contient un état de `ends`.
+///
+/// # Arguments
+///
+/// * `first_state` - Le set d'états de départ
+/// * `_alphabet` - L'alphabet du DFA
+/// * `ends` - Les états finaux de l'automate non déterministe
+/// * `step` - Calcule l'image d'un set d'états par un symbole, `None` si l'image est vide
+///
+/// # Return
+///
+/// * `(DFA, HashMap<State, BTSet<State>>)` - Le DFA et le set d'états représenté par chacun de ses états
+///
+pub(crate) fn subset_construction<F>(first_state: BTSet<State>, _alphabet: BTSet<Symbol>, ends: &BTSet<State>, step: F) -> (DFA, HashMap<State, BTSet<State>>)
+where
+    F: Fn(&BTSet<State>, &Symbol) -> Option<BTSet<State>>,
+{
+    // Un set des images que renvoie une transition
+    let mut state_image : BTSet<State>;
+    let mut transition :Transition<BTSet<State>>;
+    // les nouveaux states qui seront les states du nouvel automate
+    let mut new_states : BTSet<BTSet<State>> = BTSet::new();
+    let mut temp :Option<BTSet<State>>;
+    // un set de state dont on ne connait pas les images
+    let mut set_state_search_image : BTSet<BTSet<State>> =  BTSet::new();
+    // la table de transition regroupant l'ensemble des transitions du nouvel automate, equivalent à delta
+    let mut table_de_transition : HashMap<Transition<BTSet<State>>, BTSet<State>> = HashMap::new();
+    // HashMap<Transition<State>, BTSet<State>> 
+    let mut set_state_image : BTSet<BTSet<State>> = BTSet::new();
+    // ajoute le premier element dans les images
+    new_states.insert(first_state.clone()); 
+    set_state_search_image.insert(first_state.clone()); 
+    let mut continuer : bool = true;
+    // calculs des nouveaux etats, transitions, images
+    while continuer {
+        // parcour l'ensemble des states et des symboles pour creer des transitions et calculer les images
+        for state in set_state_search_image.get() {
+            for letter in _alphabet.get() {
+                // creation de la transition
+                transition = Transition::new(letter.clone(),state.clone());
+                // reccuperation de l'image
+                temp = step(state, letter);
+                if let Some(image) = temp {
+                    // reccuperation du contenu
+                    state_image = image;
+                    // sauvegarde de la transition
+                    table_de_transition.insert(transition, state_image.clone());
+                    // on enregistre le state, plutard on pourra verifier si on le connaissais deja ou pas (si on ne le connaissais pas on l'ajoute dans set_state_search_image pour rechercher ses images au prochain tour)
+                    set_state_image.insert(state_image);
                 }
+                // si aucune image n'a ete trouver, on ignore et passe au tour suivant
             }
-            // La liste de state dont on ne connait les images et transition est vide, donc on peut sarreter
-            if set_state_search_image.len() == 0 {
-                continuer = false;
+        }
+        // prepare la liste des states dont on ne connait pas les images
+        set_state_search_image =  BTSet::new();
+        for state in set_state_image.get(){
+            // si le state est inconnu
+            if !new_states.contains(state){
+                // on l'enregistre et on cherche ses transitions/images aux prochains tour
+                set_state_search_image.insert(state.clone());
+                new_states.insert(state.clone());
             }
         }
-        let name : String = "q_".to_string();
-        let mut _states : BTSet<State> = BTSet::new();
-        // listes des etats finaux
-        let mut _ends : BTSet<State> = BTSet::new();
-        let mut _deltas : HashMap<Transition<State>, State> = HashMap::new();
-        // sauvegarde le nom de state de chaque BTSet<state>
-        let mut _concordances  : HashMap<BTSet<State>, State> = HashMap::new();
-        let mut i : usize = 0;
-        let mut _state : State;
-        // creations des states de l'automate
-        for state in new_states.get() {
-            // buffer = name+&i.to_string();
-            _state = State::new( name.clone()+&i.to_string());
-            _states.insert(_state.clone());
-            _concordances.insert(state.clone(), _state.clone());
-            i+=1;
+        // La liste de state dont on ne connait les images et transition est vide, donc on peut sarreter
+        if set_state_search_image.is_empty() {
+            continuer = false;
         }
-        let ends = self.get_ends().get();
-        // buffer temporaire
-        let mut symbol : Symbol ;
-        let mut content : &BTSet<State> ;
-        let mut value :  BTSet<State> ;
-        // creation des transitions de l'automate
-        for (_transition_key, _transition_val) in table_de_transition {
-            symbol = _transition_key.get_symbol().clone();
-            content = &_transition_key.get_content();
-            value = _transition_val;
-            _deltas.insert(Transition::new(symbol, _concordances.get(content).unwrap().clone()), _concordances.get(&value).unwrap().clone());
-            
-            //on parcour les etats finaux de l'automate NFA pour savoir si le state actuel est finaux ou pas
-            for _state in ends {
-                if content.contains(_state){
-                    _ends.insert(_concordances.get(content).unwrap().clone());
-                }
-            }
+    }
+    let name : String = "q_".to_string();
+    let mut _states : BTSet<State> = BTSet::new();
+    // listes des etats finaux
+    let mut _ends : BTSet<State> = BTSet::new();
+    let mut _deltas : HashMap<Transition<State>, State> = HashMap::new();
+    // sauvegarde le nom de state de chaque BTSet<state>
+    let mut _concordances  : HashMap<BTSet<State>, State> = HashMap::new();
+    let mut _subsets : HashMap<State, BTSet<State>> = HashMap::new();
+    let mut _state : State;
+    // creations des states de l'automate
+    for (i, state) in new_states.get().iter().enumerate() {
+        _state = State::new( name.clone()+&i.to_string());
+        _states.insert(_state.clone());
+        _concordances.insert(state.clone(), _state.clone());
+        _subsets.insert(_state.clone(), state.clone());
+        //on parcour les etats finaux de l'automate non deterministe pour savoir si le state actuel est finaux ou pas
+        if state.get().iter().any(|_state_nfa| ends.contains(_state_nfa)) {
+            _ends.insert(_state);
         }
-        let _fsm :FSM = FSM::new(_states, _alphabet, _ends);
-        // création du DFA
-        DFA::new(_concordances.get(&first_state).unwrap().clone(), _deltas, _fsm)
     }
+    // buffer temporaire
+    let mut symbol : Symbol ;
+    let mut content : &BTSet<State> ;
+    let mut value :  BTSet<State> ;
+    // creation des transitions de l'automate
+    for (_transition_key, _transition_val) in table_de_transition {
+        symbol = _transition_key.get_symbol().clone();
+        content = _transition_key.get_content();
+        value = _transition_val;
+        _deltas.insert(Transition::new(symbol, _concordances.get(content).unwrap().clone()), _concordances.get(&value).unwrap().clone());
+    }
+    let _fsm :FSM = FSM::new(_states, _alphabet, _ends);
+    // création du DFA
+    (DFA::new(_concordances.get(&first_state).unwrap().clone(), _deltas, _fsm), _subsets)
 }
-  
-
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use std::fs;
+    use serde_json::from_str;
+    use crate::test_utils::words;
 
     #[test]
+    #[allow(clippy::bool_assert_comparison)]
     fn creation_partial_eq_clone_accept() {      
         let mut link_file: &str = "src/automates/NFA1.json";
         let content_json: Value = {
@@ -563,4 +757,35 @@ mod test {
         assert_eq!(nfa.accept("bbaaaba"), true);
         assert_eq!(nfa.accept("abbaab"), false);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn try_creation_errors() {
+        // les fichiers d'exemples sont valides
+        for link_file in ["src/automates/NFA1.json", "src/automates/NFA2.json", "src/automates/NFA3.json", "src/automates/NFATODFA.json", "src/automates/NFATODFA2.json"] {
+            assert!(NDFA::try_from_json_file(link_file).is_ok());
+        }
+        // clé manquante dans une entrée de delta
+        let mut content_json: Value = json!({"ends" : [], "starts" : ["s"], "delta" : [{"state" : "s", "images" : ["s"]}]});
+        assert_eq!(NDFA::try_from_json(&content_json).unwrap_err(), AutomatonError::MissingKey { key: "symbol".to_string(), index: Some(0) });
+        // mauvais type
+        content_json = json!({"ends" : [], "starts" : "s", "delta" : []});
+        assert_eq!(NDFA::try_from_json(&content_json).unwrap_err(), AutomatonError::WrongType { key: "starts".to_string(), expected: "un tableau".to_string(), index: None });
+        content_json = json!({"ends" : [2], "starts" : ["s"], "delta" : []});
+        assert!(matches!(NDFA::try_from_json(&content_json).unwrap_err(), AutomatonError::WrongType { index: None, .. }));
+        // etat non declaré
+        content_json = json!({"states" : ["s"], "ends" : ["t"], "starts" : ["s"], "delta" : []});
+        assert_eq!(NDFA::try_from_json(&content_json).unwrap_err(), AutomatonError::UnknownState { key: "ends".to_string(), state: "t".to_string(), index: None });
+    }
+
+    #[test]
+    fn to_dfa_same_language() {
+        let alphabets = [("src/automates/NFA1.json", ["a", "b"]), ("src/automates/NFA2.json", ["0", "1"]), ("src/automates/NFA3.json", ["a", "b"]), ("src/automates/NFATODFA.json", ["0", "1"]), ("src/automates/NFATODFA2.json", ["a", "b"])];
+        for (link_file, alphabet) in alphabets {
+            let nfa : NDFA = NDFA::from_json_file(link_file);
+            let dfa : DFA = nfa.to_dfa();
+            for word in words(&alphabet, 7) {
+                assert_eq!(nfa.accept(&word), dfa.accept(&word), "{} sur le mot {:?}", link_file, word);
+            }
+        }
+    }
+}
@@ -1,9 +1,14 @@
-use crate::{DFA, AutomateJsonIO, AutomateTrait};
+use crate::{DFA, NDFA, AutomateJsonIO, AutomateTrait, AutomatonError};
+use crate::json;
+use crate::interfaces::ToNdfa;
+use crate::nfa::subset_construction;
 
 use super::{BTSet, FSM, State, Symbol, Transition};
-use serde_json::{from_str, Value};
+use serde_json::{json, Value};
 use std::collections::HashMap;
-use std::fs;
+
+/// Le symbole utilisé pour les epsilon transitions
+pub(crate) const EPSILON: &str = "ε";
 
 /// Automate a état fini déterministe
 #[derive(Debug, Clone)]
@@ -89,7 +94,7 @@ impl NDFAEpsilon {
         _delta: HashMap<Transition<State>, BTSet<State>>,
         _fsm: FSM,
     ) -> Self {
-        let _epsilon = Symbol::new("ε".to_string());
+        let _epsilon = Symbol::from_str(EPSILON);
         NDFAEpsilon {
             starts: _starts,
             delta: _delta,
@@ -99,12 +104,25 @@ impl NDFAEpsilon {
     }
     
     /// Applique une transition et renvoie un set d'etat (representant l'image de la transition)
+    #[allow(clippy::needless_return)]
     pub fn apply_delta(&self, transition: Transition<State>) -> Option<BTSet<State>> {
         if let Some(n) = self.get_delta().get(&transition) {
             return Some(n.clone());
         }
         return None;
     }
+
+    /// Retourne les états déclarés, les états initiaux et finaux et les extrémités des transitions, meme s'ils ne sont pas déclarés
+    pub(crate) fn used_states(&self) -> BTSet<State> {
+        let mut states: BTSet<State> = self.get_states().clone();
+        states.insert_all(self.get_starts().clone());
+        states.insert_all(self.get_ends().clone());
+        for (transition, images) in self.get_delta() {
+            states.insert(transition.get_content().clone());
+            states.insert_all(images.clone());
+        }
+        states
+    }
     // construit une transition qui est applicable sur la nfae
     fn prepare_transition(&self, states: BTSet<State>, symbol : Symbol) -> BTSet<Transition<State>> {
         let mut buffer: BTSet<Transition<State>> = BTSet::new();
@@ -116,6 +134,7 @@ impl NDFAEpsilon {
     }
     
     // execute une transition sur la nfae
+    #[allow(clippy::needless_else, clippy::partialeq_to_none)]
     fn execute_transition(&self,mut buffer: BTSet<State>, transitions: BTSet<Transition<State>>) ->  BTSet<State> {
         let mut current : Option<BTSet<State>>; 
         // execute les transitions et enregistre les images
@@ -152,6 +171,7 @@ impl NDFAEpsilon {
         states_after_delta
     }
     // retourne l'ensemble des etats accessible par ε-clôture depuis les etats de states
+    #[allow(clippy::len_zero)]
     fn next_epsilon_clause(&self, mut states: BTSet<State>) -> BTSet<State> {
         // on reccupere l'ensemble des etats accessible par ε-clôture depuis ses états
         let mut states_temp = self.apply_delta_tilde_epsilon_clausure(states.clone());
@@ -174,6 +194,7 @@ impl NDFAEpsilon {
     }
     
     /// Applique les transition et renvoie un set d'etat (representant l'image de la transition)
+    #[allow(clippy::needless_else, clippy::needless_return, clippy::partialeq_to_none)]
     pub fn apply_deltas(&self, set_transition: Transition<BTSet<State>>) -> Option<BTSet<State>> {
         let mut images: BTSet<State> = BTSet::new();
         let mut current;
@@ -192,7 +213,80 @@ impl NDFAEpsilon {
         }
         return Some(images);
     }
-    
+
+    /// Déterminise l'automate comme `to_dfa`, en retournant aussi le set d'états (clos par ε) représenté par chaque état du DFA
+    ///
+    /// ```
+    /// use automaters::*;
+    /// use std::collections::HashMap;
+    /// fn main() {
+    ///     let nfae : NDFAEpsilon = Regex::parse("a*").unwrap().to_thompson();
+    ///     let (dfa, subsets) : (DFA, HashMap<State, BTSet<State>>) = nfae.to_dfa_with_subsets();
+    ///     assert!(subsets[dfa.get_start()].len() > 1);
+    /// }
+    /// ```
+    pub fn to_dfa_with_subsets(&self) -> (DFA, HashMap<State, BTSet<State>>) {
+        // l'alphabet du DFA ne contient pas epsilon
+        let mut _alphabet : BTSet<Symbol> = self.get_alphabet().clone();
+        _alphabet = _alphabet.difference(BTSet::new().from_vect(vec![self.epsilon.clone()]));
+        // le depart du DFA est la clôture des etats de depart
+        let first_state : BTSet<State> = self.next_epsilon_clause(self.get_starts().clone());
+        subset_construction(first_state, _alphabet, self.get_ends(), |states, letter| {
+            // on applique la transition puis on ferme le resultat par ε-clôture
+            let images : BTSet<State> = self.apply_deltatilde(Transition::new(letter.clone(), states.clone()));
+            if images.is_empty() {
+                return None;
+            }
+            Some(images)
+        })
+    }
+
+    /// Supprime les epsilon transitions en conservant le non déterminisme
+    ///
+    /// Construction par clôture avant: depuis un état `q`, la lecture de `a` mene à la clôture des images de la clôture de `q`,
+    /// et `q` devient final si sa clôture contient un état final. Les états et leurs noms sont conservés.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use automaters::*;
+    /// fn main() {
+    ///     let nfae : NDFAEpsilon = NDFAEpsilon::from_json_file("src/automates/NFA1e.json");
+    ///     let nfa : NDFA = nfae.remove_epsilon();
+    ///     assert_eq!(nfa.get_states(), nfae.get_states());
+    ///     assert_eq!(nfa.accept("010"), nfae.accept("010"));
+    /// }
+    /// ```
+    ///
+    /// # Return
+    ///
+    /// * `NDFA` - Un NDFA sans epsilon transition qui accepte le même langage
+    ///
+    pub fn remove_epsilon(&self) -> NDFA {
+        // l'alphabet ne contient plus epsilon
+        let _alphabet: BTSet<Symbol> = self.get_alphabet().difference(BTSet::new().from_vect(vec![self.epsilon.clone()]));
+        let mut _delta: HashMap<Transition<State>, BTSet<State>> = HashMap::new();
+        let mut _ends: BTSet<State> = BTSet::new();
+        let mut closure: BTSet<State>;
+        let mut images: BTSet<State>;
+        for state in self.used_states().get() {
+            // la clôture de l'etat
+            closure = self.next_epsilon_clause(BTSet::new().from_vect(vec![state.clone()]));
+            // l'etat est final si sa clôture contient un etat final
+            if closure.get().iter().any(|_state| self.get_ends().contains(_state)) {
+                _ends.insert(state.clone());
+            }
+            for letter in _alphabet.get() {
+                // lecture de la lettre depuis la clôture, puis clôture du resultat
+                images = self.apply_deltatilde(Transition::new(letter.clone(), closure.clone()));
+                if !images.is_empty() {
+                    _delta.insert(Transition::new(letter.clone(), state.clone()), images);
+                }
+            }
+        }
+        let _fsm: FSM = FSM::new(self.get_states().clone(), _alphabet, _ends);
+        NDFA::new(self.get_starts().clone(), _delta, _fsm)
+    }
 }
 impl AutomateJsonIO for NDFAEpsilon{    
     /// Créer un automate à état fini non détérministe depuis un chemin du json
@@ -265,57 +359,10 @@ impl AutomateJsonIO for NDFAEpsilon{
     /// * `NDFAEpsilon` - L'automate non déterministe à état fini avec Epsilon transition correspondante
     ///
     fn from_json(content_json: &Value) -> Self {
-        //creation du nfae à l'aide du content_json
-        let mut symbol: Symbol;
-        let mut state: State;
-        let mut image: State;
-        let mut transition: Transition<State>;
-
-        let mut alphabet: BTSet<Symbol> = BTSet::new();
-        let mut states: BTSet<State> = BTSet::new();
-        // réccuperation des states de départs
-        let mut starts: BTSet<State> = BTSet::new();
-        for start in content_json["starts"].as_array().unwrap() {
-            state = State::new(start.as_str().unwrap().to_string());
-            starts.insert(state.clone());
-            states.insert(state);
-        }
-        // réccuperation du delta
-        let mut delta: HashMap<Transition<State>, BTSet<State>> = HashMap::new();
-        let mut transition_json: &Value;
-        let mut images: BTSet<State>;
-
-        for element_delta in content_json["delta"].as_array().unwrap() {
-            transition_json = element_delta;
-            symbol = Symbol::new(transition_json["symbol"].as_str().unwrap().to_string());
-            state = State::new(transition_json["state"].as_str().unwrap().to_string());
-            // generation des images du state
-            images = BTSet::new();
-            for img in transition_json["images"].as_array().unwrap() {
-                image = State::new(img.as_str().unwrap().to_string());
-                states.insert(image.clone());
-                images.insert(image);
-            }
-
-            transition = Transition::new(symbol.clone(), state.clone()); //création de la transition: sur l'etat state, la lecture de state par symbol mene à un set d'images
-            delta.insert(transition, images.clone());
-
-            states.insert(state);
-            alphabet.insert(symbol);
+        match NDFAEpsilon::try_from_json(content_json) {
+            Ok(nfae) => nfae,
+            Err(error) => panic!("{}", error),
         }
-        // reccuperation des etats finaux
-        let mut ends: BTSet<State> = BTSet::new();
-        for elem in content_json["ends"].as_array().unwrap() {
-            state = State::new(elem.as_str().unwrap().to_string());
-            ends.insert(state.clone());
-            states.insert(state);
-        }
-        // on pourrait utiliser le chargement de l'automate nfae au lieu de retaper tout le code
-
-        //on aurait pus directement utiliser l'interfasse de FSM pour enumerer les etat, l'alphabet etc. mais par precaution on le fait mannuellement par apport au contenu des transitions
-        //let fsm = FSM::from_json(content_json);
-        let fsm: FSM = FSM::new(states, alphabet, ends);
-        NDFAEpsilon::new(starts, delta, fsm)
     }
 
     /// Créer un automate à état fini détérministe depuis un chemin vers un fichier json
@@ -388,14 +435,136 @@ impl AutomateJsonIO for NDFAEpsilon{
     /// * `NDFAEpsilon` - L'automate déterministe à état fini correspondante
     ///
     fn from_json_file(path: &str) -> Self {
-        let content_json: Value = {
-            // Charge le contenu du fichier en tant que String
-            let content: String = fs::read_to_string(path).unwrap();
-            // Parse le texte en structure Json
-            from_str::<Value>(&content).unwrap()
-        };
+        match NDFAEpsilon::try_from_json_file(path) {
+            Ok(nfae) => nfae,
+            Err(error) => panic!("{}", error),
+        }
+    }
+
+    /// Créer un automate à état fini non détérministe avec epsilon transition depuis du json sans paniquer
+    ///
+    /// Le format est le même que celui d'un NDFA, les epsilon transitions utilisent le symbole `ε`.
+    ///
+    /// # Arguments
+    ///
+    /// * `content_json` - Le contenu json
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use automaters::*;
+    /// use serde_json::json;
+    /// fn main() {
+    ///     let content_json = json!({
+    ///         "ends" : ["q_1"],
+    ///         "starts" : ["q_0"],
+    ///         "delta" : [{"state" : "q_0", "symbol" : "ε", "images" : "q_1"}]
+    ///     });
+    ///     let error : AutomatonError = NDFAEpsilon::try_from_json(&content_json).unwrap_err();
+    ///     assert!(matches!(error, AutomatonError::WrongType { index: Some(0), .. }));
+    /// }
+    /// ```
+    ///
+    /// # Return
+    ///
+    /// * `Result<NDFAEpsilon, AutomatonError>` - L'automate correspondant, ou l'erreur rencontrée
+    ///
+    fn try_from_json(content_json: &Value) -> Result<Self, AutomatonError> {
+        // le format est identique à celui du NDFA, on reutilise donc son chargement
+        let nfa: NDFA = NDFA::try_from_json(content_json)?;
+        Ok(NDFAEpsilon::new(nfa.get_starts().clone(), nfa.get_delta().clone(), nfa.get_fsm().clone()))
+    }
+
+    /// Créer un automate à état fini non détérministe avec epsilon transition depuis un chemin vers un fichier json sans paniquer
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Le chemin vers le fichier json
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use automaters::*;
+    /// fn main() {
+    ///     let nfae : Result<NDFAEpsilon, AutomatonError> = NDFAEpsilon::try_from_json_file("src/automates/NFA1e.json");
+    ///     assert!(nfae.is_ok());
+    /// }
+    /// ```
+    ///
+    /// # Return
+    ///
+    /// * `Result<NDFAEpsilon, AutomatonError>` - L'automate correspondant, ou l'erreur rencontrée
+    ///
+    fn try_from_json_file(path: &str) -> Result<Self, AutomatonError> {
+        let content_json: Value = json::read_json_file(path)?;
         //creation de la machine
-        NDFAEpsilon::from_json(&content_json)
+        NDFAEpsilon::try_from_json(&content_json)
+    }
+    /// Convertit l'automate au format json accepté par `from_json`
+    /// 
+    /// Les états, l'alphabet et les transitions sont triés afin que le json produit soit stable.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use automaters::*;
+    /// use serde_json::Value;
+    /// fn main() {
+    ///     let nfae : NDFAEpsilon = NDFAEpsilon::from_json_file("src/automates/NFA1e.json");
+    ///     let content_json : Value = nfae.to_json();
+    ///     let nfae2 : NDFAEpsilon = NDFAEpsilon::from_json(&content_json);
+    ///     assert_eq!(nfae.get_delta(), nfae2.get_delta());
+    /// }
+    /// ```
+    /// 
+    /// # Return
+    ///
+    /// * `Value` - Le contenu json de l'automate
+    /// 
+    fn to_json(&self) -> Value {
+        // creation des transitions
+        let mut delta: Vec<Value> = Vec::new();
+        for (transition, images) in json::sorted_transitions(self.get_delta()) {
+            delta.push(json!({
+                "state" : transition.get_content().get_name(),
+                "symbol" : transition.get_symbol().get_value(),
+                "images" : json::states_to_json(images)
+            }));
+        }
+        json!({
+            "states" : json::states_to_json(self.get_states()),
+            "alphabet" : json::symbols_to_json(self.get_alphabet()),
+            "ends" : json::states_to_json(self.get_ends()),
+            "starts" : json::states_to_json(self.get_starts()),
+            "delta" : delta
+        })
+    }
+
+    /// Enregistre l'automate dans un fichier json
+    /// 
+    /// # Arguments
+    ///
+    /// * `path` - Le chemin du fichier à écrire
+    ///
+    /// # Examples
+    /// 
+    /// ```
+    /// use automaters::*;
+    /// fn main() {
+    ///     let path : String = std::env::temp_dir().join("nfae_doc.json").display().to_string();
+    ///     let nfae : NDFAEpsilon = NDFAEpsilon::from_json_file("src/automates/NFA1e.json");
+    ///     nfae.to_json_file(&path).unwrap();
+    ///     let nfae2 : NDFAEpsilon = NDFAEpsilon::from_json_file(&path);
+    ///     assert_eq!(nfae.get_ends(), nfae2.get_ends());
+    /// }
+    /// ```
+    /// 
+    /// # Return
+    ///
+    /// * `Result<(), AutomatonError>` - Une erreur si le fichier n'a pas pu être écrit
+    /// 
+    fn to_json_file(&self, path: &str) -> Result<(), AutomatonError> {
+        json::write_json_file(path, &self.to_json())
     }
 }
 
@@ -433,6 +602,7 @@ impl AutomateTrait<BTSet<State>> for NDFAEpsilon{
     }
 
     /// indique si un mot est accepté dans la langue de l'automate
+    #[allow(clippy::needless_borrow, clippy::needless_return)]
     fn accept(&self, _word: &str) -> bool {
         let mut symbol: Symbol;
         let mut currents: BTSet<State> = self.get_starts().clone(); //etats de depart
@@ -456,125 +626,56 @@ impl AutomateTrait<BTSet<State>> for NDFAEpsilon{
         return false;
     }
      
-    /// Convertit le NFA en DFA
+    /// Convertit le NDFAEpsilon en DFA
+    /// 
+    /// Le set d'états de départ et chaque set d'images sont fermés par ε-clôture, le symbole `ε` ne fait donc 
+    /// pas partie de l'alphabet du DFA. Un état du DFA est final lorsque sa clôture contient un état final.
     /// 
     /// ```
     /// use automaters::*;
-    /// use std::fs;
-    /// use serde_json::{Value, from_str};
     /// fn main() {
-    /// 
-    ///     let link_file: &str = "src/automates/NFA1.json";
-    ///     let content_json: Value = {
-    ///         // Charge le contenu du fichier en tant que String
-    ///         let content : String = fs::read_to_string(link_file).unwrap();
-    ///         // Parse le texte en structure Json
-    ///         from_str::<Value>(&content).unwrap()
-    ///     };
-    ///     //creation depuis un lien
-    ///     let nfa : NDFA = NDFA::from_json_file(link_file);  
-    ///     dbg!(nfa.to_dfa());
+    ///     let nfae : NDFAEpsilon = NDFAEpsilon::from_json_file("src/automates/NFA1e.json");  
+    ///     let dfa : DFA = nfae.to_dfa();
+    ///     assert!(!dfa.get_alphabet().contains(&Symbol::from_str("ε")));
+    ///     assert_eq!(dfa.accept("010"), nfae.accept("010"));
     /// }
     /// ```
     /// 
     /// # Return
     ///
-    /// * `NDFA` - L'automate déterministe à état fini qui correspondante
+    /// * `DFA` - L'automate déterministe à état fini qui correspondante
     /// 
     fn to_dfa(&self) -> DFA {
-        // Un set des images que renvoie une transition
-        let mut state_image : BTSet<State>;
-        let _alphabet :BTSet<Symbol>  = self.get_alphabet().clone();
-        let mut transition :Transition<BTSet<State>>;
-        // les nouveaux states qui seront les states du nouvel automate
-        let mut new_states : BTSet<BTSet<State>> = BTSet::new();
-        let mut temp :Option<BTSet<State>>;
-        // un set de state dont on ne connait pas les images
-        let mut set_state_search_image : BTSet<BTSet<State>> =  BTSet::new();
-        // la table de transition regroupant l'ensemble des transitions du nouvel automate, equivalent à delta
-        let mut table_de_transition : HashMap<Transition<BTSet<State>>, BTSet<State>> = HashMap::new();
-        // HashMap<Transition<State>, BTSet<State>> 
-        let mut set_state_image : BTSet<BTSet<State>> = BTSet::new();
-        // le set de states de departs de self sera le state de depart du nouvel automate
-        let first_state : BTSet<State> = self.get_starts().clone();
-        // ajoute le premier element dans les images
-        new_states.insert(first_state.clone()); 
-        set_state_search_image.insert(first_state.clone()); 
-        let mut continuer : bool = true;
-        // calculs des nouveaux etats, transitions, images
-        while continuer {
-            // parcour l'ensemble des states et des symboles pour creer des transitions et calculer les images
-            for state in set_state_search_image.get() {
-                for letter in _alphabet.get() {
-                    // creation de la transition
-                    transition = Transition::new(letter.clone(),state.clone());
-                    // reccuperation de l'image
-                    temp = self.apply_deltas(transition.clone());
-                    if temp!=None {
-                        // reccuperation du contenu
-                        state_image =temp.unwrap();
-                        // sauvegarde de la transition
-                        table_de_transition.insert(transition, state_image.clone());
-                        // on enregistre le state, plutard on pourra verifier si on le connaissais deja ou pas (si on ne le connaissais pas on l'ajoute dans set_state_search_image pour rechercher ses images au prochain tour)
-                        set_state_image.insert(state_image);
-                    }
-                    // si aucune image n'a ete trouver, on ignore et passe au tour suivant
-                }
-            }
-            // prepare la liste des states dont on ne connait pas les images
-            set_state_search_image =  BTSet::new();
-            for state in set_state_image.get(){
-                // si le state est inconnu
-                if !new_states.contains(state){
-                    // on l'enregistre et on cherche ses transitions/images aux prochains tour
-                    set_state_search_image.insert(state.clone());
-                    new_states.insert(state.clone());
-                }
-            }
-            // La liste de state dont on ne connait les images et transition est vide, donc on peut sarreter
-            if set_state_search_image.len() == 0 {
-                continuer = false;
+        self.to_dfa_with_subsets().0
+    }
+}
+
+impl ToNdfa for NDFAEpsilon {
+    /// Supprime les ε-transitions sans clore les images: δ'(q, a) = δ(clôture(q), a)
+    ///
+    /// Contrairement à `remove_epsilon`, les états atteints uniquement par ε deviennent inaccessibles, ce qui donne
+    /// un NDFA bien plus petit une fois émondé pour les algorithmes génériques (élimination d'états, énumération, ...).
+    fn to_ndfa(&self) -> NDFA {
+        // les symboles des transitions absents de l'alphabet sont gardés, seul epsilon est retiré
+        let mut _alphabet: BTSet<Symbol> = self.get_alphabet().clone();
+        _alphabet.insert_all(BTSet::new().from_vect(self.get_delta().keys().map(|transition| transition.get_symbol().clone()).collect()));
+        let _alphabet: BTSet<Symbol> = _alphabet.difference(BTSet::new().from_vect(vec![self.epsilon.clone()]));
+        let mut _delta: HashMap<Transition<State>, BTSet<State>> = HashMap::new();
+        let mut _ends: BTSet<State> = BTSet::new();
+        let mut closure: BTSet<State>;
+        for state in self.used_states().get() {
+            closure = self.next_epsilon_clause(BTSet::new().from_vect(vec![state.clone()]));
+            if closure.get().iter().any(|_state| self.get_ends().contains(_state)) {
+                _ends.insert(state.clone());
             }
-        }
-        let name : String = "q_".to_string();
-        let mut _states : BTSet<State> = BTSet::new();
-        // listes des etats finaux
-        let mut _ends : BTSet<State> = BTSet::new();
-        let mut _deltas : HashMap<Transition<State>, State> = HashMap::new();
-        // sauvegarde le nom de state de chaque BTSet<state>
-        let mut _concordances  : HashMap<BTSet<State>, State> = HashMap::new();
-        let mut i : usize = 0;
-        let mut _state : State;
-        // creations des states de l'automate
-        for state in new_states.get() {
-            // buffer = name+&i.to_string();
-            _state = State::new( name.clone()+&i.to_string());
-            _states.insert(_state.clone());
-            _concordances.insert(state.clone(), _state.clone());
-            i+=1;
-        }
-        let ends = self.get_ends().get();
-        // buffer temporaire
-        let mut symbol : Symbol ;
-        let mut content : &BTSet<State> ;
-        let mut value :  BTSet<State> ;
-        // creation des transitions de l'automate
-        for (_transition_key, _transition_val) in table_de_transition {
-            symbol = _transition_key.get_symbol().clone();
-            content = &_transition_key.get_content();
-            value = _transition_val;
-            _deltas.insert(Transition::new(symbol, _concordances.get(content).unwrap().clone()), _concordances.get(&value).unwrap().clone());
-            
-            //on parcour les etats finaux de l'automate NFA pour savoir si le state actuel est finaux ou pas
-            for _state in ends {
-                if content.contains(_state){
-                    _ends.insert(_concordances.get(content).unwrap().clone());
+            for letter in _alphabet.get() {
+                if let Some(images) = self.apply_deltas(Transition::new(letter.clone(), closure.clone())) {
+                    _delta.insert(Transition::new(letter.clone(), state.clone()), images);
                 }
             }
         }
-        let _fsm :FSM = FSM::new(_states, _alphabet, _ends);
-        // création du DFA
-        DFA::new(_concordances.get(&first_state).unwrap().clone(), _deltas, _fsm)
+        let _fsm: FSM = FSM::new(self.get_states().clone(), _alphabet, _ends);
+        NDFA::new(self.get_starts().clone(), _delta, _fsm)
     }
 }
 
@@ -582,8 +683,12 @@ impl AutomateTrait<BTSet<State>> for NDFAEpsilon{
 #[cfg(test)]
 mod test {
     use super::*;
+    use std::fs;
+    use serde_json::from_str;
+    use crate::test_utils::{undeclared_intermediate_epsilon, words};
 
     #[test]
+    #[allow(clippy::bool_assert_comparison)]
     fn creation_partial_eq_clone_accept() {
         let mut link_file: &str = "src/automates/NFA1e.json";
         let content_json: Value = {
@@ -627,4 +732,59 @@ mod test {
         assert_eq!(nfae.accept("01"), true);
         assert_eq!(nfae.accept("0"), true);
     }
+
+    #[test]
+    fn to_json_round_trip() {
+        for link_file in ["src/automates/NFA1e.json", "src/automates/NFA2e.json", "src/automates/NFA3e.json"] {
+            let nfae: NDFAEpsilon = NDFAEpsilon::from_json_file(link_file);
+            let nfae2: NDFAEpsilon = NDFAEpsilon::try_from_json(&nfae.to_json()).unwrap();
+            assert_eq!(nfae.get_starts(), nfae2.get_starts());
+            assert_eq!(nfae.get_states(), nfae2.get_states());
+            assert_eq!(nfae.get_alphabet(), nfae2.get_alphabet());
+            assert_eq!(nfae.get_ends(), nfae2.get_ends());
+            assert_eq!(nfae.get_delta(), nfae2.get_delta());
+        }
+    }
+
+    #[test]
+    fn to_dfa_same_language() {
+        for link_file in ["src/automates/NFA1e.json", "src/automates/NFA2e.json", "src/automates/NFA3e.json"] {
+            let nfae: NDFAEpsilon = NDFAEpsilon::from_json_file(link_file);
+            let dfa: DFA = nfae.to_dfa();
+            // epsilon n'est pas une lettre du DFA
+            assert!(!dfa.get_alphabet().contains(&Symbol::from_str("ε")));
+            for word in words(&["0", "1"], 8) {
+                assert_eq!(nfae.accept(&word), dfa.accept(&word), "{} sur le mot {:?}", link_file, word);
+            }
+        }
+        // le mot vide est accepté grace à la clôture du depart
+        let dfa: DFA = NDFAEpsilon::from_json_file("src/automates/NFA3e.json").to_dfa();
+        assert!(dfa.accept(""));
+    }
+
+    #[test]
+    fn remove_epsilon_same_language() {
+        for link_file in ["src/automates/NFA1e.json", "src/automates/NFA2e.json", "src/automates/NFA3e.json"] {
+            let nfae: NDFAEpsilon = NDFAEpsilon::from_json_file(link_file);
+            let nfa: NDFA = nfae.remove_epsilon();
+            // aucune transition epsilon et les memes etats
+            assert!(nfa.get_delta().keys().all(|transition| transition.get_symbol().get_value() != "ε"));
+            assert_eq!(nfa.get_states(), nfae.get_states());
+            assert_eq!(nfa.get_starts(), nfae.get_starts());
+            for word in words(&["0", "1"], 8) {
+                assert_eq!(nfae.accept(&word), nfa.accept(&word), "{} sur le mot {:?}", link_file, word);
+            }
+        }
+        // q0 atteint q3 et q4 atteint q2 par ε, ils deviennent finaux
+        let nfa: NDFA = NDFAEpsilon::from_json_file("src/automates/NFA1e.json").remove_epsilon();
+        assert!(nfa.get_ends().contains(&State::from_str("q0")));
+        assert!(nfa.get_ends().contains(&State::from_str("q4")));
+        // les etats intermediaires non declarés gardent leurs transitions
+        let nfae: NDFAEpsilon = undeclared_intermediate_epsilon();
+        let nfa: NDFA = nfae.remove_epsilon();
+        for word in words(&["a", "b"], 4) {
+            assert_eq!(nfae.accept(&word), nfa.accept(&word), "sur le mot {:?}", word);
+        }
+        assert!(nfa.accept("ab"));
+    }
 }
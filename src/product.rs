@@ -0,0 +1,295 @@
+use crate::state::fresh_state;
+use crate::{AutomateTrait, BTSet, State, Symbol, Transition, DFA, FSM};
+use std::collections::{HashMap, VecDeque};
+
+// nomme l'etat du produit correspondant à la paire (p, q), et indique si la paire n'avait pas encore de nom
+fn pair_name(names: &mut HashMap<(State, State), State>, states: &mut BTSet<State>, pair: &(State, State)) -> (State, bool) {
+    if let Some(name) = names.get(pair) {
+        return (name.clone(), false);
+    }
+    let mut name: State = State::new(format!("({},{})", pair.0.get_name(), pair.1.get_name()));
+    // deux paires distinctes peuvent s'écrire de la meme facon, par exemple ("a,b", "c") et ("a", "b,c")
+    if states.contains(&name) {
+        name = fresh_state(states, name.get_name());
+    }
+    states.insert(name.clone());
+    names.insert(pair.clone(), name.clone());
+    (name, true)
+}
+
+impl DFA {
+    // etend l'alphabet de l'automate puis le complete, les nouveaux symboles menant au puits
+    // l'etat initial et les images non declarées sont ajoutés aux etats pour etre completés eux aussi
+    fn completed_over(&self, alphabet: &BTSet<Symbol>) -> DFA {
+        let fsm: FSM = FSM::new(self.used_states(), alphabet.clone(), self.get_ends().clone());
+        DFA::new(self.get_start().clone(), self.get_delta().clone(), fsm)
+            .completed_with_fresh_sink()
+            .0
+    }
+
+    // construit l'automate produit des deux DFA, accessible depuis la paire des etats initiaux
+    fn product<F: Fn(bool, bool) -> bool>(&self, other: &DFA, accept: F) -> DFA {
+        // les deux automates sont completés sur l'union des alphabets
+        let mut alphabet: BTSet<Symbol> = self.get_alphabet().clone();
+        alphabet.insert_all(other.get_alphabet().clone());
+        let left: DFA = self.completed_over(&alphabet);
+        let right: DFA = other.completed_over(&alphabet);
+
+        let mut states: BTSet<State> = BTSet::new();
+        let mut ends: BTSet<State> = BTSet::new();
+        let mut delta: HashMap<Transition<State>, State> = HashMap::new();
+        let start: (State, State) = (left.get_start().clone(), right.get_start().clone());
+        let mut names: HashMap<(State, State), State> = HashMap::new();
+        let (start_name, _): (State, bool) = pair_name(&mut names, &mut states, &start);
+        let mut queue: VecDeque<(State, State)> = VecDeque::from([start]);
+        while let Some((p, q)) = queue.pop_front() {
+            let name: State = names[&(p.clone(), q.clone())].clone();
+            if accept(left.get_ends().contains(&p), right.get_ends().contains(&q)) {
+                ends.insert(name.clone());
+            }
+            for symbol in alphabet.get() {
+                // les deux automates sont complets, chaque paire a donc une image
+                let (Some(p_image), Some(q_image)) = (left.apply_delta(Transition::new(symbol.clone(), p.clone())), right.apply_delta(Transition::new(symbol.clone(), q.clone()))) else {
+                    continue;
+                };
+                let (image, new): (State, bool) = pair_name(&mut names, &mut states, &(p_image.clone(), q_image.clone()));
+                if new {
+                    queue.push_back((p_image.clone(), q_image.clone()));
+                }
+                delta.insert(Transition::new(symbol.clone(), name.clone()), image);
+            }
+        }
+        DFA::new(start_name, delta, FSM::new(states, alphabet, ends))
+    }
+
+    /// Construit un DFA reconnaissant l'intersection des langages des deux automates
+    ///
+    /// Les alphabets sont unifiés et chaque état du produit est nommé `(p,q)` d'après la paire d'états dont il est issu.
+    /// Si ce nom est déjà celui d'une autre paire, il reçoit un suffixe `_1`, `_2`, ...
+    /// Seules les paires accessibles sont construites, et le DFA obtenu est complet.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - Le second automate
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use automaters::*;
+    /// fn main() {
+    ///     let dfa : DFA = DFA::from_json_file("src/automates/DFA3.json");
+    ///     let intersection : DFA = dfa.intersection(&dfa.complement());
+    ///     assert_eq!(intersection.get_start(), &State::from_str("(q_0,q_0)"));
+    ///     // aucun mot n'est accepté à la fois par l'automate et son complémentaire
+    ///     assert!(intersection.get_ends().is_empty());
+    /// }
+    /// ```
+    ///
+    /// # Return
+    ///
+    /// * `DFA` - Le DFA produit
+    ///
+    pub fn intersection(&self, other: &DFA) -> DFA {
+        self.product(other, |p, q| p && q)
+    }
+
+    /// Construit un DFA reconnaissant l'union des langages des deux automates
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - Le second automate
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use automaters::*;
+    /// fn main() {
+    ///     let dfa2 : DFA = DFA::from_json_file("src/automates/DFA2.json");
+    ///     let dfa3 : DFA = DFA::from_json_file("src/automates/DFA3.json");
+    ///     let union : DFA = dfa2.union(&dfa3);
+    ///     assert!(union.accept("01"));
+    ///     assert!(union.accept("b"));
+    /// }
+    /// ```
+    ///
+    /// # Return
+    ///
+    /// * `DFA` - Le DFA produit
+    ///
+    pub fn union(&self, other: &DFA) -> DFA {
+        self.product(other, |p, q| p || q)
+    }
+
+    /// Construit un DFA reconnaissant les mots acceptés par `self` et refusés par `other`
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - Le second automate
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use automaters::*;
+    /// fn main() {
+    ///     let dfa2 : DFA = DFA::from_json_file("src/automates/DFA2.json");
+    ///     let dfa3 : DFA = DFA::from_json_file("src/automates/DFA3.json");
+    ///     let difference : DFA = dfa3.difference(&dfa2);
+    ///     assert!(difference.accept("b"));
+    ///     assert!(!difference.accept("01"));
+    /// }
+    /// ```
+    ///
+    /// # Return
+    ///
+    /// * `DFA` - Le DFA produit
+    ///
+    pub fn difference(&self, other: &DFA) -> DFA {
+        self.product(other, |p, q| p && !q)
+    }
+
+    /// Construit un DFA reconnaissant les mots acceptés par exactement un des deux automates
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - Le second automate
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use automaters::*;
+    /// fn main() {
+    ///     let dfa3 : DFA = DFA::from_json_file("src/automates/DFA3.json");
+    ///     let symmetric : DFA = dfa3.symmetric_difference(&dfa3);
+    ///     assert!(symmetric.get_ends().is_empty());
+    /// }
+    /// ```
+    ///
+    /// # Return
+    ///
+    /// * `DFA` - Le DFA produit
+    ///
+    pub fn symmetric_difference(&self, other: &DFA) -> DFA {
+        self.product(other, |p, q| p != q)
+    }
+
+    /// Construit un DFA reconnaissant le complémentaire du langage de l'automate, sur son propre alphabet
+    ///
+    /// L'automate est d'abord completé avec un état puits, puis les états finaux et non finaux sont échangés.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use automaters::*;
+    /// fn main() {
+    ///     let dfa : DFA = DFA::from_json_file("src/automates/DFA3.json");
+    ///     let complement : DFA = dfa.complement();
+    ///     assert!(complement.accept(""));
+    ///     assert!(!complement.accept("b"));
+    ///     assert!(complement.accept("ba"));
+    /// }
+    /// ```
+    ///
+    /// # Return
+    ///
+    /// * `DFA` - Le DFA complémentaire
+    ///
+    pub fn complement(&self) -> DFA {
+        let complete: DFA = self.completed_with_fresh_sink().0;
+        let states: BTSet<State> = complete.used_states();
+        let ends: BTSet<State> = states.difference(complete.get_ends().clone());
+        let fsm: FSM = FSM::new(states, complete.get_alphabet().clone(), ends);
+        DFA::new(complete.get_start().clone(), complete.get_delta().clone(), fsm)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::AutomateJsonIO;
+    use crate::test_utils::words;
+    use serde_json::json;
+
+    #[test]
+    fn boolean_operations() {
+        // les mots contenant un nombre pair de a, sur {a, b}
+        let even: DFA = DFA::from_json(&json!({
+            "states" : ["p", "i"],
+            "alphabet" : ["a", "b"],
+            "ends" : ["p"],
+            "start" : "p",
+            "delta" : [
+                {"state" : "p", "symbol" : "a", "image" : "i"},
+                {"state" : "p", "symbol" : "b", "image" : "p"},
+                {"state" : "i", "symbol" : "a", "image" : "p"},
+                {"state" : "i", "symbol" : "b", "image" : "i"}
+            ]
+        }));
+        // les mots se terminant par c, sur {a, c}, automate partiel
+        let ends_with_c: DFA = DFA::from_json(&json!({
+            "states" : ["0", "1"],
+            "alphabet" : ["a", "c"],
+            "ends" : ["1"],
+            "start" : "0",
+            "delta" : [
+                {"state" : "0", "symbol" : "a", "image" : "0"},
+                {"state" : "0", "symbol" : "c", "image" : "1"},
+                {"state" : "1", "symbol" : "c", "image" : "1"}
+            ]
+        }));
+        let intersection: DFA = even.intersection(&ends_with_c);
+        let union: DFA = even.union(&ends_with_c);
+        let difference: DFA = even.difference(&ends_with_c);
+        let symmetric: DFA = even.symmetric_difference(&ends_with_c);
+        let complement: DFA = ends_with_c.complement();
+        // l'alphabet est unifié et les produits sont complets
+        for product in [&intersection, &union, &difference, &symmetric] {
+            assert_eq!(product.get_alphabet().len(), 3);
+            assert!(product.is_complete());
+        }
+        assert_eq!(intersection.get_start(), &State::from_str("(p,0)"));
+        assert!(intersection.get_states().contains(&State::from_str("(i,0)")));
+        // chaque operation correspond à l'operation booleenne sur les langages
+        for word in words(&["a", "b", "c"], 5) {
+            let (x, y): (bool, bool) = (even.accept(&word), ends_with_c.accept(&word));
+            assert_eq!(intersection.accept(&word), x && y, "{}", word);
+            assert_eq!(union.accept(&word), x || y, "{}", word);
+            assert_eq!(difference.accept(&word), x && !y, "{}", word);
+            assert_eq!(symmetric.accept(&word), x != y, "{}", word);
+        }
+        // le complementaire est pris sur l'alphabet de l'automate
+        for word in words(&["a", "c"], 5) {
+            assert_eq!(complement.accept(&word), !ends_with_c.accept(&word), "{}", word);
+        }
+        // les paires ("a,b", "c") et ("a", "b,c") s'écrivent toutes deux "(a,b,c)"
+        let left: DFA = DFA::from_json(&json!({
+            "states" : ["a,b", "a"],
+            "alphabet" : ["x"],
+            "ends" : ["a"],
+            "start" : "a,b",
+            "delta" : [{"state" : "a,b", "symbol" : "x", "image" : "a"}]
+        }));
+        let right: DFA = DFA::from_json(&json!({
+            "states" : ["c", "b,c"],
+            "alphabet" : ["x"],
+            "ends" : ["b,c"],
+            "start" : "c",
+            "delta" : [{"state" : "c", "symbol" : "x", "image" : "b,c"}]
+        }));
+        let intersection: DFA = left.intersection(&right);
+        assert_eq!(intersection.get_start(), &State::from_str("(a,b,c)"));
+        assert!(intersection.get_states().contains(&State::from_str("(a,b,c)_1")));
+        for word in words(&["x"], 3) {
+            assert_eq!(intersection.accept(&word), word == "x", "{}", word);
+        }
+        // une image non declarée est un etat comme les autres
+        let (s, t): (State, State) = (State::from_str("s"), State::from_str("t"));
+        let undeclared: DFA = DFA::new(s.clone(), HashMap::from([(Transition::new(Symbol::from_str("a"), s.clone()), t.clone())]), FSM::new(BTSet::new().from_vect(vec![s]), BTSet::new().from_vect(vec![Symbol::from_str("a")]), BTSet::new().from_vect(vec![t])));
+        let intersection: DFA = undeclared.intersection(&undeclared);
+        let complement: DFA = undeclared.complement();
+        assert!(intersection.is_complete());
+        for word in words(&["a"], 3) {
+            assert_eq!(intersection.accept(&word), word == "a", "{}", word);
+            assert_eq!(complement.accept(&word), word != "a", "{}", word);
+        }
+    }
+}
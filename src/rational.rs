@@ -0,0 +1,268 @@
+use crate::nfae::EPSILON;
+use crate::state::fresh_state;
+use crate::{AutomateNdfa, AutomateTrait, BTSet, NDFAEpsilon, State, Symbol, Transition, FSM, NDFA};
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::hash::Hash;
+
+// les composantes d'un NDFA en cours de construction
+struct Parts {
+    starts: BTSet<State>,
+    delta: HashMap<Transition<State>, BTSet<State>>,
+    states: BTSet<State>,
+    alphabet: BTSet<Symbol>,
+    ends: BTSet<State>,
+}
+
+impl Parts {
+    // reprend un automate en prefixant le nom de ses etats, par exemple "1." pour le premier operande
+    fn from_automate<T, A>(automate: &A, prefix: &str) -> Self
+    where
+        T: Clone + Hash + Debug,
+        A: AutomateTrait<T> + AutomateNdfa,
+    {
+        let nfa: NDFA = automate.to_ndfa();
+        let rename = |state: &State| State::new(format!("{}{}", prefix, state.get_name()));
+        let rename_all = |states: &BTSet<State>| {
+            let mut renamed: BTSet<State> = BTSet::new();
+            for state in states.get() {
+                renamed.insert(rename(state));
+            }
+            renamed
+        };
+        // les extrémités des transitions et les etats finaux non declarés sont gardés, pour qu'un etat ajouté ne puisse
+        // pas prendre leur nom
+        let mut states: BTSet<State> = rename_all(&nfa.used_states());
+        states.insert_all(rename_all(nfa.get_ends()));
+        let mut alphabet: BTSet<Symbol> = nfa.get_alphabet().clone();
+        alphabet.insert(Symbol::from_str(EPSILON));
+        Parts {
+            starts: rename_all(nfa.get_starts()),
+            delta: nfa
+                .get_delta()
+                .iter()
+                .map(|(transition, images)| (Transition::new(transition.get_symbol().clone(), rename(transition.get_content())), rename_all(images)))
+                .collect(),
+            states,
+            alphabet,
+            ends: rename_all(nfa.get_ends()),
+        }
+    }
+
+    fn add_epsilon(&mut self, from: &State, to: &State) {
+        self.delta
+            .entry(Transition::new(Symbol::from_str(EPSILON), from.clone()))
+            .or_default()
+            .insert(to.clone());
+    }
+
+    // relie chaque etat final à chaque etat initial, pour pouvoir recommencer
+    fn loop_back(&mut self) {
+        for end in self.ends.clone().get() {
+            for start in self.starts.clone().get() {
+                self.add_epsilon(end, start);
+            }
+        }
+    }
+
+    // ajoute un nouvel etat initial et final, qui accepte le mot vide
+    fn add_empty_word(&mut self) {
+        let state: State = fresh_state(&self.states, "init");
+        self.states.insert(state.clone());
+        self.starts.insert(state.clone());
+        self.ends.insert(state);
+    }
+
+    fn build(self) -> NDFAEpsilon {
+        NDFAEpsilon::new(self.starts, self.delta, FSM::new(self.states, self.alphabet, self.ends))
+    }
+}
+
+/// Construit un automate reconnaissant la concaténation des langages de `a` et `b`
+///
+/// Les états de `a` sont renommés `1.q` et ceux de `b` sont renommés `2.q`, puis chaque état final de `a` est relié
+/// aux états initiaux de `b` par une transition `ε`.
+///
+/// # Arguments
+///
+/// * `a` - L'automate reconnaissant le début des mots
+/// * `b` - L'automate reconnaissant la fin des mots
+///
+/// # Examples
+///
+/// ```
+/// use automaters::*;
+/// fn main() {
+///     let a : NDFAEpsilon = Regex::parse("ab").unwrap().to_thompson();
+///     let b : DFA = Regex::parse("c*").unwrap().to_brzozowski();
+///     let ab : NDFAEpsilon = concat(&a, &b);
+///     assert!(ab.get_starts().contains(&State::from_str("1.q_0")));
+///     assert!(ab.accept("abcc"));
+///     assert!(!ab.accept("cc"));
+/// }
+/// ```
+///
+/// # Return
+///
+/// * `NDFAEpsilon` - L'automate de la concaténation
+///
+pub fn concat<T, U, A, B>(a: &A, b: &B) -> NDFAEpsilon
+where
+    T: Clone + Hash + Debug,
+    U: Clone + Hash + Debug,
+    A: AutomateTrait<T> + AutomateNdfa,
+    B: AutomateTrait<U> + AutomateNdfa,
+{
+    let mut first: Parts = Parts::from_automate(a, "1.");
+    let second: Parts = Parts::from_automate(b, "2.");
+    for end in first.ends.clone().get() {
+        for start in second.starts.get() {
+            first.add_epsilon(end, start);
+        }
+    }
+    first.states.insert_all(second.states);
+    first.alphabet.insert_all(second.alphabet);
+    first.delta.extend(second.delta);
+    first.ends = second.ends;
+    first.build()
+}
+
+/// Construit un automate reconnaissant l'étoile de Kleene du langage de `a`
+///
+/// Chaque état final est relié aux états initiaux par une transition `ε`, et un nouvel état initial et final
+/// (nommé `init`, ou `init_1`, ... en cas de collision) accepte le mot vide.
+///
+/// # Examples
+///
+/// ```
+/// use automaters::*;
+/// fn main() {
+///     let ab : DFA = Regex::parse("ab").unwrap().to_brzozowski();
+///     let nfae : NDFAEpsilon = star(&ab);
+///     assert!(nfae.accept(""));
+///     assert!(nfae.accept("abab"));
+///     assert!(!nfae.accept("aba"));
+/// }
+/// ```
+///
+/// # Return
+///
+/// * `NDFAEpsilon` - L'automate de l'étoile
+///
+pub fn star<T, A>(a: &A) -> NDFAEpsilon
+where
+    T: Clone + Hash + Debug,
+    A: AutomateTrait<T> + AutomateNdfa,
+{
+    let mut parts: Parts = Parts::from_automate(a, "");
+    parts.loop_back();
+    parts.add_empty_word();
+    parts.build()
+}
+
+/// Construit un automate reconnaissant une ou plusieurs répétitions des mots de `a`
+///
+/// # Examples
+///
+/// ```
+/// use automaters::*;
+/// fn main() {
+///     let ab : DFA = Regex::parse("ab").unwrap().to_brzozowski();
+///     let nfae : NDFAEpsilon = plus(&ab);
+///     assert!(!nfae.accept(""));
+///     assert!(nfae.accept("abab"));
+/// }
+/// ```
+///
+/// # Return
+///
+/// * `NDFAEpsilon` - L'automate de la répétition
+///
+pub fn plus<T, A>(a: &A) -> NDFAEpsilon
+where
+    T: Clone + Hash + Debug,
+    A: AutomateTrait<T> + AutomateNdfa,
+{
+    let mut parts: Parts = Parts::from_automate(a, "");
+    parts.loop_back();
+    parts.build()
+}
+
+/// Construit un automate reconnaissant le langage de `a` et le mot vide
+///
+/// # Examples
+///
+/// ```
+/// use automaters::*;
+/// fn main() {
+///     let ab : DFA = Regex::parse("ab").unwrap().to_brzozowski();
+///     let nfae : NDFAEpsilon = optional(&ab);
+///     assert!(nfae.accept(""));
+///     assert!(nfae.accept("ab"));
+///     assert!(!nfae.accept("abab"));
+/// }
+/// ```
+///
+/// # Return
+///
+/// * `NDFAEpsilon` - L'automate de l'option
+///
+pub fn optional<T, A>(a: &A) -> NDFAEpsilon
+where
+    T: Clone + Hash + Debug,
+    A: AutomateTrait<T> + AutomateNdfa,
+{
+    let mut parts: Parts = Parts::from_automate(a, "");
+    parts.add_empty_word();
+    parts.build()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{equivalent, AutomateJsonIO, Regex, DFA};
+
+    #[test]
+    fn rational_operations() {
+        // les memes noms d'etats dans les deux operandes
+        let a: DFA = DFA::from_json_file("src/automates/DFA1.json");
+        let b: DFA = DFA::from_json_file("src/automates/DFA3.json");
+        let ab: NDFAEpsilon = concat(&a, &b);
+        assert_eq!(ab.get_states().len(), a.get_states().len() + b.get_states().len());
+        assert!(ab.get_states().contains(&State::from_str("1.q_0")));
+        assert!(ab.get_states().contains(&State::from_str("2.q_0")));
+        // chaque operation correspond à l'operation sur les expressions
+        let r: Regex = a.to_regex();
+        let s: Regex = b.to_regex();
+        assert!(equivalent(&ab, &Regex::concat(vec![r.clone(), s.clone()]).to_thompson()).is_ok());
+        assert!(equivalent(&star(&b), &Regex::Star(Box::new(s.clone())).to_thompson()).is_ok());
+        assert!(equivalent(&plus(&b), &Regex::Plus(Box::new(s.clone())).to_thompson()).is_ok());
+        assert!(equivalent(&optional(&b), &Regex::Optional(Box::new(s.clone())).to_thompson()).is_ok());
+        // avec des automates non deterministes et des epsilon transitions
+        let nfa: NDFA = NDFA::from_json_file("src/automates/NFA3.json");
+        let nfae: NDFAEpsilon = NDFAEpsilon::from_json_file("src/automates/NFA1e.json");
+        let expected: Regex = Regex::concat(vec![Regex::star(nfa.to_regex()), nfae.to_regex()]);
+        assert!(equivalent(&concat(&star(&nfa), &nfae), &expected.to_thompson()).is_ok());
+        // le nouvel etat initial ne doit pas entrer en collision avec un etat existant
+        let init: DFA = DFA::from_json(&serde_json::json!({
+            "states" : ["init"],
+            "alphabet" : ["a"],
+            "ends" : ["init"],
+            "start" : "init",
+            "delta" : [{"state" : "init", "symbol" : "a", "image" : "init"}]
+        }));
+        assert!(star(&init).get_states().contains(&State::from_str("init_1")));
+        // ni avec une image non declarée nommée init
+        let (s, undeclared): (State, State) = (State::from_str("s"), State::from_str("init"));
+        let delta: HashMap<Transition<State>, State> = HashMap::from([
+            (Transition::new(Symbol::from_str("a"), s.clone()), undeclared.clone()),
+            (Transition::new(Symbol::from_str("b"), undeclared), s.clone()),
+        ]);
+        let alphabet: BTSet<Symbol> = BTSet::new().from_vect(vec![Symbol::from_str("a"), Symbol::from_str("b")]);
+        let abab: DFA = DFA::new(s.clone(), delta, FSM::new(BTSet::new().from_vect(vec![s.clone()]), alphabet, BTSet::new().from_vect(vec![s])));
+        for automate in [star(&abab), optional(&abab)] {
+            assert!(automate.accept("abab") && !automate.accept("b"));
+            assert!(automate.get_states().contains(&State::from_str("init_1")));
+        }
+    }
+}
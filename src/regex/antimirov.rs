@@ -0,0 +1,200 @@
+use super::Regex;
+use crate::{BTSet, State, Symbol, Transition, FSM, NDFA};
+use std::collections::{HashMap, VecDeque};
+
+impl Regex {
+    /// Calcule les dérivées partielles d'Antimirov de l'expression par rapport à un symbole
+    ///
+    /// L'union des dérivées partielles reconnait le même langage que la dérivée de Brzozowski, mais chaque terme
+    /// reste une sous expression de l'expression d'origine (suivie d'un suffixe), ce qui borne leur nombre.
+    ///
+    /// # Arguments
+    ///
+    /// * `symbol` - Le symbole par rapport auquel dériver
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use automaters::*;
+    /// fn main() {
+    ///     let regex : Regex = Regex::parse("a(b|c)|ab*").unwrap();
+    ///     let terms : BTSet<Regex> = regex.partial_derivatives(&Symbol::from_str("a"));
+    ///     assert_eq!(terms.len(), 2);
+    ///     assert!(terms.contains(&Regex::parse("b|c").unwrap()));
+    ///     assert!(terms.contains(&Regex::parse("b*").unwrap()));
+    /// }
+    /// ```
+    ///
+    /// # Return
+    ///
+    /// * `BTSet<Regex>` - Les termes dérivés, vide si aucun mot ne commence par `symbol`
+    ///
+    pub fn partial_derivatives(&self, symbol: &Symbol) -> BTSet<Regex> {
+        let mut terms: BTSet<Regex> = BTSet::new();
+        match self {
+            Regex::Empty | Regex::Epsilon => {}
+            Regex::Symbol(value) => {
+                if value == symbol {
+                    terms.insert(Regex::Epsilon);
+                }
+            }
+            Regex::Concat(items) => {
+                // une concatenation vide est ε, elle n'a aucun terme dérivé
+                let Some((first, rest)) = items.split_first() else {
+                    return terms;
+                };
+                // ∂(r·s) = ∂(r)·s ∪ ∂(s) si r accepte le mot vide
+                let rest: Regex = Regex::concat(rest.to_vec());
+                for term in first.partial_derivatives(symbol).get() {
+                    terms.insert(Regex::concat(vec![term.clone(), rest.clone()]));
+                }
+                if first.is_nullable() {
+                    terms.insert_all(rest.partial_derivatives(symbol));
+                }
+            }
+            Regex::Union(items) => {
+                for item in items {
+                    terms.insert_all(item.partial_derivatives(symbol));
+                }
+            }
+            Regex::Star(inner) | Regex::Plus(inner) => {
+                let star: Regex = Regex::star(inner.as_ref().clone());
+                for term in inner.partial_derivatives(symbol).get() {
+                    terms.insert(Regex::concat(vec![term.clone(), star.clone()]));
+                }
+            }
+            Regex::Optional(inner) => {
+                terms.insert_all(inner.partial_derivatives(symbol));
+            }
+        }
+        // un terme ∅ ne reconnait aucun mot, il deviendrait un état mort
+        terms.difference(BTSet::new().from_vect(vec![Regex::Empty]))
+    }
+
+    /// Construit un NDFA par les dérivées partielles d'Antimirov
+    ///
+    /// Chaque terme dérivé devient un état `q_i`, numéroté dans l'ordre de découverte, l'état initial `q_0` étant
+    /// l'expression elle meme. L'automate a au plus n + 1 états pour n occurrences de symboles, et il est souvent plus
+    /// petit que ceux de Thompson et de Glushkov.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use automaters::*;
+    /// fn main() {
+    ///     let nfa : NDFA = Regex::parse("(a|b)*abb").unwrap().to_antimirov();
+    ///     assert_eq!(nfa.get_states().len(), 4);
+    ///     assert!(nfa.accept("babb"));
+    ///     assert!(!nfa.accept("abba"));
+    /// }
+    /// ```
+    ///
+    /// # Return
+    ///
+    /// * `NDFA` - L'automate reconnaissant le langage de l'expression
+    ///
+    pub fn to_antimirov(&self) -> NDFA {
+        self.to_antimirov_with_labels().0
+    }
+
+    /// Construit le NDFA comme `to_antimirov`, en retournant aussi le terme dérivé reconnu par chaque état
+    ///
+    /// ```
+    /// use automaters::*;
+    /// use std::collections::HashMap;
+    /// fn main() {
+    ///     let regex : Regex = Regex::parse("a(b|c)").unwrap();
+    ///     let (nfa, labels) : (NDFA, HashMap<State, Regex>) = regex.to_antimirov_with_labels();
+    ///     assert_eq!(labels[&State::from_str("q_1")], Regex::parse("b|c").unwrap());
+    ///     assert_eq!(nfa.get_states().len(), 3);
+    /// }
+    /// ```
+    ///
+    /// # Return
+    ///
+    /// * `(NDFA, HashMap<State, Regex>)` - Le NDFA et l'expression reconnue depuis chacun de ses états
+    ///
+    pub fn to_antimirov_with_labels(&self) -> (NDFA, HashMap<State, Regex>) {
+        let alphabet: BTSet<Symbol> = self.symbols();
+        let start: Regex = self.normalize();
+        let mut states: BTSet<State> = BTSet::new();
+        let mut ends: BTSet<State> = BTSet::new();
+        let mut delta: HashMap<Transition<State>, BTSet<State>> = HashMap::new();
+        // deux termes distincts peuvent s'écrire de la meme facon avec des symboles de plusieurs caracteres,
+        // les états sont donc numérotés plutot que nommés par leur expression
+        let mut ids: HashMap<Regex, State> = HashMap::new();
+        let mut labels: HashMap<State, Regex> = HashMap::new();
+        let mut queue: VecDeque<Regex> = VecDeque::new();
+        let initial: State = State::from_str("q_0");
+        ids.insert(start.clone(), initial.clone());
+        labels.insert(initial.clone(), start.clone());
+        queue.push_back(start);
+        while let Some(regex) = queue.pop_front() {
+            let state: State = ids[&regex].clone();
+            states.insert(state.clone());
+            if regex.is_nullable() {
+                ends.insert(state.clone());
+            }
+            for symbol in alphabet.get() {
+                let terms: BTSet<Regex> = regex.partial_derivatives(symbol);
+                if terms.is_empty() {
+                    continue;
+                }
+                let mut images: BTSet<State> = BTSet::new();
+                for term in terms.get() {
+                    if !ids.contains_key(term) {
+                        let next: State = State::new(format!("q_{}", ids.len()));
+                        ids.insert(term.clone(), next.clone());
+                        labels.insert(next, term.clone());
+                        queue.push_back(term.clone());
+                    }
+                    images.insert(ids[term].clone());
+                }
+                delta.insert(Transition::new(symbol.clone(), state.clone()), images);
+            }
+        }
+        let fsm: FSM = FSM::new(states, alphabet, ends);
+        (NDFA::new(BTSet::new().from_vect(vec![initial]), delta, fsm), labels)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{equivalent, AutomateTrait};
+
+    #[test]
+    fn antimirov() {
+        for pattern in ["(a|b)*abb", "a+b?", "ε", "∅", "a∅|b", "(a|ε)(b|ε)", "(ab)*a?", "((a|b*)c)+", "(a*b*)*", "(a|b)*a(a|b)(a|b)"] {
+            let regex: Regex = Regex::parse(pattern).unwrap();
+            let nfa: NDFA = regex.to_antimirov();
+            assert!(equivalent(&nfa, &regex.to_thompson()).is_ok(), "{}", pattern);
+            // au plus n + 1 etats, et jamais plus que l'automate de Glushkov
+            assert!(nfa.get_states().len() <= regex.position_sets().len() + 1, "{}", pattern);
+            assert!(nfa.get_states().len() <= regex.to_glushkov().0.get_states().len(), "{}", pattern);
+        }
+        // le NFA est plus petit que le DFA des dérivées de Brzozowski
+        let regex: Regex = Regex::parse("(a|b)*a(a|b)(a|b)").unwrap();
+        assert_eq!(regex.to_antimirov().get_states().len(), 4);
+        assert_eq!(regex.to_brzozowski().get_states().len(), 8);
+        // une concatenation vide est ε, une union vide est ∅
+        let a: Symbol = Symbol::from_str("a");
+        assert!(Regex::Concat(vec![]).partial_derivatives(&a).is_empty());
+        assert!(Regex::Union(vec![]).partial_derivatives(&a).is_empty());
+        assert!(Regex::Concat(vec![Regex::Concat(vec![]), Regex::Symbol(a.clone())]).partial_derivatives(&a).contains(&Regex::Epsilon));
+        assert!(Regex::Concat(vec![]).to_antimirov().accept(""));
+        assert!(!Regex::Union(vec![]).to_antimirov().accept(""));
+        // les termes ∅ sont retirés
+        let dead: Regex = Regex::Concat(vec![Regex::Symbol(a.clone()), Regex::Empty]);
+        assert!(dead.partial_derivatives(&a).is_empty());
+        let regex: Regex = Regex::Union(vec![dead, Regex::Symbol(a.clone())]);
+        assert_eq!(regex.partial_derivatives(&a), BTSet::new().from_vect(vec![Regex::Epsilon]));
+        // des termes qui s'écrivent de la meme facon restent des états distincts
+        let symbol = |value: &str| Regex::Symbol(Symbol::from_str(value));
+        let regex: Regex = Regex::union(vec![Regex::concat(vec![symbol("a"), symbol("bc")]), Regex::concat(vec![symbol("x"), symbol("b"), symbol("c")])]);
+        let (nfa, labels): (NDFA, HashMap<State, Regex>) = regex.to_antimirov_with_labels();
+        assert_eq!(nfa.get_states().len(), 5);
+        assert_eq!(labels.len(), 5);
+        assert!(equivalent(&nfa, &regex.to_thompson()).is_ok());
+    }
+}
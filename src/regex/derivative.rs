@@ -0,0 +1,311 @@
+use super::Regex;
+use crate::state::fresh_state;
+use crate::{BTSet, State, Symbol, Transition, DFA, FSM};
+use std::collections::{HashMap, VecDeque};
+
+impl Regex {
+    /// Construit l'union de plusieurs expressions, à associativité, commutativité et idempotence près
+    ///
+    /// Les unions imbriquées sont aplaties, les termes sont triés et dédoublonnés et `∅` est retiré.
+    ///
+    /// # Arguments
+    ///
+    /// * `items` - Les expressions à unir
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use automaters::*;
+    /// fn main() {
+    ///     let a : Regex = Regex::Symbol(Symbol::from_str("a"));
+    ///     let b : Regex = Regex::Symbol(Symbol::from_str("b"));
+    ///     assert_eq!(Regex::union(vec![b.clone(), a.clone(), Regex::Empty, b.clone()]), Regex::union(vec![a, b]));
+    ///     assert_eq!(Regex::union(vec![]), Regex::Empty);
+    /// }
+    /// ```
+    ///
+    /// # Return
+    ///
+    /// * `Regex` - L'union normalisée
+    ///
+    pub fn union(items: Vec<Regex>) -> Regex {
+        let mut terms: Vec<Regex> = Vec::new();
+        for item in items {
+            match item {
+                Regex::Union(inner) => terms.extend(inner),
+                Regex::Empty => {}
+                _ => terms.push(item),
+            }
+        }
+        terms.sort();
+        terms.dedup();
+        match terms.len() {
+            0 => Regex::Empty,
+            1 => terms.pop().unwrap(),
+            _ => Regex::Union(terms),
+        }
+    }
+
+    /// Construit la concaténation de plusieurs expressions
+    ///
+    /// Les concaténations imbriquées sont aplaties, `ε` est retiré et la présence de `∅` donne `∅`.
+    ///
+    /// # Arguments
+    ///
+    /// * `items` - Les expressions à concaténer, dans l'ordre
+    ///
+    /// # Return
+    ///
+    /// * `Regex` - La concaténation normalisée
+    ///
+    pub fn concat(items: Vec<Regex>) -> Regex {
+        let mut factors: Vec<Regex> = Vec::new();
+        for item in items {
+            match item {
+                Regex::Concat(inner) => factors.extend(inner),
+                Regex::Epsilon => {}
+                Regex::Empty => return Regex::Empty,
+                _ => factors.push(item),
+            }
+        }
+        match factors.len() {
+            0 => Regex::Epsilon,
+            1 => factors.pop().unwrap(),
+            _ => Regex::Concat(factors),
+        }
+    }
+
+    /// Construit l'étoile d'une expression, avec `∅* = ε* = ε` et `(r*)* = r*`
+    pub fn star(inner: Regex) -> Regex {
+        match inner {
+            Regex::Empty | Regex::Epsilon => Regex::Epsilon,
+            Regex::Star(_) => inner,
+            Regex::Plus(inner) | Regex::Optional(inner) => Regex::Star(inner),
+            _ => Regex::Star(Box::new(inner)),
+        }
+    }
+
+    /// Construit `r+`, avec `∅+ = ∅`, `ε+ = ε` et `(r*)+ = r*`
+    pub fn plus(inner: Regex) -> Regex {
+        match inner {
+            Regex::Empty | Regex::Epsilon | Regex::Star(_) | Regex::Plus(_) => inner,
+            Regex::Optional(inner) => Regex::Star(inner),
+            _ => Regex::Plus(Box::new(inner)),
+        }
+    }
+
+    /// Construit `r?`, qui vaut `r` lorsque `r` accepte déjà le mot vide
+    pub fn optional(inner: Regex) -> Regex {
+        match inner {
+            Regex::Empty => Regex::Epsilon,
+            Regex::Plus(inner) => Regex::Star(inner),
+            _ if inner.is_nullable() => inner,
+            _ => Regex::Optional(Box::new(inner)),
+        }
+    }
+
+    /// Reconstruit l'expression avec les constructeurs normalisants `union`, `concat`, `star`, `plus` et `optional`
+    pub fn normalize(&self) -> Regex {
+        match self {
+            Regex::Empty | Regex::Epsilon | Regex::Symbol(_) => self.clone(),
+            Regex::Concat(items) => Regex::concat(items.iter().map(Regex::normalize).collect()),
+            Regex::Union(items) => Regex::union(items.iter().map(Regex::normalize).collect()),
+            Regex::Star(inner) => Regex::star(inner.normalize()),
+            Regex::Plus(inner) => Regex::plus(inner.normalize()),
+            Regex::Optional(inner) => Regex::optional(inner.normalize()),
+        }
+    }
+
+    /// Indique si l'expression accepte le mot vide
+    pub fn is_nullable(&self) -> bool {
+        match self {
+            Regex::Empty | Regex::Symbol(_) => false,
+            Regex::Epsilon | Regex::Star(_) | Regex::Optional(_) => true,
+            Regex::Concat(items) => items.iter().all(Regex::is_nullable),
+            Regex::Union(items) => items.iter().any(Regex::is_nullable),
+            Regex::Plus(inner) => inner.is_nullable(),
+        }
+    }
+
+    /// Calcule la dérivée de Brzozowski de l'expression par rapport à un symbole
+    ///
+    /// La dérivée reconnait les mots `w` tels que `symbol·w` est reconnu par l'expression.
+    ///
+    /// # Arguments
+    ///
+    /// * `symbol` - Le symbole par rapport auquel dériver
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use automaters::*;
+    /// fn main() {
+    ///     let regex : Regex = Regex::parse("ab*|b").unwrap();
+    ///     assert_eq!(regex.derivative(&Symbol::from_str("a")), Regex::parse("b*").unwrap());
+    ///     assert_eq!(regex.derivative(&Symbol::from_str("c")), Regex::Empty);
+    /// }
+    /// ```
+    ///
+    /// # Return
+    ///
+    /// * `Regex` - La dérivée, construite avec les constructeurs normalisants
+    ///
+    pub fn derivative(&self, symbol: &Symbol) -> Regex {
+        match self {
+            Regex::Empty | Regex::Epsilon => Regex::Empty,
+            Regex::Symbol(value) => {
+                if value == symbol {
+                    Regex::Epsilon
+                } else {
+                    Regex::Empty
+                }
+            }
+            Regex::Concat(items) => {
+                // une concatenation vide est ε, sa dérivée est ∅
+                let Some((first, rest)) = items.split_first() else {
+                    return Regex::Empty;
+                };
+                // d(r·s) = d(r)·s | d(s) si r accepte le mot vide
+                let rest: Regex = Regex::concat(rest.to_vec());
+                let mut terms: Vec<Regex> = vec![Regex::concat(vec![first.derivative(symbol), rest.clone()])];
+                if first.is_nullable() {
+                    terms.push(rest.derivative(symbol));
+                }
+                Regex::union(terms)
+            }
+            Regex::Union(items) => Regex::union(items.iter().map(|item| item.derivative(symbol)).collect()),
+            Regex::Star(inner) | Regex::Plus(inner) => {
+                Regex::concat(vec![inner.derivative(symbol), Regex::star(inner.as_ref().clone())])
+            }
+            Regex::Optional(inner) => inner.derivative(symbol),
+        }
+    }
+
+    /// Construit un DFA par les dérivées de Brzozowski, sans passer par un automate non déterministe
+    ///
+    /// Chaque dérivée distincte (à associativité, commutativité et idempotence de l'union près) devient un état,
+    /// nommé par l'expression qu'il reconnait. Deux dérivées distinctes qui s'écrivent de la meme facon (avec des
+    /// symboles de plusieurs caractères) sont distinguées par un suffixe `_1`, `_2`, ... Un état est final si son
+    /// expression accepte le mot vide.
+    /// La dérivée `∅` n'est pas représentée, le DFA obtenu est donc partiel.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use automaters::*;
+    /// fn main() {
+    ///     let regex : Regex = Regex::parse("(a|b)*abb").unwrap();
+    ///     let dfa : DFA = regex.to_brzozowski();
+    ///     // l'état initial est nommé par l'expression
+    ///     assert_eq!(dfa.get_start(), &State::new(regex.normalize().to_string()));
+    ///     assert!(dfa.accept("babb"));
+    ///     assert!(!dfa.accept("abba"));
+    /// }
+    /// ```
+    ///
+    /// # Return
+    ///
+    /// * `DFA` - Le DFA reconnaissant le langage de l'expression
+    ///
+    pub fn to_brzozowski(&self) -> DFA {
+        self.to_brzozowski_with_labels().0
+    }
+
+    /// Construit le DFA comme `to_brzozowski`, en retournant aussi la dérivée reconnue par chaque état
+    ///
+    /// ```
+    /// use automaters::*;
+    /// use std::collections::HashMap;
+    /// fn main() {
+    ///     let regex : Regex = Regex::parse("(a|b)*abb").unwrap();
+    ///     let (dfa, labels) : (DFA, HashMap<State, Regex>) = regex.to_brzozowski_with_labels();
+    ///     // l'état initial reconnait l'expression elle meme
+    ///     assert_eq!(labels[dfa.get_start()], regex.normalize());
+    /// }
+    /// ```
+    ///
+    /// # Return
+    ///
+    /// * `(DFA, HashMap<State, Regex>)` - Le DFA et l'expression reconnue depuis chacun de ses états
+    ///
+    pub fn to_brzozowski_with_labels(&self) -> (DFA, HashMap<State, Regex>) {
+        let alphabet: BTSet<Symbol> = self.symbols();
+        let start: Regex = self.normalize();
+        let mut states: BTSet<State> = BTSet::new();
+        let mut ends: BTSet<State> = BTSet::new();
+        let mut delta: HashMap<Transition<State>, State> = HashMap::new();
+        // deux dérivées distinctes peuvent s'écrire de la meme facon avec des symboles de plusieurs caracteres,
+        // la seconde reçoit alors un nom frais
+        let mut ids: HashMap<Regex, State> = HashMap::new();
+        let mut labels: HashMap<State, Regex> = HashMap::new();
+        let mut queue: VecDeque<Regex> = VecDeque::new();
+        let initial: State = State::new(start.to_string());
+        let mut names: BTSet<State> = BTSet::new().from_vect(vec![initial.clone()]);
+        ids.insert(start.clone(), initial.clone());
+        labels.insert(initial.clone(), start.clone());
+        queue.push_back(start);
+        while let Some(regex) = queue.pop_front() {
+            let state: State = ids[&regex].clone();
+            states.insert(state.clone());
+            if regex.is_nullable() {
+                ends.insert(state.clone());
+            }
+            for symbol in alphabet.get() {
+                let derivative: Regex = regex.derivative(symbol);
+                if derivative == Regex::Empty {
+                    continue;
+                }
+                if !ids.contains_key(&derivative) {
+                    let next: State = fresh_state(&names, &derivative.to_string());
+                    names.insert(next.clone());
+                    ids.insert(derivative.clone(), next.clone());
+                    labels.insert(next, derivative.clone());
+                    queue.push_back(derivative.clone());
+                }
+                delta.insert(Transition::new(symbol.clone(), state.clone()), ids[&derivative].clone());
+            }
+        }
+        (DFA::new(initial, delta, FSM::new(states, alphabet, ends)), labels)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{equivalent, AutomateTrait};
+
+    #[test]
+    fn brzozowski() {
+        for pattern in ["(a|b)*abb", "a+b?", "ε", "∅", "a∅|b", "(a|ε)(b|ε)", "(ab)*a?", "((a|b*)c)+", "(a*b*)*", "(a|b)*a(a|b)(a|b)"] {
+            let regex: Regex = Regex::parse(pattern).unwrap();
+            let dfa: DFA = regex.to_brzozowski();
+            // un oracle independant de la construction de Thompson
+            assert!(equivalent(&dfa, &regex.to_thompson()).is_ok(), "{}", pattern);
+        }
+        // les constructeurs normalisants garantissent un nombre fini de dérivées
+        let dfa: DFA = Regex::parse("(a|b)*a(a|b)(a|b)").unwrap().to_brzozowski();
+        assert_eq!(dfa.get_states().len(), 8);
+        assert_eq!(dfa.minimize_hopcroft().0.get_states().len(), 8);
+        // normalisation
+        assert_eq!(Regex::parse("(b|a|∅)(εc)**").unwrap().normalize(), Regex::parse("(a|b)c*").unwrap());
+        assert_eq!(Regex::parse("(a?)+").unwrap().normalize(), Regex::parse("a*").unwrap());
+        assert!(Regex::parse("a*(b|ε)").unwrap().is_nullable());
+        assert!(!Regex::parse("a+b?").unwrap().is_nullable());
+        // une concatenation vide est ε, une union vide est ∅
+        let a: Symbol = Symbol::from_str("a");
+        assert_eq!(Regex::Concat(vec![]).derivative(&a), Regex::Empty);
+        assert_eq!(Regex::Union(vec![]).derivative(&a), Regex::Empty);
+        assert_eq!(Regex::Concat(vec![Regex::Concat(vec![]), Regex::Symbol(a.clone())]).derivative(&a), Regex::Epsilon);
+        assert!(Regex::Concat(vec![]).to_brzozowski().accept(""));
+        assert!(!Regex::Union(vec![]).to_brzozowski().accept(""));
+        // des dérivées qui s'écrivent de la meme facon restent des états distincts
+        let symbol = |value: &str| Regex::Symbol(Symbol::from_str(value));
+        let regex: Regex = Regex::union(vec![Regex::concat(vec![symbol("a"), symbol("bc")]), Regex::concat(vec![symbol("x"), symbol("b"), symbol("c")])]);
+        let (dfa, labels): (DFA, HashMap<State, Regex>) = regex.to_brzozowski_with_labels();
+        assert_eq!(dfa.get_states().len(), 5);
+        assert_eq!(labels.len(), 5);
+        assert!(dfa.get_states().contains(&State::from_str("bc")));
+        assert!(dfa.get_states().contains(&State::from_str("bc_1")));
+        assert!(equivalent(&dfa, &regex.to_thompson()).is_ok());
+    }
+}
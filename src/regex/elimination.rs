@@ -0,0 +1,187 @@
+use super::Regex;
+use crate::{AutomateTrait, AutomateTrim, BTSet, State, Symbol, Transition, FSM, NDFA};
+use std::collections::{BTreeSet, HashMap};
+
+/// L'ordre dans lequel les états sont éliminés lors de la conversion d'un automate en expression régulière
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum EliminationOrder {
+    /// Les états sont éliminés dans l'ordre de leurs noms
+    Sorted,
+    /// L'état ayant le moins de transitions entrantes et sortantes est éliminé en premier, ce qui limite la taille du resultat
+    #[default]
+    FewestEdges,
+}
+
+// automate generalisé dont les transitions portent des expressions régulières
+// les noeuds 0 et 1 sont l'unique etat initial et l'unique etat final ajoutés, les etats de l'automate suivent
+// les termes d'une transition sont accumulés et ne sont réunis qu'au moment où la transition est lue
+struct GeneralizedNFA {
+    edges: HashMap<(usize, usize), Vec<Regex>>,
+}
+
+impl GeneralizedNFA {
+    fn add(&mut self, from: usize, to: usize, regex: Regex) {
+        if regex != Regex::Empty {
+            self.edges.entry((from, to)).or_default().push(regex);
+        }
+    }
+
+    // retire la transition et retourne l'union de ses termes
+    fn take(&mut self, from: usize, to: usize) -> Regex {
+        Regex::union(self.edges.remove(&(from, to)).unwrap_or_default())
+    }
+
+    fn degree(&self, node: usize) -> usize {
+        self.edges.keys().filter(|(from, to)| *from == node || *to == node).count()
+    }
+
+    // retire le noeud en reportant chaque chemin i -> node -> j sur une transition i -> j
+    fn eliminate(&mut self, node: usize) {
+        let looping: Regex = Regex::star(self.take(node, node));
+        let neighbours: Vec<(usize, usize)> = self.edges.keys().filter(|(from, to)| *from == node || *to == node).copied().collect();
+        let mut incoming: Vec<(usize, Regex)> = Vec::new();
+        let mut outgoing: Vec<(usize, Regex)> = Vec::new();
+        for (from, to) in neighbours {
+            let regex: Regex = self.take(from, to);
+            if to == node {
+                incoming.push((from, regex));
+            } else {
+                outgoing.push((to, regex));
+            }
+        }
+        for (from, before) in &incoming {
+            for (to, after) in &outgoing {
+                self.add(*from, *to, Regex::concat(vec![before.clone(), looping.clone(), after.clone()]));
+            }
+        }
+    }
+}
+
+// fusionne les états ayant le meme futur (bisimulation avant): meme finalité et, pour chaque symbole, des images
+// dans les memes classes. Le langage est conservé et l'élimination porte sur moins d'états.
+fn merge_equivalent_states(nfa: &NDFA) -> NDFA {
+    let states: Vec<State> = nfa.get_states().get().iter().cloned().collect();
+    let mut class: HashMap<State, usize> = states.iter().map(|state| (state.clone(), usize::from(nfa.get_ends().contains(state)))).collect();
+    let mut count: usize = 0;
+    loop {
+        // la signature d'un état: sa classe et les classes atteintes par chaque symbole
+        let mut signatures: HashMap<(usize, BTreeSet<(Symbol, usize)>), usize> = HashMap::new();
+        let mut next: HashMap<State, usize> = HashMap::new();
+        for state in &states {
+            let mut targets: BTreeSet<(Symbol, usize)> = BTreeSet::new();
+            for symbol in nfa.get_alphabet().get() {
+                if let Some(images) = nfa.get_delta().get(&Transition::new(symbol.clone(), state.clone())) {
+                    for image in images.get() {
+                        targets.insert((symbol.clone(), class[image]));
+                    }
+                }
+            }
+            let size: usize = signatures.len();
+            let id: usize = *signatures.entry((class[state], targets)).or_insert(size);
+            next.insert(state.clone(), id);
+        }
+        class = next;
+        if signatures.len() == count {
+            break;
+        }
+        count = signatures.len();
+    }
+    // chaque classe est représentée par son plus petit état
+    let mut representative: HashMap<usize, State> = HashMap::new();
+    for state in &states {
+        representative.entry(class[state]).or_insert_with(|| state.clone());
+    }
+    let rename = |state: &State| representative[&class[state]].clone();
+    let rename_all = |set: &BTSet<State>| {
+        let mut renamed: BTSet<State> = BTSet::new();
+        for state in set.get() {
+            renamed.insert(rename(state));
+        }
+        renamed
+    };
+    let mut delta: HashMap<Transition<State>, BTSet<State>> = HashMap::new();
+    for (transition, images) in nfa.get_delta() {
+        let key: Transition<State> = Transition::new(transition.get_symbol().clone(), rename(transition.get_content()));
+        delta.entry(key).or_default().insert_all(rename_all(images));
+    }
+    let fsm: FSM = FSM::new(rename_all(nfa.get_states()), nfa.get_alphabet().clone(), rename_all(nfa.get_ends()));
+    NDFA::new(rename_all(nfa.get_starts()), delta, fsm)
+}
+
+/// Convertit un NDFA en expression régulière par élimination d'états, voir `AutomateTrait::to_regex_with`
+///
+/// L'automate est d'abord émondé et ses états de meme futur sont fusionnés, ce qui évite l'explosion de la taille
+/// de l'expression sur les automates produits par la construction de Thompson.
+pub(crate) fn eliminate(nfa: &NDFA, order: EliminationOrder) -> Regex {
+    let nfa: NDFA = merge_equivalent_states(&nfa.trim());
+    let states: Vec<State> = nfa.get_states().get().iter().cloned().collect();
+    let index: HashMap<&State, usize> = states.iter().enumerate().map(|(i, state)| (state, i + 2)).collect();
+    let mut gnfa: GeneralizedNFA = GeneralizedNFA { edges: HashMap::new() };
+    for start in nfa.get_starts().get() {
+        gnfa.add(0, index[start], Regex::Epsilon);
+    }
+    for end in nfa.get_ends().get() {
+        gnfa.add(index[end], 1, Regex::Epsilon);
+    }
+    for (transition, images) in nfa.get_delta() {
+        for image in images.get() {
+            gnfa.add(index[transition.get_content()], index[image], Regex::Symbol(transition.get_symbol().clone()));
+        }
+    }
+    let mut remaining: Vec<usize> = (2..states.len() + 2).collect();
+    while !remaining.is_empty() {
+        let position: usize = match order {
+            EliminationOrder::Sorted => 0,
+            EliminationOrder::FewestEdges => (0..remaining.len()).min_by_key(|&i| gnfa.degree(remaining[i])).unwrap(),
+        };
+        gnfa.eliminate(remaining.remove(position));
+    }
+    gnfa.take(0, 1).simplify()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::interfaces::ToNdfa;
+    use crate::{equivalent, AutomateJsonIO, NDFAEpsilon, DFA};
+
+    #[test]
+    fn state_elimination() {
+        let dfas: Vec<DFA> = ["DFA1", "DFA2", "DFA3"].iter().map(|name| DFA::from_json_file(&format!("src/automates/{}.json", name))).collect();
+        let nfas: Vec<NDFA> = ["NFA1", "NFA2", "NFA3", "NFATODFA", "NFATODFA2"].iter().map(|name| NDFA::from_json_file(&format!("src/automates/{}.json", name))).collect();
+        let nfaes: Vec<NDFAEpsilon> = ["NFA1e", "NFA2e", "NFA3e"].iter().map(|name| NDFAEpsilon::from_json_file(&format!("src/automates/{}.json", name))).collect();
+        for order in [EliminationOrder::Sorted, EliminationOrder::FewestEdges] {
+            // l'expression obtenue, une fois relue, reconnait le meme langage que l'automate
+            for dfa in &dfas {
+                let regex: Regex = Regex::parse(&dfa.to_regex_with(order).to_string()).unwrap();
+                assert!(equivalent(&regex.to_thompson(), dfa).is_ok(), "{}", regex);
+            }
+            for nfa in &nfas {
+                let regex: Regex = Regex::parse(&nfa.to_regex_with(order).to_string()).unwrap();
+                assert!(equivalent(&regex.to_thompson(), nfa).is_ok(), "{}", regex);
+            }
+            for nfae in &nfaes {
+                let regex: Regex = Regex::parse(&nfae.to_regex_with(order).to_string()).unwrap();
+                assert!(equivalent(&regex.to_thompson(), nfae).is_ok(), "{}", regex);
+            }
+        }
+        // un aller retour par une expression connue
+        for pattern in ["(a|b)*abb", "a+b?", "ε", "∅", "(ab)*a?", "((a|b*)c)+"] {
+            let regex: Regex = Regex::parse(pattern).unwrap();
+            assert!(equivalent(&regex.to_glushkov().0.to_regex().to_thompson(), &regex.to_thompson()).is_ok(), "{}", pattern);
+        }
+        // NFA2e n'a aucun état final
+        assert_eq!(nfaes[1].to_regex(), Regex::Empty);
+        // les automates de Thompson sont émondés et leurs états de meme futur fusionnés avant l'élimination
+        for pattern in ["((a|b)|(a|b))*", "((a|b)|(((a|b)|(a|b)))*)", "(a|b)*abb", "(ab|ba)*(a|bb)+", "((a|b*)c)+"] {
+            let nfae: NDFAEpsilon = Regex::parse(pattern).unwrap().to_thompson();
+            for order in [EliminationOrder::Sorted, EliminationOrder::FewestEdges] {
+                let regex: Regex = Regex::parse(&nfae.to_regex_with(order).to_string()).unwrap();
+                assert!(equivalent(&regex.to_thompson(), &nfae).is_ok(), "{} {}", pattern, regex);
+            }
+        }
+        assert_eq!(Regex::parse("((a|b)|(a|b))*").unwrap().to_thompson().to_regex().to_string(), "(a|b)*");
+        let merged: NDFA = merge_equivalent_states(&Regex::parse("(a|b)*").unwrap().to_thompson().to_ndfa().trim());
+        assert_eq!(merged.get_states().len(), 1);
+    }
+}
@@ -0,0 +1,231 @@
+use super::Regex;
+use crate::{BTSet, State, Symbol, Transition, FSM, NDFA};
+use std::collections::HashMap;
+
+/// Les ensembles de positions d'une expression régulière, utilisés par la construction de Glushkov
+///
+/// Les occurrences de symboles sont numérotées de 1 à n dans l'ordre de lecture de l'expression.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PositionSets {
+    nullable: bool,
+    first: BTSet<usize>,
+    last: BTSet<usize>,
+    // follow[i - 1] contient les positions pouvant suivre la position i
+    follow: Vec<BTSet<usize>>,
+    // symbols[i - 1] est le symbole de la position i
+    symbols: Vec<Symbol>,
+}
+
+impl PositionSets {
+    /// Indique si l'expression accepte le mot vide
+    pub fn is_nullable(&self) -> bool {
+        self.nullable
+    }
+
+    /// Retourne les positions pouvant commencer un mot
+    pub fn get_first(&self) -> &BTSet<usize> {
+        &self.first
+    }
+
+    /// Retourne les positions pouvant terminer un mot
+    pub fn get_last(&self) -> &BTSet<usize> {
+        &self.last
+    }
+
+    /// Retourne les positions pouvant suivre la position `position`, numérotée à partir de 1
+    pub fn get_follow(&self, position: usize) -> &BTSet<usize> {
+        &self.follow[position - 1]
+    }
+
+    /// Retourne le symbole de la position `position`, numérotée à partir de 1
+    pub fn get_symbol(&self, position: usize) -> &Symbol {
+        &self.symbols[position - 1]
+    }
+
+    /// Retourne le nombre de positions, c'est a dire le nombre d'occurrences de symboles
+    pub fn len(&self) -> usize {
+        self.symbols.len()
+    }
+
+    /// Indique si l'expression ne contient aucun symbole
+    pub fn is_empty(&self) -> bool {
+        self.symbols.is_empty()
+    }
+
+    // calcule (nullable, first, last) d'une sous expression, en completant follow et symbols au passage
+    fn visit(&mut self, regex: &Regex) -> (bool, BTSet<usize>, BTSet<usize>) {
+        match regex {
+            Regex::Empty => (false, BTSet::new(), BTSet::new()),
+            Regex::Epsilon => (true, BTSet::new(), BTSet::new()),
+            Regex::Symbol(symbol) => {
+                self.symbols.push(symbol.clone());
+                self.follow.push(BTSet::new());
+                let position: BTSet<usize> = BTSet::new().from_vect(vec![self.symbols.len()]);
+                (false, position.clone(), position)
+            }
+            Regex::Concat(items) => {
+                let (mut nullable, mut first, mut last): (bool, BTSet<usize>, BTSet<usize>) = (true, BTSet::new(), BTSet::new());
+                for item in items {
+                    let (item_nullable, item_first, item_last): (bool, BTSet<usize>, BTSet<usize>) = self.visit(item);
+                    // les dernieres positions de ce qui precede peuvent etre suivies par les premieres de l'element
+                    for &position in last.get() {
+                        self.follow[position - 1].insert_all(item_first.clone());
+                    }
+                    if nullable {
+                        first.insert_all(item_first);
+                    }
+                    if !item_nullable {
+                        last = BTSet::new();
+                    }
+                    last.insert_all(item_last);
+                    nullable = nullable && item_nullable;
+                }
+                (nullable, first, last)
+            }
+            Regex::Union(items) => {
+                let (mut nullable, mut first, mut last): (bool, BTSet<usize>, BTSet<usize>) = (false, BTSet::new(), BTSet::new());
+                for item in items {
+                    let (item_nullable, item_first, item_last): (bool, BTSet<usize>, BTSet<usize>) = self.visit(item);
+                    nullable = nullable || item_nullable;
+                    first.insert_all(item_first);
+                    last.insert_all(item_last);
+                }
+                (nullable, first, last)
+            }
+            Regex::Star(inner) | Regex::Plus(inner) | Regex::Optional(inner) => {
+                let (nullable, first, last): (bool, BTSet<usize>, BTSet<usize>) = self.visit(inner);
+                // la repetition permet de revenir au debut apres la fin
+                if !matches!(regex, Regex::Optional(_)) {
+                    for &position in last.get() {
+                        self.follow[position - 1].insert_all(first.clone());
+                    }
+                }
+                (nullable || !matches!(regex, Regex::Plus(_)), first, last)
+            }
+        }
+    }
+}
+
+impl Regex {
+    /// Calcule les ensembles first, last et follow des positions de l'expression
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use automaters::*;
+    /// fn main() {
+    ///     // les positions de (ab|b)*a sont 1:a 2:b 3:b 4:a
+    ///     let sets : PositionSets = Regex::parse("(ab|b)*a").unwrap().position_sets();
+    ///     assert_eq!(sets.len(), 4);
+    ///     assert_eq!(sets.get_first(), &BTSet::new().from_vect(vec![1, 3, 4]));
+    ///     assert_eq!(sets.get_last(), &BTSet::new().from_vect(vec![4]));
+    ///     assert_eq!(sets.get_follow(2), &BTSet::new().from_vect(vec![1, 3, 4]));
+    /// }
+    /// ```
+    ///
+    /// # Return
+    ///
+    /// * `PositionSets` - Les ensembles de positions de l'expression
+    ///
+    pub fn position_sets(&self) -> PositionSets {
+        let mut sets: PositionSets = PositionSets {
+            nullable: false,
+            first: BTSet::new(),
+            last: BTSet::new(),
+            follow: Vec::new(),
+            symbols: Vec::new(),
+        };
+        let (nullable, first, last): (bool, BTSet<usize>, BTSet<usize>) = sets.visit(self);
+        sets.nullable = nullable;
+        sets.first = first;
+        sets.last = last;
+        sets
+    }
+
+    /// Compile l'expression en automate sans epsilon transitions par la construction de Glushkov
+    ///
+    /// L'automate a n + 1 états pour n occurrences de symboles: l'état initial `q_0`, et un état `q_i` par position `i`.
+    /// Toutes les transitions entrant dans `q_i` portent le symbole de la position `i`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use automaters::*;
+    /// use std::collections::HashMap;
+    /// fn main() {
+    ///     let (regex, offsets) : (Regex, Vec<usize>) = Regex::parse_with_offsets("a(b|c)*").unwrap();
+    ///     let (nfa, positions) : (NDFA, HashMap<State, usize>) = regex.to_glushkov();
+    ///     assert_eq!(nfa.get_states().len(), 4);
+    ///     assert!(nfa.accept("abcb"));
+    ///     // l'état q_3 correspond à la troisième occurrence, le c situé en position 4 du texte
+    ///     assert_eq!(offsets[positions[&State::from_str("q_3")] - 1], 4);
+    /// }
+    /// ```
+    ///
+    /// # Return
+    ///
+    /// * `(NDFA, HashMap<State, usize>)` - L'automate, et la position (à partir de 1) correspondant à chaque état autre que `q_0`
+    ///
+    pub fn to_glushkov(&self) -> (NDFA, HashMap<State, usize>) {
+        let sets: PositionSets = self.position_sets();
+        let name = |position: usize| State::new(format!("q_{}", position));
+        let mut states: BTSet<State> = BTSet::new();
+        let mut positions: HashMap<State, usize> = HashMap::new();
+        let mut ends: BTSet<State> = BTSet::new();
+        let mut delta: HashMap<Transition<State>, BTSet<State>> = HashMap::new();
+        states.insert(name(0));
+        if sets.is_nullable() {
+            ends.insert(name(0));
+        }
+        for position in 1..=sets.len() {
+            states.insert(name(position));
+            positions.insert(name(position), position);
+            if sets.get_last().contains(&position) {
+                ends.insert(name(position));
+            }
+        }
+        // q_0 mene aux premieres positions, chaque position mene aux positions qui la suivent
+        let mut add = |from: usize, to: &BTSet<usize>| {
+            for &next in to.get() {
+                delta
+                    .entry(Transition::new(sets.get_symbol(next).clone(), name(from)))
+                    .or_default()
+                    .insert(name(next));
+            }
+        };
+        add(0, sets.get_first());
+        for position in 1..=sets.len() {
+            add(position, sets.get_follow(position));
+        }
+        let fsm: FSM = FSM::new(states, self.symbols(), ends);
+        (NDFA::new(BTSet::new().from_vect(vec![name(0)]), delta, fsm), positions)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{equivalent, AutomateTrait};
+
+    #[test]
+    fn glushkov() {
+        for pattern in ["(a|b)*abb", "a+b?", "ε", "∅", "a∅|b", "(a|ε)(b|ε)", "(ab)*a?", "((a|b*)c)+", "(a*b*)*"] {
+            let regex: Regex = Regex::parse(pattern).unwrap();
+            let (nfa, positions): (NDFA, HashMap<State, usize>) = regex.to_glushkov();
+            // n + 1 etats, sans epsilon, et le meme langage que l'automate de Thompson
+            assert_eq!(nfa.get_states().len(), regex.position_sets().len() + 1, "{}", pattern);
+            assert_eq!(positions.len(), regex.position_sets().len());
+            assert!(equivalent(&nfa, &regex.to_thompson()).is_ok(), "{}", pattern);
+            // chaque etat q_i n'est atteint que par le symbole de la position i
+            for (transition, images) in nfa.get_delta() {
+                for image in images.get() {
+                    assert_eq!(transition.get_symbol(), regex.position_sets().get_symbol(positions[image]));
+                }
+            }
+        }
+        let sets: PositionSets = Regex::parse("(a|b)*abb").unwrap().position_sets();
+        assert!(!sets.is_nullable());
+        assert_eq!(sets.get_follow(1), &BTSet::new().from_vect(vec![1, 2, 3]));
+        assert_eq!(sets.get_follow(5), &BTSet::new());
+    }
+}
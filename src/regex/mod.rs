@@ -0,0 +1,209 @@
+//! Les expressions régulières et leur compilation en automates
+//!
+//! La syntaxe reconnue est la syntaxe classique: la concaténation s'écrit par juxtaposition, `|` est l'union,
+//! `*`, `+` et `?` sont les répétitions, les parenthèses regroupent. `ε` (ou `()`) désigne le mot vide et `∅`
+//! le langage vide. Chaque caractère est un symbole, les caractères spéciaux s'échappent avec `\`.
+mod parser;
+pub use parser::ParseError;
+mod thompson;
+mod glushkov;
+pub use glushkov::PositionSets;
+mod derivative;
+mod antimirov;
+mod elimination;
+pub use elimination::EliminationOrder;
+pub(crate) use elimination::eliminate;
+mod simplify;
+
+use crate::{BTSet, Symbol};
+use std::fmt;
+
+/// Les caractères ayant un sens particulier dans une expression régulière
+pub(crate) const SPECIAL_CHARS: [char; 9] = ['|', '*', '+', '?', '(', ')', '\\', 'ε', '∅'];
+
+/// L'arbre de syntaxe d'une expression régulière
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Regex {
+    /// Le langage vide `∅`
+    Empty,
+    /// Le mot vide `ε`
+    Epsilon,
+    /// Un symbole de l'alphabet
+    Symbol(Symbol),
+    /// La concaténation de plusieurs expressions
+    Concat(Vec<Regex>),
+    /// L'union de plusieurs expressions
+    Union(Vec<Regex>),
+    /// L'étoile de Kleene `r*`
+    Star(Box<Regex>),
+    /// Une ou plusieurs répétitions `r+`
+    Plus(Box<Regex>),
+    /// Zero ou une occurrence `r?`
+    Optional(Box<Regex>),
+}
+
+impl Regex {
+    /// Analyse une expression régulière
+    ///
+    /// # Arguments
+    ///
+    /// * `pattern` - L'expression à analyser
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use automaters::*;
+    /// fn main() {
+    ///     let regex : Regex = Regex::parse("a(b|c)*").unwrap();
+    ///     assert_eq!(regex, Regex::Concat(vec![
+    ///         Regex::Symbol(Symbol::from_str("a")),
+    ///         Regex::Star(Box::new(Regex::Union(vec![
+    ///             Regex::Symbol(Symbol::from_str("b")),
+    ///             Regex::Symbol(Symbol::from_str("c")),
+    ///         ]))),
+    ///     ]));
+    ///     let error : ParseError = Regex::parse("a(b").unwrap_err();
+    ///     assert_eq!(error.get_position(), 3);
+    /// }
+    /// ```
+    ///
+    /// # Return
+    ///
+    /// * `Result<Regex, ParseError>` - L'arbre de syntaxe, ou la position et la nature de l'erreur
+    ///
+    pub fn parse(pattern: &str) -> Result<Regex, ParseError> {
+        parser::parse(pattern)
+    }
+
+    /// Analyse une expression régulière et retourne la position de chaque occurrence de symbole dans le texte
+    ///
+    /// Les occurrences sont dans l'ordre de lecture, la `i`-ème position du vecteur correspond donc à la position `i + 1`
+    /// de la construction de Glushkov. Pour un caractère échappé, la position est celle du `\`.
+    ///
+    /// # Arguments
+    ///
+    /// * `pattern` - L'expression à analyser
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use automaters::*;
+    /// fn main() {
+    ///     let (regex, offsets) : (Regex, Vec<usize>) = Regex::parse_with_offsets("(ab)*|c").unwrap();
+    ///     assert_eq!(regex, Regex::parse("(ab)*|c").unwrap());
+    ///     assert_eq!(offsets, vec![1, 2, 6]);
+    /// }
+    /// ```
+    ///
+    /// # Return
+    ///
+    /// * `Result<(Regex, Vec<usize>), ParseError>` - L'arbre de syntaxe et la position (en caractères) de chaque symbole
+    ///
+    pub fn parse_with_offsets(pattern: &str) -> Result<(Regex, Vec<usize>), ParseError> {
+        parser::parse_with_offsets(pattern)
+    }
+
+    /// Retourne les symboles utilisés par l'expression
+    pub fn symbols(&self) -> BTSet<Symbol> {
+        let mut symbols: BTSet<Symbol> = BTSet::new();
+        self.collect_symbols(&mut symbols);
+        symbols
+    }
+
+    fn collect_symbols(&self, symbols: &mut BTSet<Symbol>) {
+        match self {
+            Regex::Empty | Regex::Epsilon => {}
+            Regex::Symbol(symbol) => {
+                symbols.insert(symbol.clone());
+            }
+            Regex::Concat(items) | Regex::Union(items) => {
+                for item in items {
+                    item.collect_symbols(symbols);
+                }
+            }
+            Regex::Star(inner) | Regex::Plus(inner) | Regex::Optional(inner) => inner.collect_symbols(symbols),
+        }
+    }
+}
+
+// ecrit un symbole en echappant les caracteres speciaux
+pub(crate) fn write_symbol(f: &mut fmt::Formatter<'_>, symbol: &Symbol) -> fmt::Result {
+    for c in symbol.get_value().chars() {
+        if SPECIAL_CHARS.contains(&c) {
+            write!(f, "\\")?;
+        }
+        write!(f, "{}", c)?;
+    }
+    Ok(())
+}
+
+impl Regex {
+    // le niveau de priorité de l'operateur principal: union < concatenation < repetition < atome
+    fn precedence(&self) -> u8 {
+        match self {
+            Regex::Concat(items) | Regex::Union(items) if items.len() == 1 => items[0].precedence(),
+            Regex::Union(items) if items.len() > 1 => 0,
+            Regex::Concat(items) if items.len() > 1 => 1,
+            Regex::Star(_) | Regex::Plus(_) | Regex::Optional(_) => 2,
+            _ => 3,
+        }
+    }
+
+    // ecrit une sous expression, entre parentheses seulement si son operateur est moins prioritaire que `level`
+    fn write_operand(&self, f: &mut fmt::Formatter<'_>, level: u8) -> fmt::Result {
+        if self.precedence() < level {
+            write!(f, "({})", self)
+        } else {
+            write!(f, "{}", self)
+        }
+    }
+}
+
+impl fmt::Display for Regex {
+    /// Ecrit l'expression dans la syntaxe acceptée par `Regex::parse`, avec le minimum de parentheses
+    ///
+    /// ```
+    /// use automaters::*;
+    /// fn main() {
+    ///     let regex : Regex = Regex::parse("((a)(b|(c)))*|((d*)?)").unwrap();
+    ///     assert_eq!(regex.to_string(), "(a(b|c))*|d*?");
+    /// }
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Regex::Empty => write!(f, "∅"),
+            Regex::Epsilon => write!(f, "ε"),
+            Regex::Symbol(symbol) => write_symbol(f, symbol),
+            Regex::Concat(items) if items.is_empty() => write!(f, "ε"),
+            Regex::Union(items) if items.is_empty() => write!(f, "∅"),
+            Regex::Concat(items) | Regex::Union(items) if items.len() == 1 => write!(f, "{}", items[0]),
+            Regex::Concat(items) => {
+                for item in items {
+                    item.write_operand(f, 1)?;
+                }
+                Ok(())
+            }
+            Regex::Union(items) => {
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, "|")?;
+                    }
+                    item.write_operand(f, 0)?;
+                }
+                Ok(())
+            }
+            Regex::Star(inner) => {
+                inner.write_operand(f, 2)?;
+                write!(f, "*")
+            }
+            Regex::Plus(inner) => {
+                inner.write_operand(f, 2)?;
+                write!(f, "+")
+            }
+            Regex::Optional(inner) => {
+                inner.write_operand(f, 2)?;
+                write!(f, "?")
+            }
+        }
+    }
+}
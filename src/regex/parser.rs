@@ -0,0 +1,214 @@
+use super::{Regex, SPECIAL_CHARS};
+use crate::nfae::EPSILON;
+use crate::Symbol;
+use std::fmt;
+
+/// Une erreur d'analyse d'une expression régulière
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    position: usize,
+    expected: String,
+    found: Option<char>,
+}
+
+impl ParseError {
+    /// Retourne la position de l'erreur, en nombre de caractères depuis le debut de l'expression
+    pub fn get_position(&self) -> usize {
+        self.position
+    }
+
+    /// Retourne la description de ce qui était attendu
+    pub fn get_expected(&self) -> &String {
+        &self.expected
+    }
+
+    /// Retourne le caractère trouvé, ou `None` si l'expression s'est terminée trop tôt
+    pub fn get_found(&self) -> Option<char> {
+        self.found
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.found {
+            Some(c) => write!(f, "position {}: {} attendu, '{}' trouvé", self.position, self.expected, c),
+            None => write!(f, "position {}: {} attendu, fin de l'expression trouvée", self.position, self.expected),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+// analyseur par descente recursive:
+//   union  := concat ('|' concat)*
+//   concat := repeat*
+//   repeat := atom ('*' | '+' | '?')*
+//   atom   := symbole | '\' caractere | '(' union ')' | 'ε' | '∅'
+struct Parser {
+    chars: Vec<char>,
+    position: usize,
+    // la position de chaque symbole, dans l'ordre de lecture
+    offsets: Vec<usize>,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.position).copied()
+    }
+
+    fn error(&self, expected: &str) -> ParseError {
+        ParseError {
+            position: self.position,
+            expected: expected.to_string(),
+            found: self.peek(),
+        }
+    }
+
+    fn parse_union(&mut self) -> Result<Regex, ParseError> {
+        let mut items: Vec<Regex> = vec![self.parse_concat()?];
+        while self.peek() == Some('|') {
+            self.position += 1;
+            items.push(self.parse_concat()?);
+        }
+        Ok(if items.len() == 1 { items.pop().unwrap() } else { Regex::Union(items) })
+    }
+
+    fn parse_concat(&mut self) -> Result<Regex, ParseError> {
+        let mut items: Vec<Regex> = Vec::new();
+        while let Some(c) = self.peek() {
+            if c == '|' || c == ')' {
+                break;
+            }
+            items.push(self.parse_repeat()?);
+        }
+        // une branche vide designe le mot vide
+        Ok(match items.len() {
+            0 => Regex::Epsilon,
+            1 => items.pop().unwrap(),
+            _ => Regex::Concat(items),
+        })
+    }
+
+    fn parse_repeat(&mut self) -> Result<Regex, ParseError> {
+        let mut regex: Regex = self.parse_atom()?;
+        while let Some(c) = self.peek() {
+            regex = match c {
+                '*' => Regex::Star(Box::new(regex)),
+                '+' => Regex::Plus(Box::new(regex)),
+                '?' => Regex::Optional(Box::new(regex)),
+                _ => break,
+            };
+            self.position += 1;
+        }
+        Ok(regex)
+    }
+
+    fn parse_atom(&mut self) -> Result<Regex, ParseError> {
+        let expected: &str = "un symbole, '(', 'ε' ou '∅'";
+        let c: char = match self.peek() {
+            Some(c) => c,
+            None => return Err(self.error(expected)),
+        };
+        match c {
+            '(' => {
+                self.position += 1;
+                let regex: Regex = self.parse_union()?;
+                if self.peek() != Some(')') {
+                    return Err(self.error("')'"));
+                }
+                self.position += 1;
+                Ok(regex)
+            }
+            'ε' => {
+                self.position += 1;
+                Ok(Regex::Epsilon)
+            }
+            '∅' => {
+                self.position += 1;
+                Ok(Regex::Empty)
+            }
+            '\\' => {
+                self.position += 1;
+                match self.peek() {
+                    // ε est reservé aux epsilon transitions, il ne peut pas être un symbole
+                    Some(escaped) if escaped.to_string() != EPSILON => {
+                        self.offsets.push(self.position - 1);
+                        self.position += 1;
+                        Ok(Regex::Symbol(Symbol::new(escaped.to_string())))
+                    }
+                    _ => Err(self.error("un caractère à échapper autre que 'ε'")),
+                }
+            }
+            _ if SPECIAL_CHARS.contains(&c) => Err(self.error(expected)),
+            _ => {
+                self.offsets.push(self.position);
+                self.position += 1;
+                Ok(Regex::Symbol(Symbol::new(c.to_string())))
+            }
+        }
+    }
+}
+
+/// Analyse une expression régulière, voir `Regex::parse`
+pub(crate) fn parse(pattern: &str) -> Result<Regex, ParseError> {
+    parse_with_offsets(pattern).map(|(regex, _)| regex)
+}
+
+/// Analyse une expression régulière et retourne aussi la position de chaque symbole, voir `Regex::parse_with_offsets`
+pub(crate) fn parse_with_offsets(pattern: &str) -> Result<(Regex, Vec<usize>), ParseError> {
+    let mut parser: Parser = Parser {
+        chars: pattern.chars().collect(),
+        position: 0,
+        offsets: Vec::new(),
+    };
+    let regex: Regex = parser.parse_union()?;
+    // la seule raison de s'arreter avant la fin est une parenthese fermante en trop
+    if parser.peek().is_some() {
+        return Err(parser.error("un symbole, '|' ou la fin de l'expression"));
+    }
+    Ok((regex, parser.offsets))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn symbol(value: &str) -> Regex {
+        Regex::Symbol(Symbol::from_str(value))
+    }
+
+    #[test]
+    fn parse_and_errors() {
+        // les priorités: les repetitions, puis la concatenation, puis l'union
+        assert_eq!(
+            parse("ab*|c+?").unwrap(),
+            Regex::Union(vec![
+                Regex::Concat(vec![symbol("a"), Regex::Star(Box::new(symbol("b")))]),
+                Regex::Optional(Box::new(Regex::Plus(Box::new(symbol("c"))))),
+            ])
+        );
+        // le mot vide, le langage vide et les caracteres echappés
+        assert_eq!(parse("").unwrap(), Regex::Epsilon);
+        assert_eq!(parse("()").unwrap(), Regex::Epsilon);
+        assert_eq!(parse("a|").unwrap(), Regex::Union(vec![symbol("a"), Regex::Epsilon]));
+        assert_eq!(parse("ε∅").unwrap(), Regex::Concat(vec![Regex::Epsilon, Regex::Empty]));
+        assert_eq!(parse("\\*\\\\").unwrap(), Regex::Concat(vec![symbol("*"), symbol("\\")]));
+        // l'affichage peut être relu
+        for pattern in ["a(b|c)*d", "(ab)+|\\(?", "((a|ε)b*)*∅", "a\\|b"] {
+            let regex: Regex = parse(pattern).unwrap();
+            assert_eq!(parse(&regex.to_string()).unwrap(), regex, "{}", pattern);
+        }
+        // les erreurs indiquent la position et ce qui était attendu
+        let error: ParseError = parse("a(b").unwrap_err();
+        assert_eq!((error.get_position(), error.get_expected().as_str(), error.get_found()), (3, "')'", None));
+        let error: ParseError = parse("ab)c").unwrap_err();
+        assert_eq!((error.get_position(), error.get_found()), (2, Some(')')));
+        let error: ParseError = parse("a|*b").unwrap_err();
+        assert_eq!((error.get_position(), error.get_found()), (2, Some('*')));
+        assert_eq!(error.to_string(), "position 2: un symbole, '(', 'ε' ou '∅' attendu, '*' trouvé");
+        assert_eq!(parse("ab\\").unwrap_err().get_position(), 3);
+        assert_eq!(parse("\\ε").unwrap_err().get_position(), 1);
+        // la position de chaque symbole dans le texte
+        assert_eq!(parse_with_offsets("(a|\\*)b*").unwrap().1, vec![1, 3, 6]);
+    }
+}
@@ -0,0 +1,222 @@
+use super::Regex;
+
+// les facteurs d'une expression vue comme une concatenation
+fn factors(regex: &Regex) -> Vec<Regex> {
+    match regex {
+        Regex::Concat(items) => items.clone(),
+        Regex::Epsilon => Vec::new(),
+        _ => vec![regex.clone()],
+    }
+}
+
+// le contenu d'une repetition, s'il s'agit d'une etoile
+fn starred(regex: &Regex) -> Option<&Regex> {
+    match regex {
+        Regex::Star(inner) => Some(inner),
+        _ => None,
+    }
+}
+
+// factorise les termes d'une union ayant le meme premier facteur (ou le meme dernier si `suffix` est vrai)
+fn factor(terms: Vec<Regex>, suffix: bool) -> Vec<Regex> {
+    let mut groups: Vec<(Regex, Vec<Vec<Regex>>)> = Vec::new();
+    let mut others: Vec<Regex> = Vec::new();
+    for term in terms {
+        let mut items: Vec<Regex> = factors(&term);
+        let key: Option<Regex> = if suffix { items.pop() } else if items.is_empty() { None } else { Some(items.remove(0)) };
+        match key {
+            Some(key) => match groups.iter_mut().find(|(existing, _)| *existing == key) {
+                Some((_, rests)) => rests.push(items),
+                None => groups.push((key, vec![items])),
+            },
+            None => others.push(term),
+        }
+    }
+    for (key, rests) in groups {
+        if rests.len() == 1 {
+            let mut items: Vec<Regex> = rests.into_iter().next().unwrap();
+            if suffix {
+                items.push(key);
+            } else {
+                items.insert(0, key);
+            }
+            others.push(Regex::concat(items));
+        } else {
+            // x·r | x·s = x·(r|s) et r·x | s·x = (r|s)·x
+            let rest: Regex = Regex::union(rests.into_iter().map(Regex::concat).collect());
+            others.push(if suffix { Regex::concat(vec![rest, key]) } else { Regex::concat(vec![key, rest]) });
+        }
+    }
+    others
+}
+
+impl Regex {
+    /// Simplifie l'expression par des règles algébriques qui préservent son langage
+    ///
+    /// En plus de la normalisation de `normalize` (absorption de `∅` et `ε`, union idempotente et commutative,
+    /// `(r*)* = r*`), les règles suivantes sont appliquées jusqu'à ce que l'expression ne change plus:
+    ///
+    /// * `ε|r = r?` et `ε|r = r` si `r` accepte le mot vide
+    /// * `r|r* = r*` et `r+|r* = r*`
+    /// * `r·r* = r*·r = r+`, `r*·r* = r*` et `r?·r* = r*·r? = r*`
+    /// * `(ε|r|s)* = (r|s)*` et `(r*|s)* = (r|s)*`
+    /// * `r+ = r*` si `r` accepte le mot vide
+    /// * `x·r|x·s = x·(r|s)` et `r·x|s·x = (r|s)·x`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use automaters::*;
+    /// fn main() {
+    ///     let regex : Regex = Regex::parse("(a|∅)ε*(b|b)").unwrap();
+    ///     assert_eq!(regex.simplify().to_string(), "ab");
+    ///     let regex : Regex = Regex::parse("abc|abd|ε|a*a").unwrap();
+    ///     assert_eq!(regex.simplify().to_string(), "(ab(c|d)|a+)?");
+    /// }
+    /// ```
+    ///
+    /// # Return
+    ///
+    /// * `Regex` - L'expression simplifiée, qui reconnait le même langage
+    ///
+    pub fn simplify(&self) -> Regex {
+        let mut current: Regex = self.normalize();
+        loop {
+            let next: Regex = current.simplify_once();
+            if next == current {
+                return current;
+            }
+            current = next;
+        }
+    }
+
+    // applique une passe des regles, des feuilles vers la racine
+    fn simplify_once(&self) -> Regex {
+        match self {
+            Regex::Empty | Regex::Epsilon | Regex::Symbol(_) => self.clone(),
+            Regex::Star(inner) => {
+                // sous une etoile, le mot vide et les etoiles internes d'une union sont inutiles
+                let inner: Regex = match inner.simplify_once() {
+                    Regex::Union(items) => Regex::union(
+                        items
+                            .into_iter()
+                            .filter(|item| *item != Regex::Epsilon)
+                            .map(|item| match item {
+                                Regex::Star(inner) | Regex::Plus(inner) | Regex::Optional(inner) => *inner,
+                                _ => item,
+                            })
+                            .collect(),
+                    ),
+                    inner => inner,
+                };
+                Regex::star(inner)
+            }
+            Regex::Plus(inner) => {
+                let inner: Regex = inner.simplify_once();
+                if inner.is_nullable() {
+                    Regex::star(inner)
+                } else {
+                    Regex::plus(inner)
+                }
+            }
+            Regex::Optional(inner) => Regex::optional(inner.simplify_once()),
+            Regex::Concat(items) => {
+                let mut merged: Vec<Regex> = Vec::new();
+                for item in factors(&Regex::concat(items.iter().map(Regex::simplify_once).collect())) {
+                    let previous: Option<Regex> = merged.pop();
+                    match previous {
+                        Some(previous) => match (starred(&previous), starred(&item)) {
+                            // r*·r* = r*
+                            (Some(x), Some(y)) if x == y => merged.push(previous),
+                            // r·r* = r*·r = r+
+                            (None, Some(x)) | (Some(x), None) if *x == previous || *x == item => merged.push(Regex::plus(x.clone())),
+                            // r?·r* = r*·r? = r*
+                            (None, Some(x)) if previous == Regex::optional(x.clone()) => merged.push(item),
+                            (Some(x), None) if item == Regex::optional(x.clone()) => merged.push(previous),
+                            _ => {
+                                merged.push(previous);
+                                merged.push(item);
+                            }
+                        },
+                        None => merged.push(item),
+                    }
+                }
+                Regex::concat(merged)
+            }
+            Regex::Union(items) => {
+                let terms: Vec<Regex> = match Regex::union(items.iter().map(Regex::simplify_once).collect()) {
+                    Regex::Union(terms) => terms,
+                    other => return other,
+                };
+                // r|r* = r*, r+|r* = r*
+                let stars: Vec<Regex> = terms.iter().filter_map(starred).cloned().collect();
+                let mut terms: Vec<Regex> = terms
+                    .into_iter()
+                    .filter(|term| match term {
+                        Regex::Plus(inner) => !stars.contains(inner),
+                        _ => !stars.contains(term),
+                    })
+                    .collect();
+                // le mot vide est absorbé par un terme qui l'accepte deja, sinon ε|r = r?
+                let has_epsilon: bool = terms.contains(&Regex::Epsilon);
+                terms.retain(|term| *term != Regex::Epsilon);
+                let nullable: bool = terms.iter().any(Regex::is_nullable);
+                let terms: Vec<Regex> = factor(factor(terms, false), true);
+                let union: Regex = Regex::union(terms);
+                if has_epsilon && !nullable {
+                    Regex::optional(union)
+                } else {
+                    union
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{equivalent, AutomateTrait, DFA};
+
+    #[test]
+    fn simplify_and_print() {
+        let cases: [(&str, &str); 12] = [
+            ("(a|∅)ε*(b|b)", "ab"),
+            ("((a*)*)*", "a*"),
+            ("ε|a", "a?"),
+            ("ε|a*", "a*"),
+            ("a|a*|a+", "a*"),
+            ("aa*", "a+"),
+            ("a*a*", "a*"),
+            ("a?a*", "a*"),
+            ("(ε|a|b*)*", "(a|b)*"),
+            ("(a?)+", "a*"),
+            ("abc|abd", "ab(c|d)"),
+            ("ac|bc", "(a|b)c"),
+        ];
+        for (pattern, expected) in cases {
+            let regex: Regex = Regex::parse(pattern).unwrap();
+            assert_eq!(regex.simplify().to_string(), expected, "{}", pattern);
+            assert!(equivalent(&regex.simplify().to_thompson(), &regex.to_thompson()).is_ok(), "{}", pattern);
+        }
+        // la simplification ne change pas le langage et ne grandit pas l'expression
+        for pattern in ["(a|b)*abb", "((a|b*)c)+", "(a*b*)*", "(ab|ac|a)*(ε|b)", "a(b|c)d|a(b|c)e|ε", "(a+|b?)(a*|b)"] {
+            let regex: Regex = Regex::parse(pattern).unwrap();
+            let simplified: Regex = regex.simplify();
+            assert!(equivalent(&simplified.to_thompson(), &regex.to_thompson()).is_ok(), "{} {}", pattern, simplified);
+            assert!(simplified.to_string().chars().count() <= pattern.chars().count(), "{} {}", pattern, simplified);
+            assert_eq!(simplified.simplify(), simplified);
+        }
+        // l'affichage n'utilise que les parentheses necessaires et peut être relu
+        for pattern in ["(a|b)*abb", "a(b|c)+d?", "(ab)*|c", "((a|b)c)*", "\\(\\)|\\|", "a**"] {
+            let regex: Regex = Regex::parse(pattern).unwrap();
+            assert_eq!(regex.to_string(), pattern);
+            assert_eq!(Regex::parse(&regex.to_string()).unwrap(), regex);
+        }
+        // les expressions produites par elimination d'etats sont simplifiées
+        let dfa: DFA = Regex::parse("(a|b)*abb").unwrap().to_brzozowski();
+        let regex: Regex = dfa.to_regex();
+        assert_eq!(regex, regex.simplify());
+        assert!(equivalent(&regex.to_thompson(), &dfa).is_ok());
+    }
+}
@@ -0,0 +1,166 @@
+use super::Regex;
+use crate::nfae::EPSILON;
+use crate::{BTSet, NDFAEpsilon, State, Symbol, Transition, FSM};
+use std::collections::HashMap;
+
+// automate en construction, les etats sont numérotés dans l'ordre de creation
+struct Thompson {
+    count: usize,
+    delta: HashMap<Transition<State>, BTSet<State>>,
+}
+
+impl Thompson {
+    fn new_state(&mut self) -> State {
+        let state: State = State::new(format!("q_{}", self.count));
+        self.count += 1;
+        state
+    }
+
+    fn add(&mut self, from: &State, symbol: &Symbol, to: &State) {
+        self.delta
+            .entry(Transition::new(symbol.clone(), from.clone()))
+            .or_default()
+            .insert(to.clone());
+    }
+
+    // construit le fragment de l'expression et retourne son etat d'entrée et son etat de sortie
+    fn build(&mut self, regex: &Regex) -> (State, State) {
+        let epsilon: Symbol = Symbol::from_str(EPSILON);
+        match regex {
+            Regex::Empty => (self.new_state(), self.new_state()),
+            Regex::Epsilon => {
+                let (start, end): (State, State) = (self.new_state(), self.new_state());
+                self.add(&start, &epsilon, &end);
+                (start, end)
+            }
+            Regex::Symbol(symbol) => {
+                let (start, end): (State, State) = (self.new_state(), self.new_state());
+                self.add(&start, symbol, &end);
+                (start, end)
+            }
+            Regex::Concat(items) => {
+                // une concatenation vide reconnait le mot vide
+                let Some((first, rest)) = items.split_first() else {
+                    return self.build(&Regex::Epsilon);
+                };
+                // les fragments sont chainés par des epsilon transitions
+                let (start, mut end): (State, State) = self.build(first);
+                for item in rest {
+                    let (next_start, next_end): (State, State) = self.build(item);
+                    self.add(&end, &epsilon, &next_start);
+                    end = next_end;
+                }
+                (start, end)
+            }
+            Regex::Union(items) => {
+                let start: State = self.new_state();
+                let mut ends: Vec<State> = Vec::new();
+                for item in items {
+                    let (item_start, item_end): (State, State) = self.build(item);
+                    self.add(&start, &epsilon, &item_start);
+                    ends.push(item_end);
+                }
+                let end: State = self.new_state();
+                for item_end in ends {
+                    self.add(&item_end, &epsilon, &end);
+                }
+                (start, end)
+            }
+            Regex::Star(inner) | Regex::Plus(inner) | Regex::Optional(inner) => {
+                let start: State = self.new_state();
+                let (inner_start, inner_end): (State, State) = self.build(inner);
+                let end: State = self.new_state();
+                self.add(&start, &epsilon, &inner_start);
+                self.add(&inner_end, &epsilon, &end);
+                // l'etoile et le plus peuvent recommencer, l'etoile et l'option peuvent sauter le fragment
+                if !matches!(regex, Regex::Optional(_)) {
+                    self.add(&inner_end, &epsilon, &inner_start);
+                }
+                if !matches!(regex, Regex::Plus(_)) {
+                    self.add(&start, &epsilon, &end);
+                }
+                (start, end)
+            }
+        }
+    }
+}
+
+impl Regex {
+    /// Compile l'expression en automate avec epsilon transitions par la construction de Thompson
+    ///
+    /// Chaque sous expression produit un fragment avec un unique état d'entrée et un unique état de sortie,
+    /// reliés aux autres fragments par des transitions `ε`. Les états sont nommés `q_0`, `q_1`, ... dans l'ordre de création.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use automaters::*;
+    /// fn main() {
+    ///     let nfae : NDFAEpsilon = Regex::parse("a(b|c)*").unwrap().to_thompson();
+    ///     assert!(nfae.accept("abcb"));
+    ///     assert!(!nfae.accept("b"));
+    /// }
+    /// ```
+    ///
+    /// # Return
+    ///
+    /// * `NDFAEpsilon` - L'automate reconnaissant le langage de l'expression, avec un unique état initial et un unique état final
+    ///
+    pub fn to_thompson(&self) -> NDFAEpsilon {
+        let mut thompson: Thompson = Thompson {
+            count: 0,
+            delta: HashMap::new(),
+        };
+        let (start, end): (State, State) = thompson.build(self);
+        let mut states: BTSet<State> = BTSet::new();
+        for i in 0..thompson.count {
+            states.insert(State::new(format!("q_{}", i)));
+        }
+        let mut alphabet: BTSet<Symbol> = self.symbols();
+        alphabet.insert(Symbol::from_str(EPSILON));
+        let fsm: FSM = FSM::new(states, alphabet, BTSet::new().from_vect(vec![end]));
+        NDFAEpsilon::new(BTSet::new().from_vect(vec![start]), thompson.delta, fsm)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{AutomateTrait, DFA};
+    use crate::test_utils::words;
+
+    // indique si un mot appartient au langage attendu
+    type Oracle = fn(&str) -> bool;
+
+    #[test]
+    fn thompson() {
+        let nfae: NDFAEpsilon = Regex::parse("(a|b)*abb").unwrap().to_thompson();
+        assert_eq!(nfae.get_starts().len(), 1);
+        assert_eq!(nfae.get_ends().len(), 1);
+        assert!(nfae.get_alphabet().contains(&Symbol::from_str(EPSILON)));
+        let dfa: DFA = nfae.to_dfa();
+        for word in words(&["a", "b"], 6) {
+            assert_eq!(nfae.accept(&word), word.ends_with("abb"), "{}", word);
+            assert_eq!(dfa.accept(&word), word.ends_with("abb"), "{}", word);
+        }
+        // les repetitions, le mot vide et le langage vide
+        let cases: [(&str, Oracle); 6] = [
+            ("a+b?", |w| w.starts_with('a') && ["", "b"].contains(&w.trim_start_matches('a'))),
+            ("ε", |w| w.is_empty()),
+            ("∅", |_| false),
+            ("a∅|b", |w| w == "b"),
+            ("(a|ε)(b|ε)", |w| ["", "a", "b", "ab"].contains(&w)),
+            ("(ab)*", |w| w.len() % 2 == 0 && w.as_bytes().chunks(2).all(|c| c == b"ab")),
+        ];
+        for (pattern, expected) in cases {
+            let nfae: NDFAEpsilon = Regex::parse(pattern).unwrap().to_thompson();
+            for word in words(&["a", "b"], 5) {
+                assert_eq!(nfae.accept(&word), expected(&word), "{} {}", pattern, word);
+            }
+        }
+        // une concatenation vide est ε, une union vide est ∅
+        assert!(Regex::Concat(vec![]).to_thompson().accept(""));
+        assert!(!Regex::Union(vec![]).to_thompson().accept(""));
+        assert!(Regex::Concat(vec![Regex::Concat(vec![]), Regex::Symbol(Symbol::from_str("a"))]).to_thompson().accept("a"));
+    }
+}
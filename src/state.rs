@@ -1,8 +1,10 @@
 use std::str::FromStr;
+use crate::BTSet;
 
 //string n'implemente pas copy, donc on peut juste utiliser clone
 /// Un etat
 #[derive(Debug, Clone, Hash, Eq, Ord, PartialOrd)]
+#[allow(clippy::derived_hash_with_manual_eq)]
 pub struct State {
     name: String,
 }
@@ -33,6 +35,7 @@ impl State {
         State { name: _name }
     }
 
+    #[allow(clippy::should_implement_trait)]
     pub fn from_str(_name : &str) -> Self {
         State { name: String::from_str(_name).unwrap() }
     }
@@ -59,11 +62,25 @@ impl State {
     }
 }
 
+/// Retourne un état nommé à partir de `base` qui n'appartient pas à `states`
+///
+/// Si `base` est déjà utilisé, un suffixe numérique est ajouté: `base_1`, `base_2`, ...
+pub(crate) fn fresh_state(states: &BTSet<State>, base: &str) -> State {
+    let mut state: State = State::from_str(base);
+    let mut i: usize = 1;
+    while states.contains(&state) {
+        state = State::new(format!("{}_{}", base, i));
+        i += 1;
+    }
+    state
+}
+
 impl PartialEq<State> for State {
     fn eq(&self, other: &State) -> bool {
         self.get_name() == other.get_name()
     }
 
+    #[allow(clippy::partialeq_ne_impl)]
     fn ne(&self, other: &State) -> bool {
         self.get_name() != other.get_name()
     }
@@ -74,6 +91,7 @@ mod test {
     use super::*;
 
     #[test]
+    #[allow(clippy::bool_assert_comparison)]
     fn creation_partial_eq_copy() {      
         let string_one : String = String::from("state");
         let string_two : String = String::from("");
@@ -86,5 +104,12 @@ mod test {
         assert_eq!(state_one==state_two, false);
         assert_eq!(state_one.get_name(), &(string_one));
 
+        // generation d'un nom libre
+        let mut states : BTSet<State> = BTSet::new();
+        assert_eq!(fresh_state(&states, "puits"), State::from_str("puits"));
+        states.insert(State::from_str("puits"));
+        states.insert(State::from_str("puits_1"));
+        assert_eq!(fresh_state(&states, "puits"), State::from_str("puits_2"));
+
     }
 }
\ No newline at end of file
@@ -2,6 +2,7 @@ use std::str::FromStr;
 // string n'implemente pas copy, donc on peut juste utiliser clone
 /// Un symbole 
 #[derive(Debug, Clone, Hash, Eq, Ord, PartialOrd)]
+#[allow(clippy::derived_hash_with_manual_eq)]
 pub struct Symbol {
     // la valeur du symbol
     value: String, 
@@ -34,6 +35,7 @@ impl Symbol {
         Symbol { value: _value }
     }
 
+    #[allow(clippy::should_implement_trait)]
     pub fn from_str(_value : &str) -> Self {
         Symbol { value: String::from_str(_value).unwrap() }
     }
@@ -65,6 +67,7 @@ impl PartialEq<Symbol> for Symbol {
         self.get_value() == other.get_value()
     }
 
+    #[allow(clippy::partialeq_ne_impl)]
     fn ne(&self, other: &Symbol) -> bool {
         self.get_value() != other.get_value()
     }
@@ -75,6 +78,7 @@ mod test {
     use super::*;
 
     #[test]
+    #[allow(clippy::bool_assert_comparison)]
     fn creation_partial_eq_copy() {      
         let string_one : String = String::from("Symbol");
         let string_two : String = String::from("Symbol two");
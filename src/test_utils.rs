@@ -0,0 +1,44 @@
+use crate::{BTSet, NDFAEpsilon, State, Symbol, Transition, DFA, FSM};
+use std::collections::HashMap;
+
+// les fonctions partagées par les tests des modules
+
+// enumere tout les mots de longueur inferieur ou egal à `length` sur l'alphabet donné
+pub(crate) fn words(alphabet: &[&str], length: usize) -> Vec<String> {
+    let mut words: Vec<String> = vec![String::new()];
+    let mut last: Vec<String> = vec![String::new()];
+    for _ in 0..length {
+        last = last.iter().flat_map(|word| alphabet.iter().map(move |letter| format!("{}{}", word, letter))).collect();
+        words.extend(last.clone());
+    }
+    words
+}
+
+// concatene les valeurs des symboles d'un mot
+pub(crate) fn concat(word: &[Symbol]) -> String {
+    word.iter().map(|symbol| symbol.get_value().as_str()).collect()
+}
+
+// le DFA s -a-> t -b-> f dont l'etat intermediaire t n'est pas declaré, il accepte le mot "ab"
+pub(crate) fn undeclared_intermediate() -> DFA {
+    let (s, t, f): (State, State, State) = (State::new("s".to_string()), State::new("t".to_string()), State::new("f".to_string()));
+    let delta: HashMap<Transition<State>, State> = HashMap::from([
+        (Transition::new(Symbol::from_str("a"), s.clone()), t.clone()),
+        (Transition::new(Symbol::from_str("b"), t), f.clone()),
+    ]);
+    let alphabet: BTSet<Symbol> = BTSet::new().from_vect(vec![Symbol::from_str("a"), Symbol::from_str("b")]);
+    DFA::new(s.clone(), delta, FSM::new(BTSet::new().from_vect(vec![s, f.clone()]), alphabet, BTSet::new().from_vect(vec![f])))
+}
+
+// le NDFAEpsilon s -a-> t -b-> v -ε-> u dont les etats t et v ne sont pas declarés, il accepte le mot "ab"
+pub(crate) fn undeclared_intermediate_epsilon() -> NDFAEpsilon {
+    let state = |name: &str| -> State { State::from_str(name) };
+    let delta: HashMap<Transition<State>, BTSet<State>> = HashMap::from([
+        (Transition::new(Symbol::from_str("a"), state("s")), BTSet::new().from_vect(vec![state("t")])),
+        (Transition::new(Symbol::from_str("b"), state("t")), BTSet::new().from_vect(vec![state("v")])),
+        (Transition::new(Symbol::from_str("ε"), state("v")), BTSet::new().from_vect(vec![state("u")])),
+    ]);
+    let alphabet: BTSet<Symbol> = BTSet::new().from_vect(vec![Symbol::from_str("a"), Symbol::from_str("b"), Symbol::from_str("ε")]);
+    let fsm: FSM = FSM::new(BTSet::new().from_vect(vec![state("s"), state("u")]), alphabet, BTSet::new().from_vect(vec![state("u")]));
+    NDFAEpsilon::new(BTSet::new().from_vect(vec![state("s")]), delta, fsm)
+}
@@ -2,6 +2,7 @@ use super::Symbol;
 
 /// Une transition
 #[derive(Debug, Clone, Hash, Eq, Ord, PartialOrd)]
+#[allow(clippy::derived_hash_with_manual_eq)]
 pub struct Transition<T: Clone> {
     symbol: Symbol,
     content: T
@@ -123,6 +124,7 @@ impl<T> PartialEq for Transition<T>
     fn eq(&self, other: &Self) -> bool {
         self.get_symbol() == other.get_symbol() && self.get_content() == other.get_content()
     }
+    #[allow(clippy::partialeq_ne_impl)]
     fn ne(&self, other: &Self) -> bool {
         self.get_symbol() != other.get_symbol() || self.get_content() != other.get_content()
     }
@@ -0,0 +1,332 @@
+use crate::{AutomateTrait, BTSet, NDFAEpsilon, State, Transition, DFA, FSM, NDFA};
+use std::collections::HashMap;
+use std::fmt;
+
+/// La raison pour laquelle un état est inutile
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum UselessReason {
+    /// L'état n'est atteignable depuis aucun état initial
+    Unreachable,
+    /// Aucun état final n'est atteignable depuis l'état
+    Dead,
+    /// L'état est à la fois inaccessible et sans issue
+    Both,
+}
+
+/// La liste des états inutiles d'un automate, avec la raison pour chacun
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TrimReport {
+    entries: Vec<(State, UselessReason)>,
+}
+
+impl TrimReport {
+    /// Retourne les états inutiles et leur raison, dans l'ordre des états
+    pub fn get_entries(&self) -> &Vec<(State, UselessReason)> {
+        &self.entries
+    }
+
+    /// Retourne la raison pour laquelle l'état est inutile, ou `None` s'il est utile
+    pub fn get_reason(&self, state: &State) -> Option<UselessReason> {
+        self.entries.iter().find(|(useless, _)| useless == state).map(|(_, reason)| *reason)
+    }
+
+    /// Indique si tout les états de l'automate sont utiles
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+impl fmt::Display for TrimReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, (state, reason)) in self.entries.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            let reason: &str = match reason {
+                UselessReason::Unreachable => "inaccessible depuis les états initiaux",
+                UselessReason::Dead => "n'atteint aucun état final",
+                UselessReason::Both => "inaccessible et n'atteint aucun état final",
+            };
+            write!(f, "{}: {}", state.get_name(), reason)?;
+        }
+        Ok(())
+    }
+}
+
+/// La restriction d'un automate à ses états utiles
+///
+/// Un état est accessible s'il est atteignable depuis un état initial, et co-accessible s'il permet d'atteindre
+/// un état final. Les états utiles sont ceux qui sont les deux à la fois. Les transitions `ε` comptent comme des
+/// transitions ordinaires.
+pub trait AutomateTrim: Sized {
+    /// Retourne les états atteignables depuis un état initial
+    fn accessible_states(&self) -> BTSet<State>;
+    /// Retourne les états depuis lesquels un état final est atteignable
+    fn coaccessible_states(&self) -> BTSet<State>;
+    /// Retourne un nouvel automate restreint aux états accessibles et co-accessibles
+    fn trim(&self) -> Self;
+    /// Retourne les états inutiles de l'automate et la raison pour chacun
+    fn useless_states(&self) -> TrimReport;
+}
+
+// les arcs (etat, image) d'un delta non deterministe
+fn edges(delta: &HashMap<Transition<State>, BTSet<State>>) -> Vec<(State, State)> {
+    delta
+        .iter()
+        .flat_map(|(transition, images)| images.get().iter().map(move |image| (transition.get_content().clone(), image.clone())))
+        .collect()
+}
+
+// les etats atteignables depuis `from` en suivant les arcs, ou en les remontant si `backward` est vrai
+fn reachable(from: &BTSet<State>, edges: &[(State, State)], backward: bool) -> BTSet<State> {
+    let mut successors: HashMap<&State, Vec<&State>> = HashMap::new();
+    for (source, target) in edges {
+        let (source, target): (&State, &State) = if backward { (target, source) } else { (source, target) };
+        successors.entry(source).or_default().push(target);
+    }
+    let mut reached: BTSet<State> = from.clone();
+    let mut stack: Vec<&State> = from.get().iter().collect();
+    while let Some(state) = stack.pop() {
+        for &next in successors.get(state).map(|next| next.as_slice()).unwrap_or(&[]) {
+            if reached.insert(next.clone()) {
+                stack.push(next);
+            }
+        }
+    }
+    reached
+}
+
+// construit le rapport à partir des etats de l'automate
+fn report(states: &BTSet<State>, accessible: &BTSet<State>, coaccessible: &BTSet<State>) -> TrimReport {
+    let mut entries: Vec<(State, UselessReason)> = Vec::new();
+    for state in states.get() {
+        let reason: Option<UselessReason> = match (accessible.contains(state), coaccessible.contains(state)) {
+            (true, true) => None,
+            (false, true) => Some(UselessReason::Unreachable),
+            (true, false) => Some(UselessReason::Dead),
+            (false, false) => Some(UselessReason::Both),
+        };
+        if let Some(reason) = reason {
+            entries.push((state.clone(), reason));
+        }
+    }
+    TrimReport { entries }
+}
+
+// restreint un delta non deterministe aux etats utiles
+fn restrict(delta: &HashMap<Transition<State>, BTSet<State>>, useful: &BTSet<State>) -> HashMap<Transition<State>, BTSet<State>> {
+    let mut restricted: HashMap<Transition<State>, BTSet<State>> = HashMap::new();
+    for (transition, images) in delta {
+        if !useful.contains(transition.get_content()) {
+            continue;
+        }
+        let mut kept: BTSet<State> = BTSet::new();
+        for image in images.get() {
+            if useful.contains(image) {
+                kept.insert(image.clone());
+            }
+        }
+        if !kept.is_empty() {
+            restricted.insert(transition.clone(), kept);
+        }
+    }
+    restricted
+}
+
+// l'intersection de deux sets d'etats
+fn intersection(a: &BTSet<State>, b: &BTSet<State>) -> BTSet<State> {
+    let mut result: BTSet<State> = BTSet::new();
+    for state in a.get() {
+        if b.contains(state) {
+            result.insert(state.clone());
+        }
+    }
+    result
+}
+
+// les etats de l'automate, y compris les etats initiaux non declarés
+fn all_states(states: &BTSet<State>, starts: &BTSet<State>) -> BTSet<State> {
+    let mut all: BTSet<State> = states.clone();
+    all.insert_all(starts.clone());
+    all
+}
+
+impl DFA {
+    // le delta vu comme celui d'un automate non deterministe
+    fn delta_as_sets(&self) -> HashMap<Transition<State>, BTSet<State>> {
+        self.get_delta()
+            .iter()
+            .map(|(transition, image)| (transition.clone(), BTSet::new().from_vect(vec![image.clone()])))
+            .collect()
+    }
+}
+
+impl AutomateTrim for DFA {
+    /// ```
+    /// use automaters::*;
+    /// fn main() {
+    ///     let dfa : DFA = DFA::from_json_file("src/automates/DFA1.json");
+    ///     assert_eq!(dfa.accessible_states().len(), 2);
+    /// }
+    /// ```
+    fn accessible_states(&self) -> BTSet<State> {
+        reachable(&BTSet::new().from_vect(vec![self.get_start().clone()]), &edges(&self.delta_as_sets()), false)
+    }
+
+    fn coaccessible_states(&self) -> BTSet<State> {
+        reachable(self.get_ends(), &edges(&self.delta_as_sets()), true)
+    }
+
+    /// Retourne le DFA restreint à ses états utiles
+    ///
+    /// L'état initial est toujours conservé: si le langage est vide, le DFA obtenu n'a que cet état, sans transition.
+    fn trim(&self) -> DFA {
+        let useful: BTSet<State> = intersection(&self.accessible_states(), &self.coaccessible_states());
+        let delta: HashMap<Transition<State>, State> = self
+            .get_delta()
+            .iter()
+            .filter(|(transition, image)| useful.contains(transition.get_content()) && useful.contains(image))
+            .map(|(transition, image)| (transition.clone(), image.clone()))
+            .collect();
+        let mut states: BTSet<State> = useful.clone();
+        states.insert(self.get_start().clone());
+        let fsm: FSM = FSM::new(states, self.get_alphabet().clone(), intersection(self.get_ends(), &useful));
+        DFA::new(self.get_start().clone(), delta, fsm)
+    }
+
+    fn useless_states(&self) -> TrimReport {
+        let states: BTSet<State> = all_states(self.get_states(), &BTSet::new().from_vect(vec![self.get_start().clone()]));
+        report(&states, &self.accessible_states(), &self.coaccessible_states())
+    }
+}
+
+impl AutomateTrim for NDFA {
+    fn accessible_states(&self) -> BTSet<State> {
+        reachable(self.get_starts(), &edges(self.get_delta()), false)
+    }
+
+    fn coaccessible_states(&self) -> BTSet<State> {
+        reachable(self.get_ends(), &edges(self.get_delta()), true)
+    }
+
+    /// Retourne le NDFA restreint à ses états utiles, il n'a aucun état si son langage est vide
+    ///
+    /// ```
+    /// use automaters::*;
+    /// fn main() {
+    ///     let nfa : NDFA = NDFA::from_json_file("src/automates/NFA2.json");
+    ///     let trimmed : NDFA = nfa.trim();
+    ///     assert!(trimmed.useless_states().is_empty());
+    ///     assert!(equivalent(&nfa, &trimmed).is_ok());
+    /// }
+    /// ```
+    fn trim(&self) -> NDFA {
+        let useful: BTSet<State> = intersection(&self.accessible_states(), &self.coaccessible_states());
+        let fsm: FSM = FSM::new(useful.clone(), self.get_alphabet().clone(), intersection(self.get_ends(), &useful));
+        NDFA::new(intersection(self.get_starts(), &useful), restrict(self.get_delta(), &useful), fsm)
+    }
+
+    fn useless_states(&self) -> TrimReport {
+        report(&all_states(self.get_states(), self.get_starts()), &self.accessible_states(), &self.coaccessible_states())
+    }
+}
+
+impl AutomateTrim for NDFAEpsilon {
+    fn accessible_states(&self) -> BTSet<State> {
+        reachable(self.get_starts(), &edges(self.get_delta()), false)
+    }
+
+    fn coaccessible_states(&self) -> BTSet<State> {
+        reachable(self.get_ends(), &edges(self.get_delta()), true)
+    }
+
+    /// Retourne le NDFAEpsilon restreint à ses états utiles, il n'a aucun état si son langage est vide
+    fn trim(&self) -> NDFAEpsilon {
+        let useful: BTSet<State> = intersection(&self.accessible_states(), &self.coaccessible_states());
+        let fsm: FSM = FSM::new(useful.clone(), self.get_alphabet().clone(), intersection(self.get_ends(), &useful));
+        NDFAEpsilon::new(intersection(self.get_starts(), &useful), restrict(self.get_delta(), &useful), fsm)
+    }
+
+    fn useless_states(&self) -> TrimReport {
+        report(&all_states(self.get_states(), self.get_starts()), &self.accessible_states(), &self.coaccessible_states())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{equivalent, AutomateJsonIO};
+    use serde_json::json;
+
+    #[test]
+    fn trim_and_report() {
+        // U est inaccessible, D n'atteint aucun etat final, X est les deux
+        let dfa: DFA = DFA::from_json(&json!({
+            "states" : ["A", "B", "D", "U", "X"],
+            "alphabet" : ["a", "b"],
+            "ends" : ["B"],
+            "start" : "A",
+            "delta" : [
+                {"state" : "A", "symbol" : "a", "image" : "B"},
+                {"state" : "A", "symbol" : "b", "image" : "D"},
+                {"state" : "B", "symbol" : "a", "image" : "A"},
+                {"state" : "D", "symbol" : "a", "image" : "D"},
+                {"state" : "U", "symbol" : "a", "image" : "B"},
+                {"state" : "X", "symbol" : "b", "image" : "D"}
+            ]
+        }));
+        assert_eq!(dfa.accessible_states(), BTSet::new().from_vect(vec![State::from_str("A"), State::from_str("B"), State::from_str("D")]));
+        assert_eq!(dfa.coaccessible_states(), BTSet::new().from_vect(vec![State::from_str("A"), State::from_str("B"), State::from_str("U")]));
+        let report: TrimReport = dfa.useless_states();
+        assert_eq!(report.get_reason(&State::from_str("U")), Some(UselessReason::Unreachable));
+        assert_eq!(report.get_reason(&State::from_str("D")), Some(UselessReason::Dead));
+        assert_eq!(report.get_reason(&State::from_str("X")), Some(UselessReason::Both));
+        assert_eq!(report.get_reason(&State::from_str("A")), None);
+        assert_eq!(report.to_string(), "D: n'atteint aucun état final\nU: inaccessible depuis les états initiaux\nX: inaccessible et n'atteint aucun état final");
+        let trimmed: DFA = dfa.trim();
+        assert_eq!(trimmed.get_states().len(), 2);
+        assert_eq!(trimmed.get_delta().len(), 2);
+        assert!(trimmed.useless_states().is_empty());
+        assert!(equivalent(&dfa, &trimmed).is_ok());
+        // le DFA d'un langage vide garde son etat initial
+        let empty: DFA = dfa.intersection(&dfa.complement()).trim();
+        assert_eq!(empty.get_states().len(), 1);
+        assert!(empty.get_delta().is_empty());
+        // meme quand l'etat initial inutile a une boucle
+        let empty: DFA = DFA::from_json(&json!({
+            "states" : ["A", "B"],
+            "alphabet" : ["a"],
+            "ends" : [],
+            "start" : "A",
+            "delta" : [
+                {"state" : "A", "symbol" : "a", "image" : "A"},
+                {"state" : "B", "symbol" : "a", "image" : "A"}
+            ]
+        })).trim();
+        assert_eq!(empty.get_states().len(), 1);
+        assert!(empty.get_delta().is_empty());
+        // avec des epsilon transitions, q_1 et q_2 n'atteignent aucun etat final
+        let nfae: NDFAEpsilon = NDFAEpsilon::from_json(&json!({
+            "states" : ["q_0", "q_1", "q_2", "q_3"],
+            "alphabet" : ["a", "ε"],
+            "ends" : ["q_3"],
+            "starts" : ["q_0"],
+            "delta" : [
+                {"state" : "q_0", "symbol" : "ε", "images" : ["q_1", "q_3"]},
+                {"state" : "q_1", "symbol" : "a", "images" : ["q_2"]},
+                {"state" : "q_3", "symbol" : "a", "images" : ["q_3"]}
+            ]
+        }));
+        let trimmed: NDFAEpsilon = nfae.trim();
+        assert_eq!(trimmed.get_states(), &BTSet::new().from_vect(vec![State::from_str("q_0"), State::from_str("q_3")]));
+        assert_eq!(nfae.useless_states().get_entries().len(), 2);
+        assert!(equivalent(&nfae, &trimmed).is_ok());
+        let nfa: NDFA = NDFA::from_json_file("src/automates/NFA1.json");
+        assert!(nfa.trim().useless_states().is_empty());
+        assert!(equivalent(&nfa, &nfa.trim()).is_ok());
+        // un automate sans etat final perd tout ses etats
+        let nfae: NDFAEpsilon = NDFAEpsilon::from_json_file("src/automates/NFA2e.json");
+        assert!(nfae.trim().get_states().is_empty());
+        assert_eq!(nfae.useless_states().get_entries().len(), 3);
+    }
+}
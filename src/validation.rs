@@ -0,0 +1,606 @@
+use crate::json;
+use crate::nfae::EPSILON;
+use crate::{AutomateJsonIO, AutomateTrait, AutomatonError, BTSet, NDFAEpsilon, State, Symbol, Transition, DFA, NDFA};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::fmt;
+
+/// La gravité d'un diagnostic
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Severity {
+    /// L'automate est invalide, il est refusé par le chargement strict
+    Error,
+    /// L'automate est utilisable mais probablement incorrect ou incomplet
+    Warning,
+}
+
+/// La nature d'un diagnostic
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum DiagnosticKind {
+    /// L'automate n'a aucun état initial, ou son état initial n'est pas déclaré
+    MissingStart,
+    /// Un état est utilisé sans faire partie des états de l'automate
+    UndeclaredState,
+    /// Un symbole de delta ne fait pas partie de l'alphabet
+    UndeclaredSymbol,
+    /// Le symbole `ε` est utilisé par un automate qui ne gère pas les epsilon transitions
+    EpsilonInAlphabet,
+    /// Une entrée de delta est répétée pour le même couple (état, symbole)
+    DuplicateTransition,
+    /// Un DFA possède plusieurs images pour le même couple (état, symbole)
+    NonDeterministic,
+    /// Une entrée de delta n'a aucune image
+    EmptyImages,
+    /// Un DFA n'a pas de transition pour un couple (état, symbole)
+    MissingTransition,
+}
+
+/// Un diagnostic produit par la validation d'un automate
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Diagnostic {
+    severity: Severity,
+    kind: DiagnosticKind,
+    message: String,
+    state: Option<State>,
+    symbol: Option<Symbol>,
+    index: Option<usize>,
+}
+
+impl Diagnostic {
+    /// Créer un diagnostic
+    ///
+    /// # Arguments
+    ///
+    /// * `_severity` - La gravité du diagnostic
+    /// * `_kind` - La nature du diagnostic
+    /// * `_message` - Une description lisible du probleme
+    /// * `_state` - L'état en cause, s'il y en a un
+    /// * `_symbol` - Le symbole en cause, s'il y en a un
+    /// * `_index` - L'indice de l'entrée de delta en cause, si le diagnostic provient du json
+    ///
+    /// # Return
+    ///
+    /// * `Diagnostic` - Le diagnostic qui a été créer
+    ///
+    pub fn new(_severity: Severity, _kind: DiagnosticKind, _message: String, _state: Option<State>, _symbol: Option<Symbol>, _index: Option<usize>) -> Self {
+        Diagnostic {
+            severity: _severity,
+            kind: _kind,
+            message: _message,
+            state: _state,
+            symbol: _symbol,
+            index: _index,
+        }
+    }
+
+    /// Retourne la gravité du diagnostic
+    pub fn get_severity(&self) -> Severity {
+        self.severity
+    }
+
+    /// Retourne la nature du diagnostic
+    pub fn get_kind(&self) -> DiagnosticKind {
+        self.kind
+    }
+
+    /// Retourne la description du diagnostic
+    pub fn get_message(&self) -> &String {
+        &self.message
+    }
+
+    /// Retourne l'état en cause
+    pub fn get_state(&self) -> Option<&State> {
+        self.state.as_ref()
+    }
+
+    /// Retourne le symbole en cause
+    pub fn get_symbol(&self) -> Option<&Symbol> {
+        self.symbol.as_ref()
+    }
+
+    /// Retourne l'indice de l'entrée de delta en cause
+    pub fn get_index(&self) -> Option<usize> {
+        self.index
+    }
+
+    /// Indique si le diagnostic est une erreur
+    pub fn is_error(&self) -> bool {
+        self.severity == Severity::Error
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let severity: &str = match self.severity {
+            Severity::Error => "erreur",
+            Severity::Warning => "avertissement",
+        };
+        write!(f, "{}: {}", severity, self.message)?;
+        if let Some(index) = self.index {
+            write!(f, " (entrée {} de \"delta\")", index)?;
+        }
+        Ok(())
+    }
+}
+
+/// La validation sémantique d'un automate
+pub trait AutomateValidation: AutomateJsonIO {
+    /// Verifie la cohérence de l'automate et retourne la liste des problemes trouvés
+    fn validate(&self) -> Vec<Diagnostic>;
+
+    /// Verifie les problemes qui ne sont visibles que dans le json, comme les entrées de delta répétées
+    fn validate_json(content_json: &Value) -> Vec<Diagnostic>;
+
+    /// Créer l'automate depuis du json, en refusant les automates possedant au moins une erreur de validation
+    ///
+    /// # Arguments
+    ///
+    /// * `content_json` - Le contenu json
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use automaters::*;
+    /// use serde_json::json;
+    /// fn main() {
+    ///     let content_json = json!({
+    ///         "ends" : ["q_0"],
+    ///         "start" : "q_0",
+    ///         "delta" : [
+    ///             {"state" : "q_0", "symbol" : "a", "image" : "q_0"},
+    ///             {"state" : "q_0", "symbol" : "a", "image" : "q_1"}
+    ///         ]
+    ///     });
+    ///     // le chargement classique garde silencieusement la derniere entrée
+    ///     assert!(DFA::try_from_json(&content_json).is_ok());
+    ///     // le chargement strict refuse l'automate
+    ///     assert!(DFA::from_json_strict(&content_json).is_err());
+    /// }
+    /// ```
+    ///
+    /// # Return
+    ///
+    /// * `Result<Self, AutomatonError>` - L'automate, ou `AutomatonError::Invalid` avec l'ensemble des diagnostics
+    ///
+    fn from_json_strict(content_json: &Value) -> Result<Self, AutomatonError> {
+        let automate: Self = Self::try_from_json(content_json)?;
+        let mut diagnostics: Vec<Diagnostic> = Self::validate_json(content_json);
+        diagnostics.extend(automate.validate());
+        if diagnostics.iter().any(|diagnostic| diagnostic.is_error()) {
+            return Err(AutomatonError::Invalid { diagnostics });
+        }
+        Ok(automate)
+    }
+
+    /// Créer l'automate depuis un fichier json, en refusant les automates possedant au moins une erreur de validation
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Le chemin vers le fichier json
+    ///
+    /// # Return
+    ///
+    /// * `Result<Self, AutomatonError>` - L'automate, ou l'erreur rencontrée
+    ///
+    fn from_json_file_strict(path: &str) -> Result<Self, AutomatonError> {
+        let content_json: Value = json::read_json_file(path)?;
+        Self::from_json_strict(&content_json)
+    }
+}
+
+// verifie les elements communs à tout les automates: etats finaux, transitions et alphabet
+fn check_common<T>(automate: &impl AutomateTrait<T>, images_of: impl Fn(&T) -> Vec<State>, diagnostics: &mut Vec<Diagnostic>)
+where
+    T: Clone + std::hash::Hash + fmt::Debug,
+{
+    let states: &BTSet<State> = automate.get_states();
+    for state in automate.get_ends().get() {
+        if !states.contains(state) {
+            diagnostics.push(Diagnostic::new(
+                Severity::Error,
+                DiagnosticKind::UndeclaredState,
+                format!("l'état final \"{}\" n'est pas un état de l'automate", state.get_name()),
+                Some(state.clone()),
+                None,
+                None,
+            ));
+        }
+    }
+    for (transition, content) in json::sorted_transitions(automate.get_delta()) {
+        let state: &State = transition.get_content();
+        let symbol: &Symbol = transition.get_symbol();
+        if !states.contains(state) {
+            diagnostics.push(Diagnostic::new(
+                Severity::Error,
+                DiagnosticKind::UndeclaredState,
+                format!("la transition ({}, {}) part d'un état inconnu", state.get_name(), symbol.get_value()),
+                Some(state.clone()),
+                Some(symbol.clone()),
+                None,
+            ));
+        }
+        if !automate.get_alphabet().contains(symbol) {
+            diagnostics.push(Diagnostic::new(
+                Severity::Error,
+                DiagnosticKind::UndeclaredSymbol,
+                format!("le symbole \"{}\" de la transition ({}, {}) n'est pas dans l'alphabet", symbol.get_value(), state.get_name(), symbol.get_value()),
+                Some(state.clone()),
+                Some(symbol.clone()),
+                None,
+            ));
+        }
+        let images: Vec<State> = images_of(content);
+        if images.is_empty() {
+            diagnostics.push(Diagnostic::new(
+                Severity::Warning,
+                DiagnosticKind::EmptyImages,
+                format!("la transition ({}, {}) n'a aucune image", state.get_name(), symbol.get_value()),
+                Some(state.clone()),
+                Some(symbol.clone()),
+                None,
+            ));
+        }
+        for image in images {
+            if !states.contains(&image) {
+                diagnostics.push(Diagnostic::new(
+                    Severity::Error,
+                    DiagnosticKind::UndeclaredState,
+                    format!("la transition ({}, {}) mene à l'état inconnu \"{}\"", state.get_name(), symbol.get_value(), image.get_name()),
+                    Some(image),
+                    Some(symbol.clone()),
+                    None,
+                ));
+            }
+        }
+    }
+}
+
+// verifie que le symbole epsilon n'est pas utilisé par un automate qui ne le gere pas
+fn check_no_epsilon(alphabet: &BTSet<Symbol>, diagnostics: &mut Vec<Diagnostic>) {
+    let epsilon: Symbol = Symbol::from_str(EPSILON);
+    if alphabet.contains(&epsilon) {
+        diagnostics.push(Diagnostic::new(
+            Severity::Error,
+            DiagnosticKind::EpsilonInAlphabet,
+            format!("le symbole \"{}\" est reservé aux NDFAEpsilon", EPSILON),
+            None,
+            Some(epsilon),
+            None,
+        ));
+    }
+}
+
+// verifie les etats initiaux d'un automate non deterministe
+fn check_starts(automate: &impl AutomateTrait<BTSet<State>>, diagnostics: &mut Vec<Diagnostic>) {
+    if automate.get_starts().is_empty() {
+        diagnostics.push(Diagnostic::new(
+            Severity::Error,
+            DiagnosticKind::MissingStart,
+            "l'automate n'a aucun état initial".to_string(),
+            None,
+            None,
+            None,
+        ));
+    }
+    for state in automate.get_starts().get() {
+        if !automate.get_states().contains(state) {
+            diagnostics.push(Diagnostic::new(
+                Severity::Error,
+                DiagnosticKind::MissingStart,
+                format!("l'état initial \"{}\" n'est pas un état de l'automate", state.get_name()),
+                Some(state.clone()),
+                None,
+                None,
+            ));
+        }
+    }
+}
+
+// les images d'une entrée de NDFA, lues comme un ensemble d'états
+fn image_set(image: &Value) -> Option<BTSet<State>> {
+    let states: Vec<State> = image.as_array()?.iter().map(|state| state.as_str().map(State::from_str)).collect::<Option<Vec<State>>>()?;
+    Some(BTSet::new().from_vect(states))
+}
+
+// cherche les entrées de delta dont le symbole n'est pas dans l'alphabet declaré, s'il est precisé
+// le chargement ajoute ces symboles à l'alphabet, l'erreur n'est donc visible que dans le json
+fn check_symbols(content_json: &Value) -> Vec<Diagnostic> {
+    let mut diagnostics: Vec<Diagnostic> = Vec::new();
+    let (alphabet, entries): (&Vec<Value>, &Vec<Value>) = match (content_json.get("alphabet").and_then(|alphabet| alphabet.as_array()), content_json.get("delta").and_then(|delta| delta.as_array())) {
+        (Some(alphabet), Some(entries)) => (alphabet, entries),
+        _ => return diagnostics,
+    };
+    let alphabet: BTSet<Symbol> = BTSet::new().from_vect(alphabet.iter().filter_map(|symbol| symbol.as_str()).map(Symbol::from_str).collect());
+    for (index, entry) in entries.iter().enumerate() {
+        let (state, symbol) = match (entry.get("state").and_then(|v| v.as_str()), entry.get("symbol").and_then(|v| v.as_str())) {
+            (Some(state), Some(symbol)) => (State::from_str(state), Symbol::from_str(symbol)),
+            _ => continue,
+        };
+        if !alphabet.contains(&symbol) {
+            diagnostics.push(Diagnostic::new(
+                Severity::Error,
+                DiagnosticKind::UndeclaredSymbol,
+                format!("le symbole \"{}\" de la transition ({}, {}) n'est pas dans l'alphabet", symbol.get_value(), state.get_name(), symbol.get_value()),
+                Some(state),
+                Some(symbol),
+                Some(index),
+            ));
+        }
+    }
+    diagnostics
+}
+
+// cherche les entrées de delta qui reprennent un couple (état, symbole) déjà vu
+// `deterministic` indique si deux images differentes rendent l'automate non deterministe
+fn check_duplicates(content_json: &Value, image_key: &str, deterministic: bool) -> Vec<Diagnostic> {
+    let mut diagnostics: Vec<Diagnostic> = Vec::new();
+    let entries: &Vec<Value> = match content_json.get("delta").and_then(|delta| delta.as_array()) {
+        Some(entries) => entries,
+        // les erreurs de structure sont signalées par le chargement
+        None => return diagnostics,
+    };
+    // premiere entrée rencontrée pour chaque couple (état, symbole)
+    let mut seen: HashMap<Transition<State>, (usize, &Value)> = HashMap::new();
+    for (index, entry) in entries.iter().enumerate() {
+        let (state, symbol) = match (entry.get("state").and_then(|v| v.as_str()), entry.get("symbol").and_then(|v| v.as_str())) {
+            (Some(state), Some(symbol)) => (State::from_str(state), Symbol::from_str(symbol)),
+            _ => continue,
+        };
+        let image: &Value = entry.get(image_key).unwrap_or(&Value::Null);
+        let transition: Transition<State> = Transition::new(symbol.clone(), state.clone());
+        if let Some((first, first_image)) = seen.get(&transition) {
+            // les images d'un NDFA forment un ensemble, leur ordre et leurs répétitions ne comptent pas
+            let same_image: bool = match (deterministic, image_set(first_image), image_set(image)) {
+                (false, Some(first_images), Some(images)) => first_images == images,
+                _ => *first_image == image,
+            };
+            let (severity, kind, problem) = if same_image {
+                (Severity::Warning, DiagnosticKind::DuplicateTransition, "répète")
+            } else if deterministic {
+                (Severity::Error, DiagnosticKind::NonDeterministic, "contredit")
+            } else {
+                (Severity::Error, DiagnosticKind::DuplicateTransition, "écrase")
+            };
+            diagnostics.push(Diagnostic::new(
+                severity,
+                kind,
+                format!("la transition ({}, {}) {} l'entrée {}", state.get_name(), symbol.get_value(), problem, first),
+                Some(state),
+                Some(symbol),
+                Some(index),
+            ));
+        } else {
+            seen.insert(transition, (index, image));
+        }
+    }
+    diagnostics
+}
+
+impl AutomateValidation for DFA {
+    /// Verifie la cohérence du DFA
+    ///
+    /// En plus des verifications communes (états finaux, transitions, alphabet), l'état initial doit exister,
+    /// le symbole `ε` est refusé et chaque couple (état, symbole) sans transition produit un avertissement.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use automaters::*;
+    /// fn main() {
+    ///     let dfa : DFA = DFA::from_json_file("src/automates/DFA1.json");
+    ///     let diagnostics : Vec<Diagnostic> = dfa.validate();
+    ///     // DFA1 est partiel, mais valide
+    ///     assert!(diagnostics.iter().all(|diagnostic| diagnostic.get_kind() == DiagnosticKind::MissingTransition));
+    /// }
+    /// ```
+    ///
+    /// # Return
+    ///
+    /// * `Vec<Diagnostic>` - Les diagnostics trouvés
+    ///
+    fn validate(&self) -> Vec<Diagnostic> {
+        let mut diagnostics: Vec<Diagnostic> = Vec::new();
+        if !self.get_states().contains(self.get_start()) {
+            diagnostics.push(Diagnostic::new(
+                Severity::Error,
+                DiagnosticKind::MissingStart,
+                format!("l'état initial \"{}\" n'est pas un état de l'automate", self.get_start().get_name()),
+                Some(self.get_start().clone()),
+                None,
+                None,
+            ));
+        }
+        check_common(self, |image: &State| vec![image.clone()], &mut diagnostics);
+        check_no_epsilon(self.get_alphabet(), &mut diagnostics);
+        // un DFA partiel reste utilisable, on le signale seulement
+        for state in self.get_states().get() {
+            for symbol in self.get_alphabet().get() {
+                if self.apply_delta(Transition::new(symbol.clone(), state.clone())).is_none() {
+                    diagnostics.push(Diagnostic::new(
+                        Severity::Warning,
+                        DiagnosticKind::MissingTransition,
+                        format!("aucune transition depuis \"{}\" avec le symbole \"{}\"", state.get_name(), symbol.get_value()),
+                        Some(state.clone()),
+                        Some(symbol.clone()),
+                        None,
+                    ));
+                }
+            }
+        }
+        diagnostics
+    }
+
+    /// Verifie que delta ne contient pas deux entrées pour le même couple (état, symbole), ni de symbole hors de l'alphabet declaré
+    fn validate_json(content_json: &Value) -> Vec<Diagnostic> {
+        let mut diagnostics: Vec<Diagnostic> = check_duplicates(content_json, "image", true);
+        diagnostics.extend(check_symbols(content_json));
+        diagnostics
+    }
+}
+
+impl AutomateValidation for NDFA {
+    /// Verifie la cohérence du NDFA
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use automaters::*;
+    /// fn main() {
+    ///     let nfa : NDFA = NDFA::from_json_file("src/automates/NFA1.json");
+    ///     assert!(nfa.validate().is_empty());
+    /// }
+    /// ```
+    ///
+    /// # Return
+    ///
+    /// * `Vec<Diagnostic>` - Les diagnostics trouvés
+    ///
+    fn validate(&self) -> Vec<Diagnostic> {
+        let mut diagnostics: Vec<Diagnostic> = Vec::new();
+        check_starts(self, &mut diagnostics);
+        check_common(self, |images: &BTSet<State>| images.get().iter().cloned().collect(), &mut diagnostics);
+        check_no_epsilon(self.get_alphabet(), &mut diagnostics);
+        diagnostics
+    }
+
+    /// Verifie que delta ne contient pas deux entrées pour le même couple (état, symbole), ni de symbole hors de l'alphabet declaré
+    fn validate_json(content_json: &Value) -> Vec<Diagnostic> {
+        let mut diagnostics: Vec<Diagnostic> = check_duplicates(content_json, "images", false);
+        diagnostics.extend(check_symbols(content_json));
+        diagnostics
+    }
+}
+
+impl AutomateValidation for NDFAEpsilon {
+    /// Verifie la cohérence du NDFAEpsilon
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use automaters::*;
+    /// fn main() {
+    ///     let nfae : NDFAEpsilon = NDFAEpsilon::from_json_file("src/automates/NFA1e.json");
+    ///     assert!(nfae.validate().is_empty());
+    /// }
+    /// ```
+    ///
+    /// # Return
+    ///
+    /// * `Vec<Diagnostic>` - Les diagnostics trouvés
+    ///
+    fn validate(&self) -> Vec<Diagnostic> {
+        let mut diagnostics: Vec<Diagnostic> = Vec::new();
+        check_starts(self, &mut diagnostics);
+        check_common(self, |images: &BTSet<State>| images.get().iter().cloned().collect(), &mut diagnostics);
+        diagnostics
+    }
+
+    /// Verifie que delta ne contient pas deux entrées pour le même couple (état, symbole), ni de symbole hors de l'alphabet declaré
+    fn validate_json(content_json: &Value) -> Vec<Diagnostic> {
+        let mut diagnostics: Vec<Diagnostic> = check_duplicates(content_json, "images", false);
+        diagnostics.extend(check_symbols(content_json));
+        diagnostics
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::FSM;
+    use serde_json::json;
+
+    #[test]
+    fn validation_diagnostics() {
+        // les fichiers d'exemple ne contiennent aucune erreur
+        for link_file in ["src/automates/DFA1.json", "src/automates/DFA2.json", "src/automates/DFA3.json"] {
+            assert!(DFA::from_json_file_strict(link_file).is_ok());
+        }
+        for link_file in ["src/automates/NFA1.json", "src/automates/NFA2.json", "src/automates/NFA3.json"] {
+            assert!(NDFA::from_json_file_strict(link_file).is_ok());
+        }
+        for link_file in ["src/automates/NFA1e.json", "src/automates/NFA2e.json", "src/automates/NFA3e.json"] {
+            assert!(NDFAEpsilon::from_json_file_strict(link_file).is_ok());
+        }
+        // DFA2 est complet
+        assert!(DFA::from_json_file("src/automates/DFA2.json").validate().is_empty());
+
+        // entrées en double dans un DFA
+        let content_json: Value = json!({"ends" : ["q_0"], "start" : "q_0", "delta" : [
+            {"state" : "q_0", "symbol" : "a", "image" : "q_0"},
+            {"state" : "q_0", "symbol" : "a", "image" : "q_0"},
+            {"state" : "q_0", "symbol" : "a", "image" : "q_1"}
+        ]});
+        let diagnostics: Vec<Diagnostic> = DFA::validate_json(&content_json);
+        assert_eq!(diagnostics.len(), 2);
+        assert_eq!(diagnostics[0].get_severity(), Severity::Warning);
+        assert_eq!(diagnostics[0].get_index(), Some(1));
+        assert_eq!(diagnostics[1].get_kind(), DiagnosticKind::NonDeterministic);
+        assert_eq!(diagnostics[1].get_index(), Some(2));
+        assert_eq!(diagnostics[1].get_state(), Some(&State::from_str("q_0")));
+        assert_eq!(diagnostics[1].get_symbol(), Some(&Symbol::from_str("a")));
+        match DFA::from_json_strict(&content_json) {
+            Err(AutomatonError::Invalid { diagnostics }) => assert_eq!(diagnostics.iter().filter(|d| d.is_error()).count(), 1),
+            _ => panic!("le DFA aurait du etre refusé"),
+        }
+
+        // epsilon dans l'alphabet d'un NDFA
+        let content_json: Value = json!({"ends" : ["q_1"], "starts" : ["q_0"], "delta" : [
+            {"state" : "q_0", "symbol" : "ε", "images" : ["q_1"]}
+        ]});
+        let nfa: NDFA = NDFA::from_json(&content_json);
+        assert_eq!(nfa.validate()[0].get_kind(), DiagnosticKind::EpsilonInAlphabet);
+        assert!(NDFA::from_json_strict(&content_json).is_err());
+        assert!(NDFAEpsilon::from_json_strict(&content_json).is_ok());
+
+        // les images d'un NDFA sont un ensemble: la meme entrée dans un autre ordre est une simple répétition
+        let content_json: Value = json!({"ends" : ["b"], "starts" : ["a"], "delta" : [
+            {"state" : "a", "symbol" : "x", "images" : ["a", "b"]},
+            {"state" : "a", "symbol" : "x", "images" : ["b", "a", "b"]},
+            {"state" : "a", "symbol" : "x", "images" : ["b"]}
+        ]});
+        let diagnostics: Vec<Diagnostic> = NDFA::validate_json(&content_json);
+        assert_eq!(diagnostics.len(), 2);
+        assert_eq!((diagnostics[0].get_severity(), diagnostics[0].get_kind()), (Severity::Warning, DiagnosticKind::DuplicateTransition));
+        assert_eq!((diagnostics[1].get_severity(), diagnostics[1].get_index()), (Severity::Error, Some(2)));
+        let content_json: Value = json!({"ends" : ["b"], "starts" : ["a"], "delta" : [
+            {"state" : "a", "symbol" : "x", "images" : ["a", "b"]},
+            {"state" : "a", "symbol" : "x", "images" : ["b", "a"]}
+        ]});
+        assert!(NDFA::from_json_strict(&content_json).is_ok());
+        assert!(NDFAEpsilon::from_json_strict(&content_json).is_ok());
+
+        // un symbole de delta absent de l'alphabet declaré
+        let content_json: Value = json!({"alphabet" : ["a"], "ends" : ["q_0"], "start" : "q_0", "delta" : [
+            {"state" : "q_0", "symbol" : "a", "image" : "q_0"},
+            {"state" : "q_0", "symbol" : "b", "image" : "q_0"}
+        ]});
+        let diagnostics: Vec<Diagnostic> = DFA::validate_json(&content_json);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].get_kind(), DiagnosticKind::UndeclaredSymbol);
+        assert_eq!(diagnostics[0].get_symbol(), Some(&Symbol::from_str("b")));
+        assert_eq!(diagnostics[0].get_index(), Some(1));
+        assert!(DFA::from_json_strict(&content_json).is_err());
+        let content_json: Value = json!({"alphabet" : ["a"], "ends" : [], "starts" : ["q_0"], "delta" : [
+            {"state" : "q_0", "symbol" : "b", "images" : ["q_0"]}
+        ]});
+        assert_eq!(NDFA::validate_json(&content_json)[0].get_kind(), DiagnosticKind::UndeclaredSymbol);
+        assert!(NDFAEpsilon::from_json_strict(&content_json).is_err());
+        // sans alphabet declaré, l'alphabet est celui de delta
+        assert!(NDFA::validate_json(&json!({"ends" : [], "starts" : ["q_0"], "delta" : [{"state" : "q_0", "symbol" : "b", "images" : ["q_0"]}]})).is_empty());
+
+        // aucun état initial
+        let content_json: Value = json!({"ends" : [], "starts" : [], "delta" : []});
+        assert_eq!(NDFA::from_json(&content_json).validate()[0].get_kind(), DiagnosticKind::MissingStart);
+
+        // automate construit à la main avec des états non declarés
+        let dfa: DFA = DFA::from_json_file("src/automates/DFA1.json");
+        let mut ends: BTSet<State> = BTSet::new();
+        ends.insert(State::from_str("q_9"));
+        let fsm: FSM = FSM::new(BTSet::new(), BTSet::new(), ends);
+        let broken: DFA = DFA::new(dfa.get_start().clone(), dfa.get_delta().clone(), fsm);
+        let diagnostics: Vec<Diagnostic> = broken.validate();
+        assert_eq!(diagnostics[0].get_kind(), DiagnosticKind::MissingStart);
+        assert!(diagnostics.iter().any(|d| d.get_kind() == DiagnosticKind::UndeclaredState && d.get_state() == Some(&State::from_str("q_9"))));
+        assert!(diagnostics.iter().any(|d| d.get_kind() == DiagnosticKind::UndeclaredSymbol));
+    }
+}
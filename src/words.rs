@@ -0,0 +1,158 @@
+use crate::decision::useful_states;
+use crate::equivalence::IndexedNDFA;
+use crate::{AutomateNdfa, AutomateTrait, Symbol, NDFA};
+use std::collections::VecDeque;
+use std::fmt::Debug;
+use std::hash::Hash;
+
+/// Un itérateur paresseux sur les mots acceptés par un automate, dans l'ordre shortlex
+///
+/// Les mots sont donnés par longueur croissante, puis dans l'ordre lexicographique induit par l'ordre des symboles
+/// de l'alphabet. L'itérateur se termine lorsque le langage est fini.
+pub struct Words {
+    alphabet: Vec<Symbol>,
+    nfa: IndexedNDFA,
+    useful: Vec<bool>,
+    // les mots restant à examiner avec le set d'etats utiles qu'ils atteignent, dans l'ordre shortlex
+    queue: VecDeque<(Vec<Symbol>, Vec<usize>)>,
+}
+
+impl Words {
+    fn new(nfa: NDFA) -> Self {
+        let alphabet: Vec<Symbol> = nfa.get_alphabet().get().iter().cloned().collect();
+        let nfa: IndexedNDFA = IndexedNDFA::new(&nfa, &alphabet);
+        let useful: Vec<bool> = useful_states(&nfa);
+        let mut words: Words = Words { alphabet, nfa, useful, queue: VecDeque::new() };
+        let starts: Vec<usize> = words.nfa.starts.clone();
+        words.push(Vec::new(), starts);
+        words
+    }
+
+    // ajoute un mot à examiner, s'il peut encore mener à un etat final
+    fn push(&mut self, word: Vec<Symbol>, states: Vec<usize>) {
+        let mut states: Vec<usize> = states.into_iter().filter(|&q| self.useful[q]).collect();
+        states.sort_unstable();
+        states.dedup();
+        if !states.is_empty() {
+            self.queue.push_back((word, states));
+        }
+    }
+}
+
+impl Iterator for Words {
+    type Item = Vec<Symbol>;
+
+    fn next(&mut self) -> Option<Vec<Symbol>> {
+        // chaque mot de la file mène à un mot accepté, la recherche termine donc toujours
+        while let Some((word, states)) = self.queue.pop_front() {
+            for a in 0..self.alphabet.len() {
+                let mut next: Vec<Symbol> = word.clone();
+                next.push(self.alphabet[a].clone());
+                let images: Vec<usize> = self.nfa.step(&states, a);
+                self.push(next, images);
+            }
+            if self.nfa.any_final(&states) {
+                return Some(word);
+            }
+        }
+        None
+    }
+}
+
+/// L'énumération des mots acceptés par un automate
+pub trait AutomateWords<T: Clone + Hash + Debug>: AutomateTrait<T> + AutomateNdfa {
+    /// Retourne un itérateur paresseux sur les mots acceptés, dans l'ordre shortlex
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use automaters::*;
+    /// fn main() {
+    ///     let nfae : NDFAEpsilon = Regex::parse("(b|a)*a").unwrap().to_thompson();
+    ///     let words : Vec<String> = nfae.words().take(4).map(|word| word.iter().map(|symbol| symbol.get_value().clone()).collect()).collect();
+    ///     assert_eq!(words, vec!["a", "aa", "ba", "aaa"]);
+    /// }
+    /// ```
+    ///
+    /// # Return
+    ///
+    /// * `Words` - L'itérateur sur les mots, chaque mot étant une suite de symboles
+    ///
+    fn words(&self) -> Words {
+        Words::new(self.to_ndfa())
+    }
+
+    /// Retourne le plus petit mot accepté dans l'ordre shortlex, ou `None` si le langage est vide
+    ///
+    /// ```
+    /// use automaters::*;
+    /// fn main() {
+    ///     let dfa : DFA = DFA::from_json_file("src/automates/DFA2.json");
+    ///     assert_eq!(dfa.shortest_word(), Some(vec![Symbol::from_str("0"), Symbol::from_str("1")]));
+    /// }
+    /// ```
+    fn shortest_word(&self) -> Option<Vec<Symbol>> {
+        self.words().next()
+    }
+
+    /// Retourne les mots acceptés de longueur au plus `length`, dans l'ordre shortlex
+    ///
+    /// ```
+    /// use automaters::*;
+    /// fn main() {
+    ///     let nfa : NDFA = Regex::parse("a*").unwrap().to_glushkov().0;
+    ///     assert_eq!(nfa.words_up_to_length(2).len(), 3);
+    /// }
+    /// ```
+    fn words_up_to_length(&self, length: usize) -> Vec<Vec<Symbol>> {
+        self.words().take_while(|word| word.len() <= length).collect()
+    }
+}
+
+impl<T: Clone + Hash + Debug, A: AutomateTrait<T> + AutomateNdfa> AutomateWords<T> for A {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{AutomateJsonIO, NDFAEpsilon, Regex, DFA};
+    use crate::test_utils::{concat, undeclared_intermediate, undeclared_intermediate_epsilon, words};
+
+    #[test]
+    fn shortlex_enumeration() {
+        // l'enumeration correspond au filtrage des mots générés dans l'ordre shortlex
+        for pattern in ["∅", "ε", "(a|b)*", "ab|a|abc", "(ab)*a?|b(a|b)*", "a∅|b", "(b|a)*abb", "c?(a|b)"] {
+            let regex: Regex = Regex::parse(pattern).unwrap();
+            let nfae: NDFAEpsilon = regex.to_thompson();
+            let alphabet: Vec<String> = regex.symbols().get().iter().map(|symbol| symbol.get_value().clone()).collect();
+            let alphabet: Vec<&str> = alphabet.iter().map(String::as_str).collect();
+            let expected: Vec<String> = words(&alphabet, 5).into_iter().filter(|word| nfae.accept(word)).collect();
+            let found: Vec<String> = nfae.words_up_to_length(5).iter().map(|word| concat(word)).collect();
+            assert_eq!(found, expected, "{}", pattern);
+            assert_eq!(regex.to_glushkov().0.words_up_to_length(5), nfae.words_up_to_length(5), "{}", pattern);
+            assert_eq!(regex.to_brzozowski().words_up_to_length(5), nfae.words_up_to_length(5), "{}", pattern);
+            assert_eq!(nfae.shortest_word().map(|word| concat(&word)), expected.first().cloned(), "{}", pattern);
+        }
+        // l'iterateur se termine sur un langage fini, meme avec des etats sans issue
+        let nfa: NDFA = Regex::parse("ab|a|abc|b∅").unwrap().to_glushkov().0;
+        let all: Vec<String> = nfa.words().map(|word| concat(&word)).collect();
+        assert_eq!(all, vec!["a", "ab", "abc"]);
+        assert_eq!(NDFAEpsilon::from_json_file("src/automates/NFA2e.json").words().count(), 0);
+        // NFA3 a plusieurs etats initiaux
+        let nfa: NDFA = NDFA::from_json_file("src/automates/NFA3.json");
+        let expected: Vec<String> = words(&["a", "b"], 4).into_iter().filter(|word| nfa.accept(word)).collect();
+        assert_eq!(nfa.words_up_to_length(4).iter().map(|word| concat(word)).collect::<Vec<String>>(), expected);
+        let dfa: DFA = DFA::from_json_file("src/automates/DFA2.json");
+        assert_eq!(dfa.words().nth(2).map(|word| concat(&word)), Some("001".to_string()));
+    }
+
+    #[test]
+    fn undeclared_intermediate_state() {
+        // l'etat t n'est pas declaré mais le mot "ab" passe par lui
+        let dfa: DFA = undeclared_intermediate();
+        assert_eq!(dfa.shortest_word().map(|word| concat(&word)), Some("ab".to_string()));
+        assert_eq!(dfa.words().map(|word| concat(&word)).collect::<Vec<String>>(), vec!["ab"]);
+        let nfae: NDFAEpsilon = undeclared_intermediate_epsilon();
+        assert_eq!(nfae.shortest_word().map(|word| concat(&word)), Some("ab".to_string()));
+        assert_eq!(nfae.words().map(|word| concat(&word)).collect::<Vec<String>>(), vec!["ab"]);
+    }
+}
@@ -0,0 +1,231 @@
+// un lecteur xml minimal, suffisant pour les fichiers produits par les outils d'automates (JFLAP)
+use crate::AutomatonError;
+
+/// Un élément xml avec ses attributs, ses enfants et son texte
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Element {
+    pub(crate) name: String,
+    pub(crate) attributes: Vec<(String, String)>,
+    pub(crate) children: Vec<Element>,
+    pub(crate) text: String,
+}
+
+impl Element {
+    /// Reccupere la valeur d'un attribut
+    pub(crate) fn attribute(&self, name: &str) -> Option<&str> {
+        self.attributes.iter().find(|(key, _)| key == name).map(|(_, value)| value.as_str())
+    }
+
+    /// Reccupere le premier enfant portant ce nom
+    pub(crate) fn child(&self, name: &str) -> Option<&Element> {
+        self.children.iter().find(|child| child.name == name)
+    }
+
+    /// Reccupere les enfants portant ce nom, dans l'ordre du document
+    pub(crate) fn children_named<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a Element> + 'a {
+        self.children.iter().filter(move |child| child.name == name)
+    }
+}
+
+/// Echappe les caracteres reservés du xml
+pub(crate) fn escape(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;").replace('\'', "&apos;")
+}
+
+/// Analyse un document xml et retourne son élément racine
+pub(crate) fn parse(content: &str) -> Result<Element, AutomatonError> {
+    let mut parser: Parser = Parser { chars: content.chars().collect(), position: 0 };
+    parser.skip_misc()?;
+    let root: Element = parser.element()?;
+    parser.skip_misc()?;
+    if parser.position < parser.chars.len() {
+        return Err(parser.error("contenu inattendu après l'élément racine"));
+    }
+    Ok(root)
+}
+
+struct Parser {
+    chars: Vec<char>,
+    position: usize,
+}
+
+impl Parser {
+    // l'erreur indique la ligne en cours de lecture
+    fn error(&self, message: &str) -> AutomatonError {
+        let line: usize = self.chars[..self.position.min(self.chars.len())].iter().filter(|&&c| c == '\n').count() + 1;
+        AutomatonError::MalformedXml { message: format!("ligne {}: {}", line, message) }
+    }
+
+    fn starts_with(&self, prefix: &str) -> bool {
+        prefix.chars().enumerate().all(|(i, c)| self.chars.get(self.position + i) == Some(&c))
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.chars.get(self.position).is_some_and(|c| c.is_whitespace()) {
+            self.position += 1;
+        }
+    }
+
+    // avance jusqu'après `end`
+    fn skip_past(&mut self, end: &str) -> Result<(), AutomatonError> {
+        while self.position < self.chars.len() {
+            if self.starts_with(end) {
+                self.position += end.chars().count();
+                return Ok(());
+            }
+            self.position += 1;
+        }
+        Err(self.error(&format!("\"{}\" attendu", end)))
+    }
+
+    // ignore les espaces, commentaires, instructions de traitement et declarations
+    fn skip_misc(&mut self) -> Result<(), AutomatonError> {
+        loop {
+            self.skip_whitespace();
+            if self.starts_with("<!--") {
+                self.skip_past("-->")?;
+            } else if self.starts_with("<?") {
+                self.skip_past("?>")?;
+            } else if self.starts_with("<!") {
+                self.skip_past(">")?;
+            } else {
+                return Ok(());
+            }
+        }
+    }
+
+    fn name(&mut self) -> Result<String, AutomatonError> {
+        let start: usize = self.position;
+        while self.chars.get(self.position).is_some_and(|&c| c.is_alphanumeric() || "_-.:".contains(c)) {
+            self.position += 1;
+        }
+        if start == self.position {
+            return Err(self.error("nom attendu"));
+        }
+        Ok(self.chars[start..self.position].iter().collect())
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), AutomatonError> {
+        if self.chars.get(self.position) != Some(&expected) {
+            return Err(self.error(&format!("'{}' attendu", expected)));
+        }
+        self.position += 1;
+        Ok(())
+    }
+
+    // remplace les references d'entités par leur caractere
+    fn decode(&self, raw: &str) -> Result<String, AutomatonError> {
+        let mut decoded: String = String::new();
+        let mut rest: &str = raw;
+        while let Some(start) = rest.find('&') {
+            decoded.push_str(&rest[..start]);
+            let end: usize = rest[start..].find(';').ok_or_else(|| self.error("référence d'entité non terminée"))? + start;
+            let entity: &str = &rest[start + 1..end];
+            let c: Option<char> = match entity {
+                "lt" => Some('<'),
+                "gt" => Some('>'),
+                "amp" => Some('&'),
+                "quot" => Some('"'),
+                "apos" => Some('\''),
+                _ if entity.starts_with("#x") => u32::from_str_radix(&entity[2..], 16).ok().and_then(char::from_u32),
+                _ if entity.starts_with('#') => entity[1..].parse::<u32>().ok().and_then(char::from_u32),
+                _ => None,
+            };
+            decoded.push(c.ok_or_else(|| self.error(&format!("entité \"&{};\" inconnue", entity)))?);
+            rest = &rest[end + 1..];
+        }
+        decoded.push_str(rest);
+        Ok(decoded)
+    }
+
+    fn element(&mut self) -> Result<Element, AutomatonError> {
+        self.expect('<')?;
+        let name: String = self.name()?;
+        let mut element: Element = Element { name, attributes: Vec::new(), children: Vec::new(), text: String::new() };
+        // les attributs
+        loop {
+            self.skip_whitespace();
+            if self.starts_with("/>") {
+                self.position += 2;
+                return Ok(element);
+            }
+            if self.starts_with(">") {
+                self.position += 1;
+                break;
+            }
+            let key: String = self.name()?;
+            self.skip_whitespace();
+            self.expect('=')?;
+            self.skip_whitespace();
+            let quote: char = match self.chars.get(self.position) {
+                Some(&c) if c == '"' || c == '\'' => c,
+                _ => return Err(self.error("valeur d'attribut entre guillemets attendue")),
+            };
+            self.position += 1;
+            let start: usize = self.position;
+            while self.chars.get(self.position).is_some_and(|&c| c != quote) {
+                self.position += 1;
+            }
+            let raw: String = self.chars[start..self.position].iter().collect();
+            self.expect(quote)?;
+            element.attributes.push((key, self.decode(&raw)?));
+        }
+        // le contenu, jusqu'à la balise fermante
+        loop {
+            if self.position >= self.chars.len() {
+                return Err(self.error(&format!("balise fermante </{}> attendue", element.name)));
+            }
+            if self.starts_with("</") {
+                self.position += 2;
+                let closing: String = self.name()?;
+                if closing != element.name {
+                    return Err(self.error(&format!("balise fermante </{}> attendue, </{}> trouvée", element.name, closing)));
+                }
+                self.skip_whitespace();
+                self.expect('>')?;
+                return Ok(element);
+            }
+            if self.starts_with("<![CDATA[") {
+                self.position += 9;
+                let start: usize = self.position;
+                self.skip_past("]]>")?;
+                element.text.extend(&self.chars[start..self.position - 3]);
+            } else if self.starts_with("<!--") || self.starts_with("<?") {
+                self.skip_misc()?;
+            } else if self.starts_with("<") {
+                element.children.push(self.element()?);
+            } else {
+                let start: usize = self.position;
+                while self.chars.get(self.position).is_some_and(|&c| c != '<') {
+                    self.position += 1;
+                }
+                let raw: String = self.chars[start..self.position].iter().collect();
+                element.text.push_str(&self.decode(&raw)?);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_xml() {
+        let root: Element = parse("<?xml version=\"1.0\"?><!--commentaire--><a x='1' y=\"&lt;&#233;&gt;\">\n  <b/>texte &amp; <![CDATA[<brut>]]><c><b>2</b></c>\n</a>\n").unwrap();
+        assert_eq!(root.name, "a");
+        assert_eq!(root.attribute("x"), Some("1"));
+        assert_eq!(root.attribute("y"), Some("<é>"));
+        assert_eq!(root.children.len(), 2);
+        assert_eq!(root.children_named("b").count(), 1);
+        assert_eq!(root.child("c").unwrap().child("b").unwrap().text, "2");
+        assert_eq!(root.text.trim(), "texte & <brut>");
+        assert_eq!(escape("<a & 'b'>"), "&lt;a &amp; &apos;b&apos;&gt;");
+        // les erreurs indiquent la ligne
+        assert_eq!(parse("<a>\n<b></a>").unwrap_err(), AutomatonError::MalformedXml { message: "ligne 2: balise fermante </b> attendue, </a> trouvée".to_string() });
+        assert!(parse("<a>").is_err());
+        assert!(parse("<a x=1/>").is_err());
+        assert!(parse("<a/><b/>").is_err());
+        assert!(parse("<a>&inconnu;</a>").is_err());
+    }
+}